@@ -0,0 +1,439 @@
+// alerts.rs - Eşik tabanlı uyarı sistemi
+// Anlık sıçramalar uyarı üretmez - değer eşiğin üstünde belirli bir süre kalmalıdır
+// Tıpkı bir duman dedektörünün tek bir kıvılcımda değil, sürekli dumanda ötmesi gibi
+
+use chrono::{DateTime, Local};
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::time::{Duration, Instant};
+
+use crate::system_info::{categorize_disk_usage, ColorThresholds, DiskInfo, DiskUsageCategory, TemperatureInfo};
+
+// Ekranda tutulacak en fazla uyarı sayısı - eski uyarılar düşer
+const MAX_ALERTS: usize = 50;
+
+// Uyarı eşikleri - config dosyasındaki [alerts] tablosundan okunur
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct AlertThresholds {
+    pub cpu_percent: f32,    // Ortalama CPU kullanımı eşiği (%)
+    pub memory_percent: f32, // RAM kullanımı eşiği (%)
+    pub sustain_secs: u64,   // Eşiğin kaç saniye boyunca aşılması gerektiği
+    // Tek çekirdek bu değerin üstündeyken ortalama pegged_core_max_average'ın altındaysa
+    // tek thread'e sıkışmış bir iş var demektir - ortalama bunu gizler
+    pub pegged_core_percent: f32,
+    pub pegged_core_max_average: f32,
+    // Swap'ten okuma hızı eşiği (KiB/s) - doluluk değil, sürekli swap-in sıkışmanın işaretidir
+    pub swap_in_kib_per_sec: u64,
+}
+
+impl Default for AlertThresholds {
+    fn default() -> Self {
+        Self {
+            cpu_percent: 90.0,
+            memory_percent: 95.0,
+            sustain_secs: 5,
+            pegged_core_percent: 95.0,
+            pegged_core_max_average: 50.0,
+            swap_in_kib_per_sec: 1024,
+        }
+    }
+}
+
+// Uyarının hangi kaynaktan geldiği - UI ilgili paneli buna göre vurgular
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AlertKind {
+    HighCpu,
+    HighMemory,
+    PeggedCore,
+    DiskFull,
+    CriticalTemperature,
+    SwapThrashing,
+}
+
+impl AlertKind {
+    // Uyarı listesinde gösterilen kısa etiket
+    pub fn label(&self) -> &'static str {
+        match self {
+            AlertKind::HighCpu => "CPU",
+            AlertKind::HighMemory => "MEM",
+            AlertKind::PeggedCore => "CORE",
+            AlertKind::DiskFull => "DISK",
+            AlertKind::CriticalTemperature => "TEMP",
+            AlertKind::SwapThrashing => "SWAP",
+        }
+    }
+}
+
+// Tetiklenmiş tek bir uyarı
+#[derive(Debug, Clone)]
+pub struct Alert {
+    pub kind: AlertKind,
+    pub message: String,
+    pub timestamp: DateTime<Local>,
+}
+
+// Kritik bir koşulun durum değişimi - masaüstü bildirimleri bu olaylardan üretilir
+// Her tick değil, sadece geçişlerde olay üretildiği için bildirimler spam'e dönüşmez
+#[derive(Debug, Clone, PartialEq)]
+pub enum CriticalEvent {
+    Entered { kind: AlertKind, message: String },
+    Cleared { kind: AlertKind, message: String },
+}
+
+// Bir koşulun ne zamandan beri sürdüğünü takip eden küçük durum makinesi
+// Koşul ilk kez doğru olduğunda zamanı kaydeder, süre dolunca bir kez tetiklenir
+// Koşul ortadan kalkınca sıfırlanır ve tekrar tetiklenmeye hazır hale gelir
+#[derive(Debug, Default)]
+pub struct SustainedCondition {
+    since: Option<Instant>,
+    fired: bool,
+}
+
+impl SustainedCondition {
+    // Yeni ölçümü işle - uyarının tam şimdi tetiklenmesi gerekiyorsa true döner
+    pub fn update(&mut self, active: bool, now: Instant, sustain: Duration) -> bool {
+        if !active {
+            self.since = None;
+            self.fired = false;
+            return false;
+        }
+
+        let since = *self.since.get_or_insert(now);
+        if !self.fired && now.duration_since(since) >= sustain {
+            self.fired = true;
+            return true;
+        }
+        false
+    }
+
+    // Koşul tetiklenmiş ve hâlâ sürüyor mu - gauge'ları yakıp söndürmek için
+    pub fn is_firing(&self) -> bool {
+        self.fired
+    }
+}
+
+// Tüm uyarı koşullarını ve tetiklenen uyarıların geçmişini yöneten struct
+pub struct AlertMonitor {
+    pub thresholds: AlertThresholds,
+    cpu: SustainedCondition,
+    memory: SustainedCondition,
+    swap: SustainedCondition,
+    cores: BTreeMap<usize, SustainedCondition>, // CPU numarasına göre "tek başına dolu" durumu
+    // Şu anda kritik durumda olan kaynaklar (disk mount noktası, sensör adı)
+    critical: BTreeSet<(AlertKind, String)>,
+    pub alerts: VecDeque<Alert>, // En yeni uyarı en sonda
+    // Açılıştan beri tetiklenen uyarı sayısı - liste sınırda kırpıldığı için uzunluğu yeni
+    // uyarıyı anlamaya yetmez (pause_on_alert bununla karar verir)
+    pub fired: u64,
+}
+
+impl AlertMonitor {
+    pub fn new(thresholds: AlertThresholds) -> Self {
+        Self {
+            thresholds,
+            cpu: SustainedCondition::default(),
+            memory: SustainedCondition::default(),
+            swap: SustainedCondition::default(),
+            cores: BTreeMap::new(),
+            critical: BTreeSet::new(),
+            alerts: VecDeque::with_capacity(MAX_ALERTS),
+            fired: 0,
+        }
+    }
+
+    // Her güncellemede çağrılır - eşikleri kontrol eder ve gerekirse uyarı ekler
+    pub fn evaluate(&mut self, cpu_average: f32, memory_percent: f32, now: Instant) {
+        let sustain = Duration::from_secs(self.thresholds.sustain_secs);
+
+        if self.cpu.update(cpu_average > self.thresholds.cpu_percent, now, sustain) {
+            self.push(
+                AlertKind::HighCpu,
+                format!(
+                    "CPU average above {:.0}% for {}s ({:.1}%)",
+                    self.thresholds.cpu_percent, self.thresholds.sustain_secs, cpu_average
+                ),
+            );
+        }
+
+        if self.memory.update(memory_percent > self.thresholds.memory_percent, now, sustain) {
+            self.push(
+                AlertKind::HighMemory,
+                format!(
+                    "Memory above {:.0}% for {}s ({:.1}%)",
+                    self.thresholds.memory_percent, self.thresholds.sustain_secs, memory_percent
+                ),
+            );
+        }
+    }
+
+    // Tek bir çekirdeğin diğerleri boştayken sürekli dolu olması - tek thread'lik darboğaz
+    // cores: (CPU numarası, kullanım). Her çekirdeğin kendi süresi CPU numarasına göre tutulur,
+    // böylece bir çekirdek kapanıp sıralar kayınca (hotplug) süreler karışmaz
+    pub fn evaluate_cores(&mut self, cores: &[(usize, f32)], cpu_average: f32, now: Instant) {
+        let sustain = Duration::from_secs(self.thresholds.sustain_secs);
+        let average_low = cpu_average < self.thresholds.pegged_core_max_average;
+        self.cores.retain(|id, _| cores.iter().any(|(core, _)| core == id));
+        
+        let mut fired = Vec::new();
+        for &(id, usage) in cores {
+            let pegged = average_low && usage > self.thresholds.pegged_core_percent;
+            if self.cores.entry(id).or_default().update(pegged, now, sustain) {
+                fired.push((id, usage));
+            }
+        }
+        for (index, usage) in fired {
+            self.push(
+                AlertKind::PeggedCore,
+                format!(
+                    "CPU{} above {:.0}% for {}s while average is {:.0}% ({:.1}%) - single-threaded load?",
+                    index, self.thresholds.pegged_core_percent, self.thresholds.sustain_secs, cpu_average, usage
+                ),
+            );
+        }
+    }
+    
+    // Sürekli yüksek swap-in hızı - bellek yetmiyor, sayfalar sürekli diskten geri okunuyor
+    // Hız bilinmiyorsa (Linux dışı) koşul hiç tetiklenmez
+    pub fn evaluate_swap(&mut self, swap_in_per_sec: Option<u64>, now: Instant) {
+        let sustain = Duration::from_secs(self.thresholds.sustain_secs);
+        let threshold = self.thresholds.swap_in_kib_per_sec * 1024;
+        let active = swap_in_per_sec.is_some_and(|rate| rate > threshold);
+        if self.swap.update(active, now, sustain) {
+            self.push(
+                AlertKind::SwapThrashing,
+                format!(
+                    "Swap-in above {} KiB/s for {}s ({} KiB/s) - memory thrashing?",
+                    self.thresholds.swap_in_kib_per_sec,
+                    self.thresholds.sustain_secs,
+                    swap_in_per_sec.unwrap_or(0) / 1024
+                ),
+            );
+        }
+    }
+    
+    // Bu CPU numarası için "tek başına dolu" uyarısı sürüyor mu
+    pub fn is_core_pegged(&self, cpu_id: usize) -> bool {
+        self.cores.get(&cpu_id).is_some_and(SustainedCondition::is_firing)
+    }
+    
+    // Disk ve sıcaklık gibi kritik durumları kontrol et
+    // Bunlar zaten ciddi durumlar olduğu için süre şartı aranmaz, ama olaylar sadece
+    // kritik duruma giriş ve çıkışta üretilir - aynı koşul her tick tekrar bildirilmez
+    // "Dolu" disk, disk tablosunda kırmızı görünen seviyeyle aynıdır (renk eşikleri)
+    pub fn evaluate_critical(
+        &mut self,
+        disks: &[DiskInfo],
+        temperatures: &[TemperatureInfo],
+        colors: &ColorThresholds,
+    ) -> Vec<CriticalEvent> {
+        let mut current: BTreeMap<(AlertKind, String), String> = BTreeMap::new();
+        
+        for disk in disks {
+            if categorize_disk_usage(disk.usage_percent, colors) == DiskUsageCategory::Full {
+                current.insert(
+                    (AlertKind::DiskFull, disk.mount_point.clone()),
+                    format!("Disk {} is {:.0}% full", disk.mount_point, disk.usage_percent),
+                );
+            }
+        }
+        
+        for temp in temperatures {
+            if let Some(critical) = temp.critical_temp {
+                if temp.current_temp >= critical {
+                    current.insert(
+                        (AlertKind::CriticalTemperature, temp.component_name.clone()),
+                        format!(
+                            "{} at {:.0}°C (critical {:.0}°C)",
+                            temp.component_name, temp.current_temp, critical
+                        ),
+                    );
+                }
+            }
+        }
+        
+        let mut events = Vec::new();
+        
+        // Yeni kritik duruma girenler
+        for ((kind, source), message) in &current {
+            if !self.critical.contains(&(*kind, source.clone())) {
+                self.push(*kind, message.clone());
+                events.push(CriticalEvent::Entered { kind: *kind, message: message.clone() });
+            }
+        }
+        
+        // Kritik durumdan çıkanlar
+        for (kind, source) in &self.critical {
+            if !current.contains_key(&(*kind, source.clone())) {
+                events.push(CriticalEvent::Cleared {
+                    kind: *kind,
+                    message: format!("{} back to normal", source),
+                });
+            }
+        }
+        
+        self.critical = current.into_keys().collect();
+        events
+    }
+    
+    // Verilen türdeki koşul şu anda aktif mi
+    pub fn is_active(&self, kind: AlertKind) -> bool {
+        match kind {
+            AlertKind::HighCpu => self.cpu.is_firing(),
+            AlertKind::HighMemory => self.memory.is_firing(),
+            AlertKind::SwapThrashing => self.swap.is_firing(),
+            AlertKind::PeggedCore => self.cores.values().any(SustainedCondition::is_firing),
+            AlertKind::DiskFull | AlertKind::CriticalTemperature => {
+                self.critical.iter().any(|(k, _)| *k == kind)
+            }
+        }
+    }
+
+    fn push(&mut self, kind: AlertKind, message: String) {
+        self.alerts.push_back(Alert {
+            kind,
+            message,
+            timestamp: Local::now(),
+        });
+        self.fired += 1;
+        if self.alerts.len() > MAX_ALERTS {
+            self.alerts.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor() -> AlertMonitor {
+        AlertMonitor::new(AlertThresholds {
+            cpu_percent: 90.0,
+            memory_percent: 95.0,
+            sustain_secs: 5,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_transient_spike_does_not_fire() {
+        let mut monitor = monitor();
+        let start = Instant::now();
+        monitor.evaluate(99.0, 10.0, start);
+        monitor.evaluate(99.0, 10.0, start + Duration::from_secs(3));
+        monitor.evaluate(20.0, 10.0, start + Duration::from_secs(4));
+        monitor.evaluate(99.0, 10.0, start + Duration::from_secs(6));
+        assert!(monitor.alerts.is_empty());
+        assert!(!monitor.is_active(AlertKind::HighCpu));
+    }
+
+    #[test]
+    fn test_sustained_condition_fires_once() {
+        let mut monitor = monitor();
+        let start = Instant::now();
+        for secs in 0..=10 {
+            monitor.evaluate(95.0, 10.0, start + Duration::from_secs(secs));
+        }
+        assert_eq!(monitor.alerts.len(), 1);
+        assert_eq!(monitor.alerts[0].kind, AlertKind::HighCpu);
+        assert!(monitor.is_active(AlertKind::HighCpu));
+    }
+
+    #[test]
+    fn test_condition_rearms_after_clearing() {
+        let mut monitor = monitor();
+        let start = Instant::now();
+        monitor.evaluate(10.0, 99.0, start);
+        monitor.evaluate(10.0, 99.0, start + Duration::from_secs(5));
+        monitor.evaluate(10.0, 50.0, start + Duration::from_secs(6));
+        assert!(!monitor.is_active(AlertKind::HighMemory));
+        monitor.evaluate(10.0, 99.0, start + Duration::from_secs(7));
+        monitor.evaluate(10.0, 99.0, start + Duration::from_secs(12));
+        assert_eq!(monitor.alerts.len(), 2);
+    }
+
+    #[test]
+    fn test_pegged_core_fires_per_core() {
+        let mut monitor = monitor();
+        let start = Instant::now();
+        // 8 çekirdekten biri dolu, ortalama ~14%
+        let mut cores: Vec<(usize, f32)> = (0..8).map(|id| (id, 2.0)).collect();
+        cores[3].1 = 99.0;
+        for secs in 0..=5 {
+            monitor.evaluate_cores(&cores, 14.0, start + Duration::from_secs(secs));
+        }
+        assert_eq!(monitor.alerts.len(), 1);
+        assert_eq!(monitor.alerts[0].kind, AlertKind::PeggedCore);
+        assert!(monitor.alerts[0].message.starts_with("CPU3 "));
+        assert!(monitor.is_core_pegged(3));
+        assert!(!monitor.is_core_pegged(0));
+        
+        // Tüm makine yüklüyse tek çekirdek uyarısı anlamsız - durum sıfırlanır
+        let busy: Vec<(usize, f32)> = (0..8).map(|id| (id, 99.0)).collect();
+        monitor.evaluate_cores(&busy, 99.0, start + Duration::from_secs(6));
+        assert!(!monitor.is_active(AlertKind::PeggedCore));
+        assert_eq!(monitor.alerts.len(), 1);
+    }
+
+    fn disk(mount_point: &str, usage_percent: f32) -> DiskInfo {
+        DiskInfo {
+            name: "sda1".to_string(),
+            mount_point: mount_point.to_string(),
+            total_space: 100,
+            available_space: 0,
+            used_space: 100,
+            usage_percent,
+            file_system: "ext4".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_sustained_swap_in_fires() {
+        let mut monitor = monitor();
+        let start = Instant::now();
+        let busy = Some(4 * 1024 * 1024);
+        // Hızı bilinmeyen platformda hiç tetiklenmez
+        monitor.evaluate_swap(None, start);
+        monitor.evaluate_swap(busy, start + Duration::from_secs(1));
+        monitor.evaluate_swap(busy, start + Duration::from_secs(4));
+        assert!(!monitor.is_active(AlertKind::SwapThrashing));
+        monitor.evaluate_swap(busy, start + Duration::from_secs(6));
+        assert!(monitor.is_active(AlertKind::SwapThrashing));
+        assert_eq!(monitor.alerts.len(), 1);
+        assert!(monitor.alerts[0].message.contains("4096 KiB/s"));
+        
+        monitor.evaluate_swap(Some(0), start + Duration::from_secs(7));
+        assert!(!monitor.is_active(AlertKind::SwapThrashing));
+    }
+    
+    #[test]
+    fn test_critical_events_only_on_transitions() {
+        let mut monitor = monitor();
+        let full = [disk("/", 98.0)];
+        
+        let events = monitor.evaluate_critical(&full, &[], &ColorThresholds::default());
+        assert!(matches!(events.as_slice(), [CriticalEvent::Entered { kind: AlertKind::DiskFull, .. }]));
+        
+        // Aynı durum sürerken yeni olay üretilmemeli
+        assert!(monitor.evaluate_critical(&full, &[], &ColorThresholds::default()).is_empty());
+        assert!(monitor.is_active(AlertKind::DiskFull));
+        
+        let events = monitor.evaluate_critical(&[disk("/", 50.0)], &[], &ColorThresholds::default());
+        assert!(matches!(events.as_slice(), [CriticalEvent::Cleared { kind: AlertKind::DiskFull, .. }]));
+        assert!(!monitor.is_active(AlertKind::DiskFull));
+    }
+
+    #[test]
+    fn test_temperature_crossing_critical() {
+        let mut monitor = monitor();
+        let hot = TemperatureInfo {
+            component_name: "CPU".to_string(),
+            current_temp: 101.0,
+            max_temp: None,
+            critical_temp: Some(100.0),
+        };
+        let events = monitor.evaluate_critical(&[], &[hot], &ColorThresholds::default());
+        assert_eq!(events.len(), 1);
+        assert!(monitor.is_active(AlertKind::CriticalTemperature));
+    }
+}
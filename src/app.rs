@@ -2,74 +2,710 @@
 // Bu dosya tıpkı bir yöneticinin rolünü oynar - tüm bilgileri toplar, düzenler ve sunar
 
 use anyhow::Result;
-use sysinfo::{System, SystemExt, CpuExt, NetworkExt, ProcessExt};
-use std::collections::VecDeque;
+use ratatui::layout::Rect;
+use sysinfo::{PidExt, Signal};
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::alerts::{AlertMonitor, CriticalEvent};
+use crate::battery::{PowerProfiles, PowerSource};
+use crate::clipboard::Clipboard;
+use crate::config::Config;
+use crate::export;
+use crate::health::{self, Health, HealthInputs};
+use crate::input::KeyLabels;
+use crate::notifications;
+use crate::panels::{PanelVisibility, Section};
+use crate::schedule::{Category, Due, RefreshSchedule};
+use crate::diff::{diff_snapshots, SnapshotDiff};
+use crate::snapshot::{ProcessSnapshot, Snapshot};
+use crate::source::{MetricsSource, ProcessSample, Sample};
+use crate::state::ViewState;
+use crate::theme::{Theme, ThemeName};
+use crate::tree::{self, TreeNode};
+use crate::system_info::{categorize_disk_usage, ByteUnits, ColorThresholds, DiskFilter, DiskInfo, DiskUsageCategory, ProcessStates};
+use crate::vmstat::{SwapCounters, PAGE_SIZE};
+
+// Ana ekrandaki paneller - odaklanma ve büyütme (zoom) için kullanılır
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Panel {
+    Cpu,
+    Memory,
+    Processes,
+    Network,
+    DiskIo,
+    Alerts,
+}
+
+impl Panel {
+    // Tab tuşu ile dolaşma sırası - ekrandaki yerleşimle aynı (sol üst → sağ alt)
+    pub const ALL: [Panel; 6] = [
+        Panel::Cpu,
+        Panel::Memory,
+        Panel::Processes,
+        Panel::Network,
+        Panel::DiskIo,
+        Panel::Alerts,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Panel::Cpu => "CPU",
+            Panel::Memory => "Memory",
+            Panel::Processes => "Processes",
+            Panel::Network => "Network",
+            Panel::DiskIo => "Disk I/O",
+            Panel::Alerts => "Alerts",
+        }
+    }
+
+    // Sıradaki panel - sona gelince başa döner
+    pub fn next(&self) -> Panel {
+        let index = Panel::ALL.iter().position(|p| p == self).unwrap_or(0);
+        Panel::ALL[(index + 1) % Panel::ALL.len()]
+    }
+
+    // Önceki panel - Shift+Tab için
+    pub fn previous(&self) -> Panel {
+        let index = Panel::ALL.iter().position(|p| p == self).unwrap_or(0);
+        Panel::ALL[(index + Panel::ALL.len() - 1) % Panel::ALL.len()]
+    }
+}
+
+// Varsayılan yenileme aralığı (ms) - 4 FPS
+pub const DEFAULT_REFRESH_MS: u64 = 250;
+
+// '+' / '-' tuşlarıyla seçilebilen yenileme aralıkları (ms)
+pub const REFRESH_STEPS_MS: [u64; 6] = [100, 250, 500, 1000, 2000, 5000];
+
+// ']' / '[' tuşlarıyla seçilebilen process listesi uzunlukları
+pub const PROCESS_COUNT_STEPS: [usize; 7] = [5, 10, 15, 20, 30, 50, 100];
+
+// Üst kısımdaki sekmeler
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Tab {
+    #[default]
+    Overview,  // CPU, RAM, process'ler, ağ - ana ekran
+    System,    // Diskler, sıcaklıklar ve host bilgileri
+    Processes, // Tüm process'ler - kaydırılabilir, her kolona göre sıralanabilir tablo
+}
+
+impl Tab {
+    pub const ALL: [Tab; 3] = [Tab::Overview, Tab::System, Tab::Processes];
+    
+    pub fn title(&self) -> &'static str {
+        match self {
+            Tab::Overview => "Overview",
+            Tab::System => "System",
+            Tab::Processes => "Processes",
+        }
+    }
+    
+    pub fn index(&self) -> usize {
+        Tab::ALL.iter().position(|t| t == self).unwrap_or(0)
+    }
+}
+
+// Process tablosunun sıralama kriteri
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessSortKey {
+    #[default]
+    Cpu,
+    Memory,
+    Name,
+    RunTime,
+    Pid,
+    Path,
+    DiskRead,  // Okuma hızı (byte/s)
+    DiskWrite, // Yazma hızı (byte/s)
+}
+
+impl ProcessSortKey {
+    // 's' tuşu ile dolaşılan sıra
+    pub fn next(&self) -> ProcessSortKey {
+        match self {
+            ProcessSortKey::Cpu => ProcessSortKey::Memory,
+            ProcessSortKey::Memory => ProcessSortKey::Name,
+            ProcessSortKey::Name => ProcessSortKey::RunTime,
+            ProcessSortKey::RunTime => ProcessSortKey::Pid,
+            ProcessSortKey::Pid => ProcessSortKey::Path,
+            ProcessSortKey::Path => ProcessSortKey::DiskRead,
+            ProcessSortKey::DiskRead => ProcessSortKey::DiskWrite,
+            ProcessSortKey::DiskWrite => ProcessSortKey::Cpu,
+        }
+    }
+}
+
+// Sıralama kriterinde eşit kalan process'lerin sırası - yoksa eşit CPU'lu satırlar
+// her turda yer değiştirip titrer
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TieBreak {
+    #[default]
+    Pid,
+    Name, // Ada göre, aynı addakiler yine PID'e göre
+}
+
+// Eşiğin altındaki (boşta) çekirdeklerin gösterimi - çok çekirdekli makinelerde %0'daki
+// gauge'lar meşgul çekirdekleri gölgeler
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IdleCores {
+    #[default]
+    Show,     // Her çekirdek normal gauge
+    Dim,      // Boştakiler soluk renkte
+    Collapse, // Boştakiler tek satırda özetlenir, sadece meşgul çekirdeklere gauge
+}
+
+impl IdleCores {
+    pub fn next(self) -> Self {
+        match self {
+            IdleCores::Show => IdleCores::Dim,
+            IdleCores::Dim => IdleCores::Collapse,
+            IdleCores::Collapse => IdleCores::Show,
+        }
+    }
+    
+    pub fn label(self) -> &'static str {
+        match self {
+            IdleCores::Show => "show",
+            IdleCores::Dim => "dim",
+            IdleCores::Collapse => "collapse",
+        }
+    }
+}
+
+// Kill popup'ında sunulan sinyaller
+// Windows'ta sinyal kavramı yok - sadece process'i sonlandırmak anlamlı
+#[cfg(not(target_os = "windows"))]
+pub const KILL_SIGNALS: &[(Signal, &str)] = &[
+    (Signal::Term, "TERM (graceful)"),
+    (Signal::Kill, "KILL (force)"),
+    (Signal::Interrupt, "INT (interrupt)"),
+    (Signal::Hangup, "HUP (hang up)"),
+];
+
+#[cfg(target_os = "windows")]
+pub const KILL_SIGNALS: &[(Signal, &str)] = &[(Signal::Kill, "Terminate")];
+
+// Ada göre toplu kill popup'ı - önce desen yazılır, sonra eşleşen liste onaylanır
+#[derive(Debug, Clone)]
+pub struct BulkKillPopup {
+    pub pattern: String,
+    pub confirming: bool, // false: desen düzenleniyor, true: liste gösteriliyor, onay bekleniyor
+}
+
+// Toplu kill'de ayrıca onay isteyen process'ler - öldürülmeleri oturumu veya makineyi düşürür
+const CRITICAL_PROCESS_NAMES: &[&str] = &[
+    "init", "systemd", "systemd-journald", "systemd-logind", "dbus-daemon", "dbus-broker", "sshd",
+    "login", "Xorg", "Xwayland", "gnome-shell", "kwin_wayland", "kwin_x11", "launchd",
+    "WindowServer", "loginwindow", "csrss.exe", "wininit.exe", "winlogon.exe", "explorer.exe",
+];
+
+// Footer'da geçici olarak gösterilen durum mesajının ekranda kalma süresi
+const STATUS_MESSAGE_DURATION: Duration = Duration::from_secs(5);
+
+// Olay günlüğünde tutulan en fazla kayıt - eskiler düşer
+const MAX_EVENTS: usize = 500;
+
+// Olay günlüğündeki bir kaydın önemi - popup'ta renge dönüşür
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Severity::Info => "INFO",
+            Severity::Warning => "WARN",
+            Severity::Error => "ERROR",
+        }
+    }
+}
+
+// Oturum boyunca olanların kaydı - footer'daki mesaj birkaç saniyede kaybolur, bu kalır
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub timestamp: chrono::DateTime<chrono::Local>,
+    pub severity: Severity,
+    pub text: String,
+}
+
+// Kill işlemi için açılan sinyal seçim popup'ı
+#[derive(Debug, Clone)]
+pub struct KillPopup {
+    pub pid: u32,
+    pub name: String,
+    pub selected: usize, // KILL_SIGNALS içindeki seçili sinyalin indeksi
+}
+
+// Detay popup'ında gösterilen, bir process hakkında bilinen her şey
+// Process kapansa bile popup son bilinen değerleri göstermeye devam edebilsin diye kopyalıyoruz
+#[derive(Debug, Clone)]
+pub struct ProcessDetails {
+    pub pid: u32,
+    pub parent_pid: Option<u32>,
+    pub name: String,
+    pub status: String,
+    pub cpu_usage: f32,
+    pub memory: u64,
+    pub virtual_memory: u64,
+    pub start_time: u64, // Epoch saniyesi
+    pub run_time: u64,   // Saniye
+    pub exe: String,
+    pub cmd: Vec<String>,
+    pub exited: bool,    // Popup açıkken process sonlandı mı
+}
+
+impl ProcessDetails {
+    fn from_sample(process: &ProcessSample, now: u64) -> Self {
+        Self {
+            pid: process.pid,
+            parent_pid: process.parent_pid,
+            name: process.name.clone(),
+            status: process.status.clone(),
+            cpu_usage: process.cpu_usage,
+            memory: process.memory,
+            virtual_memory: process.virtual_memory,
+            start_time: process.start_time,
+            run_time: process_run_time(process.start_time, now),
+            exe: process.exe.clone(),
+            cmd: process.cmd.clone(),
+            exited: false,
+        }
+    }
+}
+
+// Kullanıcıya kısa süreliğine gösterilen durum mesajı (örn. kill sonucu)
+#[derive(Debug, Clone)]
+pub struct StatusMessage {
+    pub text: String,
+    pub is_error: bool,
+    pub created_at: Instant,
+}
 
 // Uygulamamızın tüm durumunu tutan ana struct
 // Rust'ta struct'lar hem veri hem de davranış (method) barındırabilir
 pub struct App {
-    // Sistem bilgilerini almak için sysinfo'nun System struct'ını kullanacağız
-    pub system: System,
+    // Metriklerin geldiği yer - canlı sistem veya kayıttan oynatma
+    source: Box<dyn MetricsSource>,
+    
+    // Kaynaktan gelen son örnek - anlık değerler (RAM, process'ler, diskler...) buradan okunur
+    pub sample: Sample,
+    
+    // Son örnekte eksik/anlamsız gelen ve önceki iyi değerle doldurulan alanlar
+    pub data_gaps: DataGaps,
+    // Kaynak şu an hata veriyor mu - günlüğe her turda değil, sadece durum değişince yazılır
+    source_failing: bool,
+    
+    // Kategori başına yenileme zamanlayıcısı ([refresh]) - zamanı gelmeyenler önceki değerlerini korur
+    schedule: RefreshSchedule,
+    
+    // Son başarılı örneğin alındığı an - donukken veya kaynak takıldığında "Xs ago" büyür
+    pub last_refresh: Instant,
+    // Başarılı yenileme sayacı - başlıktaki nabız göstergesi bununla ilerler, donukken durur
+    pub refresh_ticks: u64,
+    pub heartbeat: bool,
     
     // CPU kullanım geçmişini tutmak için - grafikler çizmek için gerekli
     // VecDeque bir çift yönlü kuyruk, hem baştan hem sondan eleman ekleyip çıkarabiliriz
     pub cpu_history: VecDeque<Vec<f32>>, // Her indeks bir çekirdek, değer kullanım yüzdesi
-    pub cpu_history_len: usize,          // Kaç saniye geçmiş tutacağımız
+    pub cpu_history_len: usize,          // Geçmişte tutulan örnek sayısı - history_seconds / yenileme aralığı
+    pub history_seconds: u64,            // Grafiklerin kapsadığı süre
+    pub cpu_smoothing: usize,            // CPU grafiğindeki kayan ortalamanın örnek sayısı (1 = yok)
+    pub uptime_warning_days: u64,        // Başlıktaki uptime'ın renklendiği eşik (0 = kapalı)
+    
+    // Çekirdek başına şimdiye kadar görülen en yüksek frekans (MHz) - anlık değer örnekte
+    // Anlık değerin en yüksekten belirgin düşük olması thermal throttling işareti olabilir
+    pub cpu_max_frequencies: Vec<u64>,
+    // cpu_history sütunlarının ve gauge'ların gerçek CPU numaraları - hotplug'da değişir
+    pub cpu_ids: Vec<usize>,
     
     // RAM kullanımı için geçmiş verileri
     pub memory_history: VecDeque<(u64, u64)>, // (kullanılan, toplam) formatında
     
     // Ağ trafiği için - indirme ve yükleme hızlarını izlemek
     pub network_history: VecDeque<(u64, u64)>, // (indirme, yükleme) byte/s
+    // Arayüz başına aynı geçmiş ve önceki sayaçlar - arayüz kaybolunca geçmişi de atılır
+    pub interface_history: HashMap<String, VecDeque<(u64, u64)>>,
+    prev_interface_counters: HashMap<String, (u64, u64)>,
+    // Ağ grafiğinde gösterilen arayüz - None tüm arayüzlerin toplamı
+    pub selected_interface: Option<String>,
     
     // Önceki ağ verilerini tutuyoruz - hız hesaplamak için fark almamız gerekir
     pub prev_network_data: Option<(u64, u64)>,
+    // İlk hesaplanan ağ hızı atıldı mı - bkz. update_network_data
+    network_primed: bool,
+    
+    // Byte değerleri 1024 (KiB) mi 1000 (KB) tabanlı mı gösterilsin
+    pub byte_units: ByteUnits,
+    
+    // Ağ hızları bit/s olarak mı gösterilsin (Mbps), yoksa byte/s mi (MB/s)
+    pub network_bits: bool,
+    // Ağ grafiğinin y ekseni logaritmik mi - boşta KB/s ile patlamada yüzlerce MB/s aynı grafikte
+    pub network_log_scale: bool,
+    
+    // Emoji ve Braille yerine ASCII karşılıkları (config: ascii_only)
+    pub ascii_only: bool,
+    // Ağ panelinde hızlar yerine arayüzlerin ham kümülatif sayaçları ('n')
+    pub network_counters: bool,
+    
+    // Uygulama açıldığından beri indirilen/yüklenen toplam veri
+    pub network_received_total: TransferCounter,
+    pub network_transmitted_total: TransferCounter,
+    
+    // Disk I/O hızları - tüm process'lerin okuma/yazma toplamından hesaplanır
+    pub disk_io_history: VecDeque<(u64, u64)>, // (okuma, yazma) byte/s
+    pub prev_disk_io_data: Option<(u64, u64)>,
+    
+    // Process başına disk okuma/yazma hızları (byte/s) ve hesap için önceki kümülatif sayaçlar
+    // PID'e göre; kapanan process'ler her turda atılır
+    pub process_io_rates: HashMap<u32, (u64, u64)>,
+    prev_process_io: HashMap<u32, (u64, u64)>,
+    
+    // Swap giriş/çıkış hızları (byte/s) - sayaçlar sadece Linux'ta var, diğerlerinde None
+    pub swap_rates: Option<(u64, u64)>,
+    prev_swap_counters: Option<SwapCounters>,
     
     // CPU kullanımının moving average'ı - anlık dalgalanmaları yumuşatmak için
     pub cpu_average: f32,
-    pub cpu_scroll: usize, // yeni
+    
+    // Açılıştan (veya son geçmiş sıfırlamasından) beri görülen en yüksek değerler
+    pub peaks: Peaks,
+    
+    // Son dakikada CPU'da ilk sıralara giren process adlarının sayımı
+    pub offenders: OffenderTally,
+    
+    pub cpu_scroll: usize, // CPU gauge listesinin kaydırma miktarı (ilk görünen satır)
+    
+    // Aktif sekme, renk teması ve yenileme aralığı - oturumlar arasında saklanır
+    pub active_tab: Tab,
+    pub theme: ThemeName,
+    pub refresh_ms: u64,
+    
+    // Odaklanılan panel ve bu panelin tüm içerik alanını kaplayıp kaplamadığı
+    pub focused_panel: Panel,
+    pub zoomed: bool,
+    // Sade düzeni zorla - terminal küçükse zaten otomatik açılır
+    pub compact: bool,
+    
+    // Çekirdekleri tek tek gauge yerine hücre başına bir çekirdek olan ısı haritası olarak çiz
+    // Çok çekirdekli makinelerde tüm çekirdekler kaydırmadan tek bakışta görünür
+    pub core_heatmap: bool,
+    
+    // Boştaki çekirdeklerin gösterimi ('D' ile değişir) ve boşta sayılma eşiği (%)
+    pub idle_cores: IdleCores,
+    pub idle_core_threshold: f32,
+    
+    // Process tablosunun sıralama kriteri ve seçili satır
+    pub process_sort: ProcessSortKey,
+    pub selected_process: usize,
+    // Processes sekmesindeki tam tablonun seçimi ve kaydırması - ilk 10 tablosundan bağımsız
+    // Kaydırma çizim sırasında TableState'ten geri yazılır, bir sonraki karede oradan devam edilir
+    pub selected_full_process: usize,
+    pub full_process_offset: Cell<usize>,
+    // Processes sekmesi ağaç olarak mı gösteriliyor, ebeveynlerde alt ağaç toplamları ve
+    // çocukları gizlenen (kapalı) düğümlerin PID'leri
+    pub process_tree: bool,
+    pub tree_totals: bool,
+    pub collapsed_pids: HashSet<u32>,
+    // Salt okunur mod - sinyal gönderen eylemler girdi katmanında reddedilir
+    pub read_only: bool,
+    // Sabitlenen process'ler - ilk N'e girmeseler de tabloda vurgulu görünürler,
+    // process kapanınca sabitleme kendiliğinden kalkar
+    pub pinned_pids: HashSet<u32>,
+    
+    // Tabloda gösterilecek process sayısı - 0 panele sığdığı kadar demek
+    pub process_count: usize,
+    
+    // "Network by Process" listesinde gösterilecek process sayısı
+    pub network_talkers: usize,
+    
+    // Process listesinden gizlenecekler - isim desenleri ve monitörün kendi PID'i
+    pub ignore_processes: Vec<String>,
+    pub hide_self: bool,
+    pub own_pid: Option<u32>,
+    pub tie_break: TieBreak,
+    pub process_filter_enabled: bool,
+    
+    // Açık popup ve footer'daki geçici mesaj
+    pub kill_popup: Option<KillPopup>,
+    pub bulk_kill_popup: Option<BulkKillPopup>,
+    pub detail_popup: Option<ProcessDetails>,
+    
+    // En çok bellek kullanan process'ler popup'ı açık mı - içerik her çizimde güncel örnekten gelir
+    pub memory_popup: bool,
+    // Son dakikada CPU'da sık sık ilk sıralara giren process'ler popup'ı açık mı
+    pub offenders_popup: bool,
+    // Sistem sağlığını kötüleştiren nedenlerin listesi açık mı
+    pub health_popup: bool,
+    // 'B' ile alınan referans snapshot ve ona göre farkların gösterildiği popup
+    pub baseline: Option<Snapshot>,
+    pub diff_popup: bool,
+    // Bölüm görünürlüğü listesi açıksa seçili satır (Section::ALL içindeki indeks)
+    pub panels_popup: Option<usize>,
+    pub status_message: Option<StatusMessage>,
+    
+    // Ekran görüntüsünün kopyalandığı pano - X11'de içerik bağlantı açık kaldıkça durur
+    clipboard: Clipboard,
+    
+    // Olay günlüğü (en yeni sonda) ve açıksa popup'taki kaydırma - en yeniden itibaren satır
+    pub events: VecDeque<LogEntry>,
+    pub events_popup: Option<usize>,
+    
+    // Son çizimde fareyle etkileşilebilen alanlar - tıklamaları satırlara eşlemek için
+    pub hit_areas: Cell<HitAreas>,
+    
+    // Footer'daki tuş etiketleri - main kullanıcının atamalarıyla değiştirir
+    pub key_labels: KeyLabels,
+    
+    // Çıkmadan önce onay istensin mi ve "Quit? (y/n)" popup'ı şu an açık mı
+    pub confirm_quit: bool,
+    pub quit_prompt: bool,
+    
+    // Eşik tabanlı uyarılar - sürekli yüksek CPU/RAM kullanımını yakalar
+    pub alerts: AlertMonitor,
+    // Kritik durumlarda masaüstü bildirimi gönderilsin mi (varsayılan: kapalı)
+    pub notify_on_critical: bool,
+    // Ekran donuk mu - donukken yeni örnek alınmaz. Uyarı yüzünden donduysa nedeni de tutulur
+    pub paused: bool,
+    pub pause_reason: Option<String>,
+    // Bir uyarı tetiklenince kendiliğinden dondur
+    pub pause_on_alert: bool,
+    
+    // Güç kaynağına göre yenileme aralığı - son görülen kaynak ve AC'ye dönünce
+    // geri yüklenecek aralık (ac_ms verilmediyse bataryaya geçmeden önceki aralık)
+    pub power_profiles: PowerProfiles,
+    pub power_source: Option<PowerSource>,
+    ac_refresh_ms: Option<u64>,
+    
+    // Örnekteki disk listesi tüm bağlama noktalarını içerir,
+    // ekranda varsayılan olarak sadece gerçek diskler gösterilir
+    pub disk_filter: DiskFilter,
+    pub show_all_disks: bool,
+    // Diskleri tablo yerine çekirdekler gibi gauge ızgarası olarak çiz
+    pub disk_gauges: bool,
+    
+    // Gauge, sıcaklık ve disk renklerinin değiştiği sınırlar
+    pub color_thresholds: ColorThresholds,
+    
+    // Hangi bölümlerin ekranda olduğu - gizlenenlerin yeri diğerlerine dağıtılır
+    pub panels: PanelVisibility,
+    
+    // Bağlama noktası başına doluluk geçmişi (%) ve tabloda seçili disk
+    // Diskler yavaş dolduğu için DISK_HISTORY_INTERVAL'da bir örnek alınır
+    pub disk_history: HashMap<String, VecDeque<f32>>,
+    last_disk_history: Option<chrono::DateTime<chrono::Local>>,
+    pub selected_disk: usize,
+    
+    // Sensör başına sıcaklık geçmişi (°C) - throttling döngüleri anlık değerde görünmez
+    // Grafiklerle aynı pencerede tutulur; kaybolan sensörlerin geçmişi silinir
+    pub temperature_history: HashMap<String, VecDeque<f32>>,
 }
 
+// Disk doluluk geçmişinin örnekleme aralığı ve uzunluğu - 10 saniyede bir, son 1 saat
+const DISK_HISTORY_INTERVAL_SECS: i64 = 10;
+const DISK_HISTORY_LEN: usize = 360;
+
+// CPU "sık görülenler" sayımı: her turda ilk kaç process, kaç saniyelik pencere ve
+// pencerede en fazla kaç tur (100 ms yenilemede bir dakika = 600 tur)
+pub const OFFENDER_TOP_N: usize = 5;
+const OFFENDER_WINDOW_SECS: i64 = 60;
+const OFFENDER_MAX_TICKS: usize = 600;
+
 impl App {
     // Constructor - yeni bir App instance'ı oluşturur
     // async çünkü sistem bilgilerini ilk kez toplarken zaman alabilir
-    pub async fn new() -> Result<Self> {
-        let mut system = System::new_all();
+    // Görünüm durumu dışarıdan verilir (main'de state dosyasından) - testler diske bağlı kalmasın
+    pub async fn new(config: Config, view_state: ViewState, mut source: Box<dyn MetricsSource>) -> Result<Self> {
+        // İlk örnek - çekirdek sayısı ve sayaçların başlangıç değerleri buradan gelir
+        let mut sample = source.next_sample()?.unwrap_or_default();
+        let data_gaps = repair_sample(&Sample::default(), &mut sample);
+        let mut schedule = RefreshSchedule::new(config.refresh);
+        schedule.mark(Due::ALL, Instant::now(), sample.timestamp);
         
-        // İlk refresh - sistem bilgilerini doldurmak için
-        // System::new_all() boş bir sistem oluşturur, refresh ile doldururuz
-        system.refresh_all();
+        // Kendi PID'imiz sadece örnekler bu makineden geliyorsa anlamlı - kayıtta veya uzak
+        // makinede aynı numara başka bir process'e ait olabilir
+        let own_pid = source
+            .observes_self()
+            .then(|| sysinfo::get_current_pid().ok().map(|pid| pid.as_u32()))
+            .flatten();
         
         // CPU çekirdek sayısını öğreniyoruz - dinamik olarak array boyutu belirleme
-        let cpu_count = system.cpus().len();
+        let cpu_count = sample.cpu_usage.len();
         
-        // Geçmiş için 60 saniye tutacağız (4 FPS * 60 = 240 entry)
-        let history_len = 60 * 4;
+        // Geçmiş süre olarak ayarlanır; kaç örnek tutulacağı yenileme aralığına bağlı
+        let refresh_ms = clamp_refresh_ms(view_state.refresh_ms);
+        let history_seconds = config.history_seconds.max(1);
+        let history_len = history_capacity(history_seconds, refresh_ms);
         
         // Her CPU çekirdeği için başlangıçta 0.0 değeri
         let initial_cpu_data = vec![0.0; cpu_count];
+        let cpu_ids = cpu_ids_of(&sample);
+        
+        // Açılıştaki ağ sayaçları - "açılıştan beri" toplamlarının başlangıç noktası
+        let (initial_received, initial_transmitted) = (sample.network_received, sample.network_transmitted);
+        let initial_swap = sample.swap_counters;
+        let initial_process_io = process_io_counters(&sample);
         
         let mut app = App {
-            system,
+            source,
+            sample,
+            data_gaps,
+            source_failing: false,
+            last_refresh: Instant::now(),
+            refresh_ticks: 0,
+            heartbeat: config.heartbeat,
+            schedule,
             cpu_history: VecDeque::with_capacity(history_len),
             cpu_history_len: history_len,
+            history_seconds,
+            cpu_smoothing: config.cpu_smoothing,
+            uptime_warning_days: config.uptime_warning_days,
+            cpu_max_frequencies: vec![0; cpu_count],
+            cpu_ids,
             memory_history: VecDeque::with_capacity(history_len),
             network_history: VecDeque::with_capacity(history_len),
+            interface_history: HashMap::new(),
+            prev_interface_counters: HashMap::new(),
+            selected_interface: None,
             prev_network_data: None,
+            network_primed: false,
+            byte_units: config.byte_units,
+            network_bits: config.network_bits,
+            network_log_scale: config.network_log_scale,
+            ascii_only: config.ascii_only,
+            network_counters: false,
+            network_received_total: TransferCounter::new(initial_received),
+            network_transmitted_total: TransferCounter::new(initial_transmitted),
+            disk_io_history: VecDeque::with_capacity(history_len),
+            prev_disk_io_data: None,
+            process_io_rates: HashMap::new(),
+            prev_process_io: initial_process_io,
+            swap_rates: None,
+            prev_swap_counters: initial_swap,
             cpu_average: 0.0,
-            cpu_scroll: 0, // yeni
+            peaks: Peaks::default(),
+            offenders: OffenderTally::default(),
+            cpu_scroll: 0,
+            active_tab: view_state.active_tab,
+            theme: view_state.theme,
+            refresh_ms,
+            focused_panel: Panel::Cpu,
+            zoomed: false,
+            compact: false,
+            core_heatmap: false,
+            idle_cores: config.idle_cores,
+            idle_core_threshold: config.idle_core_threshold,
+            process_sort: view_state.process_sort,
+            selected_process: 0,
+            selected_full_process: 0,
+            full_process_offset: Cell::new(0),
+            process_tree: false,
+            tree_totals: config.process_tree_totals,
+            collapsed_pids: HashSet::new(),
+            pinned_pids: HashSet::new(),
+            read_only: config.read_only,
+            process_count: config.process_count,
+            network_talkers: config.network_talkers,
+            hit_areas: Cell::new(HitAreas::default()),
+            key_labels: KeyLabels::default(),
+            ignore_processes: config.ignore_processes,
+            hide_self: config.hide_self,
+            own_pid,
+            tie_break: config.process_tie_break,
+            process_filter_enabled: true,
+            kill_popup: None,
+            bulk_kill_popup: None,
+            detail_popup: None,
+            memory_popup: false,
+            offenders_popup: false,
+            health_popup: false,
+            baseline: None,
+            diff_popup: false,
+            panels_popup: None,
+            status_message: None,
+            clipboard: Clipboard::default(),
+            events: VecDeque::new(),
+            events_popup: None,
+            confirm_quit: config.confirm_quit,
+            quit_prompt: false,
+            alerts: AlertMonitor::new(config.alerts),
+            notify_on_critical: config.notify_on_critical,
+            paused: false,
+            pause_reason: None,
+            pause_on_alert: config.pause_on_alert,
+            power_profiles: config.power,
+            power_source: None,
+            ac_refresh_ms: None,
+            disk_filter: config.disks,
+            show_all_disks: false,
+            disk_gauges: false,
+            color_thresholds: config.thresholds,
+            panels: config.panels,
+            disk_history: HashMap::new(),
+            temperature_history: HashMap::new(),
+            last_disk_history: None,
+            selected_disk: 0,
         };
         
         // İlk CPU verilerini kuyruğa ekle
         app.cpu_history.push_back(initial_cpu_data);
+        // Sıcaklıklar anlık okunur - ilk örnekteki değerler de geçerlidir
+        app.update_temperature_history();
         
         Ok(app)
     }
     
     // Sistem bilgilerini güncelleyen method - her frame'de çağrılacak
     pub async fn update(&mut self) -> Result<()> {
-        // Sistem verilerini yenile - bu CPU, RAM, disk, ağ bilgilerini günceller
-        self.system.refresh_all();
+        // Kaynaktan yeni örnek iste - oynatmada sıradaki örneğin zamanı gelmemiş olabilir
+        // Kaynak hata verirse uygulama kapanmaz; son iyi değerler ekranda kalır ve
+        // paneller verinin güncel olmadığını gösterir. Bir sonraki turda tekrar denenir
+        // Her kategori kendi aralığıyla tazelenir; zamanı gelmeyenler önceki örnekten taşınır
+        let now = Instant::now();
+        let due = self.schedule.due(now);
+        let mut sample = match self.source.next_sample_for(due) {
+            Ok(Some(sample)) => sample,
+            Ok(None) => return Ok(()),
+            Err(err) => {
+                self.data_gaps = DataGaps { cpu: true, memory: true };
+                let text = format!("Data unavailable: {err}");
+                if self.source_failing {
+                    // Hata sürüyor - mesaj ekranda tazelenir, günlük aynı kayıtla dolmaz
+                    self.status_message = Some(StatusMessage { text, is_error: true, created_at: Instant::now() });
+                } else {
+                    self.source_failing = true;
+                    self.set_status(text, true);
+                }
+                return Ok(());
+            }
+        };
+        if self.source_failing {
+            self.source_failing = false;
+            self.set_status("Data source recovered".to_string(), false);
+        }
+        carry_over(&self.sample, &mut sample, due);
+        self.data_gaps = repair_sample(&self.sample, &mut sample);
+        
+        // Kategorinin önceki tazelenmesinden bu yana geçen süre - örneklerin kendi zaman
+        // damgalarından; oynatmada duvar saati değil kaydın zamanı geçerlidir
+        let elapsed = |category| self.schedule.elapsed_secs(category, sample.timestamp);
+        let (network_secs, disk_secs, memory_secs, process_secs) =
+            (elapsed(Category::Network), elapsed(Category::Disk), elapsed(Category::Memory), elapsed(Category::Processes));
+        self.schedule.mark(due, now, sample.timestamp);
+        self.sample = sample;
+        self.last_refresh = now;
+        self.refresh_ticks = self.refresh_ticks.wrapping_add(1);
         
         // CPU bilgilerini güncelle
         self.update_cpu_data();
@@ -77,20 +713,126 @@ impl App {
         // RAM bilgilerini güncelle  
         self.update_memory_data();
         
-        // Ağ bilgilerini güncelle
-        self.update_network_data();
+        // Ağ bilgilerini güncelle - tazelenmeyen turlarda grafik son hızı tekrarlar ki zaman
+        // ekseni diğer grafiklerle hizalı kalsın
+        if due.contains(Category::Network) {
+            self.update_network_data(network_secs);
+        } else {
+            hold_last_rate(&mut self.network_history, self.cpu_history_len);
+            for history in self.interface_history.values_mut() {
+                hold_last_rate(history, self.cpu_history_len);
+            }
+        }
+        
+        // Disk I/O bilgilerini güncelle
+        if due.contains(Category::Disk) {
+            self.update_disk_io_data(disk_secs);
+        } else {
+            hold_last_rate(&mut self.disk_io_history, self.cpu_history_len);
+        }
+        if due.contains(Category::Memory) {
+            self.update_swap_rates(memory_secs);
+        }
+        if due.contains(Category::Processes) {
+            self.update_process_io_rates(process_secs);
+        }
+        self.update_disk_history();
+        self.update_temperature_history();
+        
+        // Fişe takılınca veya çekilince yenileme aralığını o kaynağın profiline geçir
+        self.apply_power_profile();
+        
+        // Kapanan process'lerin sabitlemesini kaldır - PID daha sonra başka bir process'e verilebilir
+        if due.contains(Category::Processes) && !self.pinned_pids.is_empty() {
+            let alive: HashSet<u32> = self.sample.processes.iter().map(|p| p.pid).collect();
+            self.pinned_pids.retain(|pid| alive.contains(pid));
+        }
+        
+        // Bu turda CPU'da ilk sıralarda olanları say
+        self.update_offenders();
+        
+        // Detay popup'ı açıksa içindeki değerleri tazele
+        self.refresh_detail_popup();
+        
+        // Güncel değerlere göre uyarı eşiklerini kontrol et
+        let fired_before = self.alerts.fired;
+        let memory_percent = self.memory_usage_percent();
+        let (download_speed, upload_speed) = self.network_history.back().copied().unwrap_or((0, 0));
+        self.peaks.record(self.cpu_average, memory_percent, download_speed, upload_speed);
+        self.alerts.evaluate(self.cpu_average, memory_percent, now);
+        let cores: Vec<(usize, f32)> = self.cpu_ids.iter().copied().zip(self.sample.cpu_usage.iter().copied()).collect();
+        self.alerts.evaluate_cores(&cores, self.cpu_average, now);
+        self.alerts.evaluate_swap(self.swap_rates.map(|(swap_in, _)| swap_in), now);
+        
+        // Disk ve sıcaklıklarda kritik durumları kontrol et
+        // Sahte dosya sistemleri (dolu bir tmpfs gibi) uyarı üretmesin
+        let physical_disks = self.disk_filter.physical(&self.sample.disks);
+        let events = self.alerts.evaluate_critical(&physical_disks, &self.sample.temperatures, &self.color_thresholds);
+        if self.notify_on_critical {
+            events.iter().for_each(notifications::notify);
+        }
+        
+        // Bu turda tetiklenen uyarılar ve kritik durumdan çıkışlar günlüğe
+        let fired = (self.alerts.fired - fired_before) as usize;
+        let new_alerts: Vec<String> = self.alerts.alerts.iter().rev().take(fired).rev().map(|alert| alert.message.clone()).collect();
+        for message in new_alerts {
+            self.log_event(Severity::Warning, message);
+        }
+        for event in events {
+            if let CriticalEvent::Cleared { message, .. } = event {
+                self.log_event(Severity::Info, message);
+            }
+        }
+        
+        // Yeni bir uyarı tetiklendiyse ekranı dondur - gösterilen durum onu tetikleyen durumdur
+        if self.pause_on_alert && self.alerts.fired > fired_before {
+            self.paused = true;
+            self.pause_reason = self.alerts.alerts.back().map(|alert| alert.message.clone());
+        }
         
         Ok(())
     }
     
+    // Güç kaynağı değiştiyse (veya ilk kez görüldüyse) profilin aralığına geç ve günlüğe yaz
+    // Açılışta AC'de olmak bir geçiş değildir, sadece aralık uygulanır
+    fn apply_power_profile(&mut self) {
+        if !self.power_profiles.enabled() {
+            return;
+        }
+        let source = PowerSource::detect(&self.sample.batteries);
+        let previous = self.power_source;
+        if previous == Some(source) {
+            return;
+        }
+        self.power_source = Some(source);
+        let refresh_ms = match source {
+            PowerSource::Ac if self.power_profiles.ac_ms != 0 => self.power_profiles.ac_ms,
+            PowerSource::Ac => self.ac_refresh_ms.unwrap_or(self.refresh_ms),
+            PowerSource::Battery => {
+                self.ac_refresh_ms = Some(self.refresh_ms);
+                match self.power_profiles.battery_ms {
+                    0 => self.refresh_ms,
+                    battery_ms => battery_ms,
+                }
+            }
+        };
+        self.set_refresh_ms(refresh_ms);
+        if previous.is_some() || source == PowerSource::Battery {
+            let text = format!("On {} power, refreshing every {} ms", source.label(), self.refresh_ms);
+            self.log_event(Severity::Info, text);
+        }
+    }
+    
     // CPU verilerini güncelleyen private method
     fn update_cpu_data(&mut self) {
+        // Bir çekirdek kapandı veya açıldıysa geçmişi yeni düzene uyarla
+        let cpu_ids = cpu_ids_of(&self.sample);
+        if cpu_ids != self.cpu_ids {
+            self.remap_cpu_layout(cpu_ids);
+        }
+        
         // Her CPU çekirdeğinin kullanımını bir vector'e topluyoruz
-        let cpu_usage: Vec<f32> = self.system
-            .cpus()
-            .iter()
-            .map(|cpu| cpu.cpu_usage()) // Her çekirdeğin kullanım yüzdesini al
-            .collect();
+        let cpu_usage: Vec<f32> = self.sample.cpu_usage.clone();
         
         // Geçmiş verilerimize yeni veriyi ekliyoruz
         self.cpu_history.push_back(cpu_usage.clone());
@@ -101,15 +843,168 @@ impl App {
             self.cpu_history.pop_front();
         }
         
+        // Frekansların en yüksek değerlerini sakla
+        self.cpu_max_frequencies.resize(self.sample.cpu_frequencies.len(), 0);
+        for (max, &current) in self.cpu_max_frequencies.iter_mut().zip(&self.sample.cpu_frequencies) {
+            *max = (*max).max(current);
+        }
+        
         // Ortalama CPU kullanımını hesapla - tüm çekirdeklerin ortalaması
         // iter() → sum() → fold işlemi functional programming yaklaşımı
-        self.cpu_average = cpu_usage.iter().sum::<f32>() / cpu_usage.len() as f32;
+        // Hiç çekirdek raporlanmadıysa 0'a bölüp NaN üretmeyelim
+        self.cpu_average = if cpu_usage.is_empty() {
+            0.0
+        } else {
+            cpu_usage.iter().sum::<f32>() / cpu_usage.len() as f32
+        };
+    }
+    
+    // Çekirdek düzeni değişti (hotplug) - geçmişteki her örneği ve en yüksek frekansları
+    // CPU numarasına göre yeni sıraya taşı. Yeni açılan çekirdeğin geçmişi yoktur; o turun
+    // diğer çekirdeklerinin ortalamasıyla doldurulur ki grafikteki ortalama sıçramasın
+    fn remap_cpu_layout(&mut self, cpu_ids: Vec<usize>) {
+        let old_ids = std::mem::replace(&mut self.cpu_ids, cpu_ids);
+        let position = |id: &usize| old_ids.iter().position(|old| old == id);
+        
+        for usage in self.cpu_history.iter_mut() {
+            let average = if usage.is_empty() { 0.0 } else { usage.iter().sum::<f32>() / usage.len() as f32 };
+            *usage = self.cpu_ids
+                .iter()
+                .map(|id| position(id).and_then(|index| usage.get(index).copied()).unwrap_or(average))
+                .collect();
+        }
+        self.cpu_max_frequencies = self.cpu_ids
+            .iter()
+            .map(|id| position(id).and_then(|index| self.cpu_max_frequencies.get(index).copied()).unwrap_or(0))
+            .collect();
+        
+        let describe = |ids: Vec<String>, change: &str| {
+            (!ids.is_empty()).then(|| format!("CPU {} {}", ids.join(", "), change))
+        };
+        let offline = describe(
+            old_ids.iter().filter(|id| !self.cpu_ids.contains(id)).map(|id| id.to_string()).collect(),
+            "went offline",
+        );
+        let online = describe(
+            self.cpu_ids.iter().filter(|id| !old_ids.contains(id)).map(|id| id.to_string()).collect(),
+            "came online",
+        );
+        let changes: Vec<String> = offline.into_iter().chain(online).collect();
+        if !changes.is_empty() {
+            self.set_status(changes.join(", "), false);
+        }
+    }
+    
+    // Sıradaki çekirdeğin gerçek CPU numarası
+    pub fn cpu_id(&self, index: usize) -> usize {
+        self.cpu_ids.get(index).copied().unwrap_or(index)
+    }
+    
+    // Tüm process listesini zaman damgalı bir CSV dosyasına yaz - sonuç durum çubuğunda
+    // Ekrandaki listenin sınırı ve gizleme filtresi uygulanmaz, örnekteki her process yazılır
+    pub fn export_processes(&mut self) {
+        let csv = export::processes_csv(&self.sample.processes, self.sample_time());
+        match export::write_timestamped("processes", "csv", self.sample.timestamp, &csv) {
+            Ok(path) => self.set_status(
+                format!("Saved {} processes to {}", self.sample.processes.len(), path.display()),
+                false,
+            ),
+            Err(err) => self.set_status(format!("Process export failed: {err}"), true),
+        }
+    }
+    
+    // Grafik geçmişini (çekirdek başına CPU, bellek, ağ) zaman damgalı bir CSV dosyasına yaz
+    pub fn export_charts(&mut self) {
+        let cores = self.cpu_history.iter().map(Vec::len).max().unwrap_or(0);
+        let core_ids: Vec<usize> = (0..cores).map(|index| self.cpu_id(index)).collect();
+        let csv = export::history_csv(
+            &self.cpu_history,
+            &core_ids,
+            &self.memory_history,
+            &self.network_history,
+            self.refresh_ms,
+        );
+        match export::write_timestamped("charts", "csv", self.sample.timestamp, &csv) {
+            Ok(path) => self.set_status(
+                format!("Saved {} chart samples to {}", csv.lines().count() - 1, path.display()),
+                false,
+            ),
+            Err(err) => self.set_status(format!("Chart export failed: {err}"), true),
+        }
+    }
+    
+    // Ekranın metin halini panoya kopyala - pano yoksa (SSH, başsız sunucu, özellik kapalı)
+    // aynı metin zaman damgalı bir dosyaya yazılır ve nedeni alt bilgide görünür
+    pub fn copy_snapshot(&mut self, text: String) {
+        match self.clipboard.copy(&text) {
+            Ok(()) => self.set_status("Copied screen to clipboard".to_string(), false),
+            Err(reason) => match export::write_timestamped("snapshot", "txt", self.sample.timestamp, &text) {
+                Ok(path) => self.set_status(format!("No clipboard ({reason}), saved snapshot to {}", path.display()), false),
+                Err(err) => self.set_status(format!("Copy failed: {reason}; could not write file: {err}"), true),
+            },
+        }
+    }
+    
+    // Tüm grafik geçmişini temizle - bir iş yükü bittikten sonra grafikleri sıfırdan başlatmak için
+    // CPU geçmişine anlık değer tekrar eklenir ki grafik bir tur boyunca boş görünmesin.
+    // Hız hesabındaki önceki sayaçlar da silinir; hızlar birkaç tur sonra yeniden hesaplanır
+    pub fn reset_history(&mut self) {
+        self.cpu_history.clear();
+        self.memory_history.clear();
+        self.network_history.clear();
+        self.interface_history.clear();
+        self.disk_io_history.clear();
+        self.temperature_history.clear();
+        self.reset_rate_baselines();
+        self.cpu_average = 0.0;
+        self.peaks = Peaks::default();
+        self.offenders.clear();
+        
+        self.cpu_history.push_back(self.sample.cpu_usage.clone());
+        
+        self.set_status("History cleared".to_string(), false);
+    }
+    
+    // Hız hesabındaki önceki sayaçları unut - sonraki ilk fark tek bir dev ölçüm gibi görünmesin
+    fn reset_rate_baselines(&mut self) {
+        self.prev_network_data = None;
+        self.network_primed = false;
+        self.prev_interface_counters.clear();
+        self.prev_disk_io_data = None;
+        self.prev_swap_counters = None;
+        self.swap_rates = None;
+        self.process_io_rates.clear();
+        self.prev_process_io = process_io_counters(&self.sample);
+    }
+    
+    // Ekrandaki verinin yaşı - son başarılı örnekten bu yana geçen süre
+    pub fn sample_age(&self) -> Duration {
+        self.last_refresh.elapsed()
+    }
+    
+    // Son örnek birkaç yenileme aralığından eskiyse veri canlı değildir
+    pub fn is_stale(&self) -> bool {
+        self.sample_age() > Duration::from_millis(self.refresh_ms.max(self.cpu_sample_ms()) * 3)
+    }
+    
+    // Ekranı dondur / devam ettir
+    // Donukken örnek alınmadığı için devam edince sayaç farkları tüm duraklamayı kapsar;
+    // ağ ve disk hızları tek bir ortalamaya ezilmesin diye sıfırdan hesaplanır
+    pub fn toggle_pause(&mut self) {
+        if self.paused {
+            self.paused = false;
+            self.pause_reason = None;
+            self.reset_rate_baselines();
+            self.set_status("Resumed".to_string(), false);
+        } else {
+            self.paused = true;
+        }
     }
     
     // RAM verilerini güncelleyen method
     fn update_memory_data(&mut self) {
-        let used_memory = self.system.used_memory();
-        let total_memory = self.system.total_memory();
+        let used_memory = self.sample.memory_used;
+        let total_memory = self.sample.memory_total;
         
         // Memory verilerini geçmişe ekle
         self.memory_history.push_back((used_memory, total_memory));
@@ -121,25 +1016,27 @@ impl App {
     }
     
     // Ağ trafiği verilerini güncelleyen method
-    fn update_network_data(&mut self) {
-        // Modern sysinfo API'sinde networks() artık System üzerinde direkt method
-        // Tüm ağ interface'lerinin verilerini topluyoruz
-        let mut total_received = 0;
-        let mut total_transmitted = 0;
+    fn update_network_data(&mut self, elapsed_secs: f64) {
+        self.update_interface_data(elapsed_secs);
+        let (total_received, total_transmitted) = (self.sample.network_received, self.sample.network_transmitted);
         
-        // self.system.networks() tüm ağ arayüzlerini döndürür (eth0, wlan0, vs.)
-        // Yeni API'de Networks struct'ı üzerinden iterate ediyoruz
-        for (_interface_name, network) in self.system.networks() {
-            total_received += network.received();
-            total_transmitted += network.transmitted();
-        }
+        // Açılıştan beri toplamları güncelle
+        self.network_received_total.update(total_received);
+        self.network_transmitted_total.update(total_transmitted);
         
         // Eğer önceki veri varsa, hız hesaplayabiliriz
+        // İlk hesaplanan hız atılır: açılışta arayüz listesi henüz dolmamışken okunan sayaç 0
+        // (veya eksik) olabilir ve bir sonraki okumada açılıştan beri biriken tüm trafik tek bir
+        // kısa aralığa bölünür - grafiğin ve zirve değerin başında saçma bir sıçrama oluşur
         if let Some((prev_received, prev_transmitted)) = self.prev_network_data {
-            // Saniye başına byte hesaplama - delta / time
-            // Burada time = 0.25 saniye (çünkü 4 FPS ile güncelliyoruz)
-            let download_speed = ((total_received.saturating_sub(prev_received) as f64) / 0.25) as u64;
-            let upload_speed = ((total_transmitted.saturating_sub(prev_transmitted) as f64) / 0.25) as u64;
+            if !self.network_primed {
+                self.network_primed = true;
+                self.prev_network_data = Some((total_received, total_transmitted));
+                return;
+            }
+            
+            let download_speed = rate_per_second(total_received, prev_received, elapsed_secs);
+            let upload_speed = rate_per_second(total_transmitted, prev_transmitted, elapsed_secs);
             
             self.network_history.push_back((download_speed, upload_speed));
             
@@ -153,64 +1050,2338 @@ impl App {
         self.prev_network_data = Some((total_received, total_transmitted));
     }
     
-    // UI'nin kullanabileceği yardımcı method'lar
+    // Arayüz başına hızlar - yeni görülen arayüzün ilk okuması sadece başlangıç noktasıdır
+    // Kaybolan arayüzün (VPN kapandı) geçmişi atılır; grafikte o seçiliyse toplama dönülür
+    fn update_interface_data(&mut self, elapsed_secs: f64) {
+        let cap = self.cpu_history_len;
+        let mut counters = HashMap::new();
+        for interface in &self.sample.interfaces {
+            let history = self.interface_history.entry(interface.name.clone()).or_default();
+            if let Some(&(prev_received, prev_transmitted)) = self.prev_interface_counters.get(&interface.name) {
+                history.push_back((
+                    rate_per_second(interface.received, prev_received, elapsed_secs),
+                    rate_per_second(interface.transmitted, prev_transmitted, elapsed_secs),
+                ));
+                trim_front(history, cap);
+            }
+            counters.insert(interface.name.clone(), (interface.received, interface.transmitted));
+        }
+        self.interface_history.retain(|name, _| counters.contains_key(name));
+        self.prev_interface_counters = counters;
+        
+        if let Some(name) = self.selected_interface.take_if(|name| !self.interface_history.contains_key(name)) {
+            self.set_status(format!("Interface {name} disappeared, showing all interfaces"), false);
+        }
+    }
     
-    // Toplam CPU çekirdek sayısını döndür
-    pub fn cpu_count(&self) -> usize {
-        self.system.cpus().len()
+    // Disk okuma/yazma hızlarını güncelleyen method
+    // Örnekteki toplamlar process'lerin kümülatif sayaçlarından gelir. Process'ler kapandığında
+    // toplam düşebilir - bu durumu rate_per_second içindeki saturating_sub sıfır hız olarak ele alır
+    fn update_disk_io_data(&mut self, elapsed_secs: f64) {
+        let (total_read, total_written) = (self.sample.disk_read, self.sample.disk_written);
+        
+        if let Some((prev_read, prev_written)) = self.prev_disk_io_data {
+            let read_speed = rate_per_second(total_read, prev_read, elapsed_secs);
+            let write_speed = rate_per_second(total_written, prev_written, elapsed_secs);
+            
+            self.disk_io_history.push_back((read_speed, write_speed));
+            
+            if self.disk_io_history.len() > self.cpu_history_len {
+                self.disk_io_history.pop_front();
+            }
+        }
+        
+        self.prev_disk_io_data = Some((total_read, total_written));
     }
     
-    // En son CPU verilerini döndür - UI'de anlık değerleri göstermek için
-    pub fn current_cpu_usage(&self) -> Vec<f32> {
-        self.cpu_history
-            .back() // En son eklenen veri
-            .cloned() // Ownership transferi için klon
-            .unwrap_or_default() // Eğer veri yoksa boş vector döndür
+    // Swap giriş/çıkış hızlarını güncelle - sayaçlar kaybolursa (kayıt başka platformdan) hız da silinir
+    fn update_swap_rates(&mut self, elapsed_secs: f64) {
+        let current = self.sample.swap_counters;
+        self.swap_rates = match (self.prev_swap_counters, current) {
+            (Some(prev), Some(current)) => Some((
+                rate_per_second(current.pages_in * PAGE_SIZE, prev.pages_in * PAGE_SIZE, elapsed_secs),
+                rate_per_second(current.pages_out * PAGE_SIZE, prev.pages_out * PAGE_SIZE, elapsed_secs),
+            )),
+            _ => None,
+        };
+        self.prev_swap_counters = current;
     }
     
-    // RAM kullanım yüzdesini hesapla
-    pub fn memory_usage_percent(&self) -> f32 {
-        let used = self.system.used_memory() as f64;
-        let total = self.system.total_memory() as f64;
+    // Process başına disk hızlarını güncelle
+    // Önceki turda olmayan (yeni başlamış) process'in önceki değeri sıfır sayılır - tüm I/O'su
+    // bu aralıkta yapılmıştır. Önceki harita her turda yeniden kurulduğu için kapanan PID'ler düşer
+    fn update_process_io_rates(&mut self, elapsed_secs: f64) {
+        let current = process_io_counters(&self.sample);
+        self.process_io_rates = current
+            .iter()
+            .map(|(&pid, &(read, written))| {
+                let (prev_read, prev_written) = self.prev_process_io.get(&pid).copied().unwrap_or((0, 0));
+                (
+                    pid,
+                    (
+                        rate_per_second(read, prev_read, elapsed_secs),
+                        rate_per_second(written, prev_written, elapsed_secs),
+                    ),
+                )
+            })
+            .collect();
+        self.prev_process_io = current;
+    }
+    
+    // Disk doluluk geçmişini güncelle
+    // Çıkarılan diskin (USB bellek vb.) geçmişi silinir, yoksa takılıp çıkarılan her
+    // bağlama noktası sonsuza kadar bellekte kalırdı
+    fn update_disk_history(&mut self) {
+        let timestamp = self.sample.timestamp;
+        if let Some(last) = self.last_disk_history {
+            if (timestamp - last).num_seconds() < DISK_HISTORY_INTERVAL_SECS {
+                return;
+            }
+        }
+        self.last_disk_history = Some(timestamp);
         
-        if total > 0.0 {
-            ((used / total) * 100.0) as f32
-        } else {
-            0.0
+        let disks = &self.sample.disks;
+        self.disk_history.retain(|mount, _| disks.iter().any(|disk| &disk.mount_point == mount));
+        for disk in disks {
+            let history = self.disk_history.entry(disk.mount_point.clone()).or_default();
+            history.push_back(disk.usage_percent);
+            if history.len() > DISK_HISTORY_LEN {
+                history.pop_front();
+            }
         }
     }
     
-    // İnsan tarafından okunabilir boyut formatı (KB, MB, GB)
-    pub fn format_bytes(bytes: u64) -> String {
-        const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
-        let mut size = bytes as f64;
-        let mut unit_index = 0;
-        
-        // 1024'e bölerek hangi birime yaklaştığımızı buluyoruz
-        while size >= 1024.0 && unit_index < UNITS.len() - 1 {
-            size /= 1024.0;
-            unit_index += 1;
+    // Her sensörün son değerini geçmişine ekle
+    fn update_temperature_history(&mut self) {
+        let temperatures = &self.sample.temperatures;
+        self.temperature_history
+            .retain(|name, _| temperatures.iter().any(|temp| &temp.component_name == name));
+        for temp in temperatures {
+            let history = self.temperature_history.entry(temp.component_name.clone()).or_default();
+            history.push_back(temp.current_temp);
+            trim_front(history, self.cpu_history_len);
         }
-        
-        format!("{:.1} {}", size, UNITS[unit_index])
     }
     
-    // En çok CPU kullanan processler - performans analizi için
-    pub fn top_processes(&self) -> Vec<(String, f32, u64)> {
-        let mut processes: Vec<_> = self.system
-            .processes()
-            .values()
-            .map(|p| (
-                p.name().to_string(),           // Process adı
-                p.cpu_usage(),                  // CPU kullanımı
-                p.memory()                      // RAM kullanımı
-            ))
-            .collect();
-        
-        // CPU kullanımına göre sırala (yüksekten alçağa)
-        processes.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    // Son güncellemede toplanan metriklerin kopyası - UI dışındaki tüketiciler için
+    pub fn snapshot(&self) -> Snapshot {
+        let (network_rx_rate, network_tx_rate) = self.network_history.back().copied().unwrap_or((0, 0));
+        let (disk_read_rate, disk_write_rate) = self.disk_io_history.back().copied().unwrap_or((0, 0));
+        Snapshot {
+            timestamp: self.sample.timestamp,
+            cpu_usage: self.current_cpu_usage(),
+            cpu_ids: self.cpu_ids.clone(),
+            cpu_average: self.cpu_average,
+            memory_used: self.sample.memory_used,
+            memory_total: self.sample.memory_total,
+            swap_used: self.sample.swap_used,
+            swap_total: self.sample.swap_total,
+            network_rx_rate,
+            network_tx_rate,
+            network_received: self.sample.network_received,
+            network_transmitted: self.sample.network_transmitted,
+            disk_read_rate,
+            disk_write_rate,
+            disks: self.sample.disks.clone(),
+            temperatures: self.sample.temperatures.clone(),
+            processes: self
+                .sample
+                .processes
+                .iter()
+                .map(|process| ProcessSnapshot {
+                    pid: process.pid,
+                    name: process.name.clone(),
+                    cpu_usage: process.cpu_usage,
+                    memory: process.memory,
+                })
+                .collect(),
+        }
+    }
+    
+    // Kapanışta diske yazılacak görünüm durumu
+    pub fn view_state(&self) -> ViewState {
+        ViewState {
+            active_tab: self.active_tab,
+            process_sort: self.process_sort,
+            theme: self.theme,
+            // Bataryadayken kapatılırsa batarya aralığı bir sonraki açılışa taşınmasın
+            refresh_ms: match self.power_source {
+                Some(PowerSource::Battery) => self.ac_refresh_ms.unwrap_or(self.refresh_ms),
+                _ => self.refresh_ms,
+            },
+        }
+    }
+    
+    // Aktif temanın renk paleti
+    pub fn theme(&self) -> Theme {
+        self.theme.theme()
+    }
+    
+    // Sonraki temaya geç
+    pub fn cycle_theme(&mut self) {
+        self.theme = self.theme.next();
+    }
+    
+    // Sekmeler arasında gezin - sona gelince başa döner
+    pub fn next_tab(&mut self) {
+        let index = (self.active_tab.index() + 1) % Tab::ALL.len();
+        self.active_tab = Tab::ALL[index];
+    }
+    
+    pub fn previous_tab(&mut self) {
+        let index = (self.active_tab.index() + Tab::ALL.len() - 1) % Tab::ALL.len();
+        self.active_tab = Tab::ALL[index];
+    }
+    
+    // Yenileme aralığını bir kademe kısalt (daha sık güncelle)
+    pub fn faster_refresh(&mut self) {
+        if let Some(&step) = REFRESH_STEPS_MS.iter().rev().find(|&&ms| ms < self.refresh_ms) {
+            self.set_refresh_ms(step);
+        }
+    }
+    
+    // Yenileme aralığını bir kademe uzat (daha seyrek güncelle)
+    pub fn slower_refresh(&mut self) {
+        if let Some(&step) = REFRESH_STEPS_MS.iter().find(|&&ms| ms > self.refresh_ms) {
+            self.set_refresh_ms(step);
+        }
+    }
+    
+    // Yenileme aralığını değiştir ve geçmişin örnek sınırını aynı süreyi kapsayacak şekilde
+    // yeniden hesapla. Sınır küçüldüyse en eski örnekler atılır; büyüdüyse geçmiş zamanla dolar
+    pub fn set_refresh_ms(&mut self, refresh_ms: u64) {
+        self.refresh_ms = clamp_refresh_ms(refresh_ms);
+        self.cpu_history_len = history_capacity(self.history_seconds, self.refresh_ms);
+        let cap = self.cpu_history_len;
+        trim_front(&mut self.cpu_history, cap);
+        trim_front(&mut self.memory_history, cap);
+        trim_front(&mut self.network_history, cap);
+        for history in self.interface_history.values_mut() {
+            trim_front(history, cap);
+        }
+        trim_front(&mut self.disk_io_history, cap);
+        for history in self.temperature_history.values_mut() {
+            trim_front(history, cap);
+        }
+    }
+    
+    // Process listesini bir kademe uzat
+    pub fn more_processes(&mut self) {
+        let current = self.process_limit();
+        if let Some(&step) = PROCESS_COUNT_STEPS.iter().find(|&&count| count > current) {
+            self.process_count = step;
+        }
+        self.set_status(format!("Showing up to {} processes", self.process_count), false);
+    }
+    
+    // Process listesini bir kademe kısalt - seçim listenin dışında kalmasın
+    pub fn fewer_processes(&mut self) {
+        let current = self.process_limit();
+        if let Some(&step) = PROCESS_COUNT_STEPS.iter().rev().find(|&&count| count < current) {
+            self.process_count = step;
+        }
+        self.move_process_selection(0);
+        self.set_status(format!("Showing up to {} processes", self.process_count), false);
+    }
+    
+    // Gösterilecek process sayısı - otomatikte son çizimdeki tablo gövdesinin yüksekliği
+    // Henüz çizilmediyse (veya tablo ekranda değilse) varsayılan 10
+    pub fn process_limit(&self) -> usize {
+        match self.process_count {
+            0 => self
+                .hit_areas
+                .get()
+                .process_rows
+                .map(|area| (area.height as usize).max(1))
+                .unwrap_or(10),
+            count => count,
+        }
+    }
+    
+    // Odağı bir sonraki panele taşı
+    // Gizlenen paneller atlanır - hepsi gizliyse odak yerinde kalır
+    pub fn focus_next(&mut self) {
+        self.move_focus(Panel::next);
+    }
+    
+    // Odağı bir önceki panele taşı
+    pub fn focus_previous(&mut self) {
+        self.move_focus(Panel::previous);
+    }
+    
+    fn move_focus(&mut self, step: fn(&Panel) -> Panel) {
+        let mut panel = self.focused_panel;
+        for _ in 0..Panel::ALL.len() {
+            panel = step(&panel);
+            if self.panels.panel_visible(panel) {
+                self.focused_panel = panel;
+                return;
+            }
+        }
+    }
+    
+    // Şimdiki durumu referans olarak sakla - öncekinin yerine geçer
+    pub fn capture_baseline(&mut self) {
+        let baseline = self.snapshot();
+        self.set_status(
+            format!(
+                "Baseline captured at {} ({} processes), press d to compare",
+                baseline.timestamp.format("%H:%M:%S"),
+                baseline.processes.len()
+            ),
+            false,
+        );
+        self.baseline = Some(baseline);
+    }
+    
+    // Ekranda herhangi bir popup veya çıkış onayı var mı - açıkken alttaki ekran tuş ve fare almaz
+    pub fn popup_open(&self) -> bool {
+        self.kill_popup.is_some()
+            || self.bulk_kill_popup.is_some()
+            || self.detail_popup.is_some()
+            || self.memory_popup
+            || self.offenders_popup
+            || self.health_popup
+            || self.diff_popup
+            || self.events_popup.is_some()
+            || self.panels_popup.is_some()
+            || self.quit_prompt
+    }
+    
+    // Referansa göre farklar - referans yoksa popup açılmaz, nasıl alınacağı söylenir
+    pub fn open_diff_popup(&mut self) {
+        if self.baseline.is_some() {
+            self.diff_popup = true;
+        } else {
+            self.set_status("No baseline yet - press B to capture one".to_string(), false);
+        }
+    }
+    
+    pub fn snapshot_diff(&self) -> Option<SnapshotDiff> {
+        self.baseline.as_ref().map(|baseline| diff_snapshots(baseline, &self.snapshot()))
+    }
+    
+    // Bölüm görünürlüğü listesini aç
+    pub fn open_panels_popup(&mut self) {
+        self.panels_popup = Some(0);
+    }
+    
+    // Listede seçimi kaydır
+    pub fn move_panels_selection(&mut self, delta: isize) {
+        if let Some(selected) = &mut self.panels_popup {
+            *selected = selected.saturating_add_signed(delta).min(Section::ALL.len() - 1);
+        }
+    }
+    
+    // Seçili bölümü göster/gizle - odaklanılan panel tamamen gizlendiyse odak sıradakine geçer
+    pub fn toggle_selected_section(&mut self) {
+        let Some(selected) = self.panels_popup else {
+            return;
+        };
+        self.panels.toggle(Section::ALL[selected]);
+        if !self.panels.panel_visible(self.focused_panel) {
+            self.focus_next();
+        }
+    }
+    
+    // Odaklanılan paneli tam ekran yap / grid düzenine geri dön
+    pub fn toggle_zoom(&mut self) {
+        if !self.panels.panel_visible(self.focused_panel) {
+            return;
+        }
+        self.zoomed = !self.zoomed;
+    }
+    
+    // Sade düzeni aç/kapat
+    pub fn toggle_compact(&mut self) {
+        self.compact = !self.compact;
+    }
+    
+    // Çekirdek gauge'ları ↔ ısı haritası
+    pub fn toggle_core_heatmap(&mut self) {
+        self.core_heatmap = !self.core_heatmap;
+    }
+    
+    // Process tablosunun sıralama kriterini değiştir
+    pub fn cycle_process_sort(&mut self) {
+        self.process_sort = self.process_sort.next();
+    }
+    
+    // Sıralamayı doğrudan seç (tablo başlığına tıklama)
+    pub fn set_process_sort(&mut self, key: ProcessSortKey) {
+        self.process_sort = key;
+    }
+    
+    // Ekrandaki process tablosu - Processes sekmesinde tüm liste (veya ağaç), diğerlerinde ilk N
+    pub fn visible_processes(&self) -> Vec<ProcessRow> {
+        match self.active_tab {
+            Tab::Processes if self.process_tree => self.process_tree_rows(),
+            Tab::Processes => self.sorted_processes(),
+            _ => self.top_processes(),
+        }
+    }
+    
+    // Gizlenmeyen process'ler ağaç sırasında - kardeşler seçili kritere göre sıralı
+    pub fn process_tree_rows(&self) -> Vec<ProcessRow> {
+        tree::build_tree(self.process_rows(), &self.collapsed_pids, self.tree_totals, |a, b| {
+            self.compare_processes(self.process_sort, a, b)
+        })
+    }
+    
+    // Processes sekmesi: düz liste ↔ ağaç. Seçim aynı process'te kalır
+    pub fn toggle_process_tree(&mut self) {
+        let selected = self.selected_process_row().map(|row| row.pid);
+        self.process_tree = !self.process_tree;
+        self.select_full_process_pid(selected);
+    }
+    
+    // Ağaçta ebeveynler: kendi değerleri ↔ alt ağacın toplamı
+    pub fn toggle_tree_totals(&mut self) {
+        let selected = self.selected_process_row().map(|row| row.pid);
+        self.tree_totals = !self.tree_totals;
+        self.select_full_process_pid(selected);
+    }
+    
+    // Seçili düğümün çocuklarını gizle/göster - çocuğu olmayan düğümde bir şey yapmaz
+    pub fn toggle_collapse(&mut self) {
+        if self.active_tab != Tab::Processes || !self.process_tree {
+            return;
+        }
+        let Some(row) = self.selected_process_row() else {
+            return;
+        };
+        if row.tree.is_some_and(|node| node.children > 0) && !self.collapsed_pids.remove(&row.pid) {
+            self.collapsed_pids.insert(row.pid);
+        }
+    }
+    
+    fn select_full_process_pid(&mut self, pid: Option<u32>) {
+        if self.active_tab != Tab::Processes {
+            return;
+        }
+        let rows = self.visible_processes();
+        self.selected_full_process = pid
+            .and_then(|pid| rows.iter().position(|row| row.pid == pid))
+            .unwrap_or(0);
+    }
+    
+    // Ekrandaki tablonun seçili satır indeksi
+    pub fn process_selection(&self) -> usize {
+        match self.active_tab {
+            Tab::Processes => self.selected_full_process,
+            _ => self.selected_process,
+        }
+    }
+    
+    fn process_selection_mut(&mut self) -> &mut usize {
+        match self.active_tab {
+            Tab::Processes => &mut self.selected_full_process,
+            _ => &mut self.selected_process,
+        }
+    }
+    
+    // Process tablosunda seçimi hareket ettir - listenin dışına taşmaz
+    pub fn move_process_selection(&mut self, delta: isize) {
+        let max_index = self.visible_processes().len().saturating_sub(1);
+        let selected = self.process_selection_mut();
+        *selected = selected.saturating_add_signed(delta).min(max_index);
+    }
+    
+    // Process'i listedeki sırasıyla seç (fare tıklaması) - listenin dışındaysa seçim değişmez
+    pub fn select_process(&mut self, index: usize) {
+        if index < self.visible_processes().len() {
+            *self.process_selection_mut() = index;
+        }
+    }
+    
+    // Seçili process'in satırı - liste boşsa None
+    pub fn selected_process_row(&self) -> Option<ProcessRow> {
+        self.visible_processes().into_iter().nth(self.process_selection())
+    }
+    
+    // PageUp/PageDown ile atlanacak satır sayısı - son çizimdeki tablo gövdesinin yüksekliği
+    pub fn process_page_size(&self) -> usize {
+        self.hit_areas
+            .get()
+            .process_rows
+            .map(|area| (area.height as usize).max(1))
+            .unwrap_or(10)
+    }
+    
+    // Disk listesinde tüm bağlama noktaları ↔ sadece gerçek diskler
+    pub fn toggle_all_disks(&mut self) {
+        self.show_all_disks = !self.show_all_disks;
+        self.selected_disk = 0;
+    }
+    
+    // Disk tablosu ↔ gauge ızgarası
+    pub fn toggle_disk_gauges(&mut self) {
+        self.disk_gauges = !self.disk_gauges;
+    }
+    
+    // Disk tablosunda seçimi kaydır - seçili diskin doluluk trendi gösterilir
+    pub fn move_disk_selection(&mut self, delta: isize) {
+        let max_index = self.visible_disks().len().saturating_sub(1);
+        self.selected_disk = self.selected_disk.saturating_add_signed(delta).min(max_index);
+    }
+    
+    // Seçili disk - liste boşsa None
+    pub fn selected_disk(&self) -> Option<DiskInfo> {
+        let disks = self.visible_disks();
+        let index = self.selected_disk.min(disks.len().saturating_sub(1));
+        disks.into_iter().nth(index)
+    }
+    
+    // Ekranda gösterilecek diskler
+    pub fn visible_disks(&self) -> Vec<DiskInfo> {
+        if self.show_all_disks {
+            self.sample.disks.clone()
+        } else {
+            self.disk_filter.physical(&self.sample.disks)
+        }
+    }
+    
+    // En dolu fiziksel disk - sadece uyarı eşiğini geçtiyse
+    // Sahte dosya sistemleri ('a' açık olsa bile) sayılmaz: squashfs snap'ler her zaman %100 doludur
+    pub fn worst_disk(&self) -> Option<DiskInfo> {
+        self.disk_filter
+            .physical(&self.sample.disks)
+            .into_iter()
+            .max_by(|a, b| a.usage_percent.total_cmp(&b.usage_percent))
+            .filter(|disk| categorize_disk_usage(disk.usage_percent, &self.color_thresholds) != DiskUsageCategory::Normal)
+    }
+    
+    // Seçili process için sinyal seçim popup'ını aç
+    pub fn open_kill_popup(&mut self) {
+        if let Some(process) = self.selected_process_row() {
+            self.kill_popup = Some(KillPopup {
+                pid: process.pid,
+                name: process.name,
+                selected: 0,
+            });
+        }
+    }
+    
+    // Ada göre toplu kill popup'ını aç - desen seçili process'in adıyla başlar
+    pub fn open_bulk_kill_popup(&mut self) {
+        let pattern = self.selected_process_row().map(|process| process.name).unwrap_or_default();
+        self.bulk_kill_popup = Some(BulkKillPopup { pattern, confirming: false });
+    }
+    
+    // Desene uyan tüm process'ler (PID'e göre) - ilk listedeki sınır ve gizleme filtresi uygulanmaz
+    // Desen, ignore_processes ile aynı kurala göre eşleşir: alt metin veya '*'/'?' içeren glob
+    pub fn bulk_kill_matches(&self) -> Vec<&ProcessSample> {
+        let Some(popup) = &self.bulk_kill_popup else {
+            return Vec::new();
+        };
+        if popup.pattern.is_empty() {
+            return Vec::new();
+        }
+        let mut matches: Vec<&ProcessSample> = self.sample.processes
+            .iter()
+            .filter(|process| process_name_matches(&process.name, &popup.pattern))
+            .collect();
+        matches.sort_by_key(|process| process.pid);
+        matches
+    }
+    
+    // Öldürülmesi oturumu düşürebilecek process mi - init, kernel thread'leri, oturum/masaüstü
+    // süreçleri ve monitörün kendisi
+    pub fn is_critical_process(&self, process: &ProcessSample) -> bool {
+        process.pid <= 1
+            || process.parent_pid == Some(2)
+            || self.own_pid == Some(process.pid)
+            || CRITICAL_PROCESS_NAMES.contains(&process.name.as_str())
+    }
+    
+    // Desen düzenlenirken yazılan karakter
+    pub fn edit_bulk_kill_pattern(&mut self, edit: Option<char>) {
+        if let Some(popup) = &mut self.bulk_kill_popup {
+            match edit {
+                Some(c) => popup.pattern.push(c),
+                None => {
+                    popup.pattern.pop();
+                }
+            }
+        }
+    }
+    
+    // Enter: eşleşme varsa onay aşamasına geç
+    pub fn review_bulk_kill(&mut self) {
+        let has_matches = !self.bulk_kill_matches().is_empty();
+        if let Some(popup) = &mut self.bulk_kill_popup {
+            popup.confirming = has_matches;
+        }
+    }
+    
+    // Onaylandı: eşleşenlerin hepsine ilk sinyali (TERM) gönder, sonucu footer'da göster
+    // Listede kritik bir process varsa sadece açık onay (include_critical) ile gönderilir
+    pub fn confirm_bulk_kill(&mut self, include_critical: bool) {
+        let targets: Vec<(u32, bool)> = self.bulk_kill_matches()
+            .iter()
+            .map(|process| (process.pid, self.is_critical_process(process)))
+            .collect();
+        if !include_critical && targets.iter().any(|&(_, critical)| critical) {
+            return;
+        }
+        let Some(popup) = self.bulk_kill_popup.take() else {
+            return;
+        };
+        let (signal, label) = KILL_SIGNALS[0];
+        
+        let mut failed = 0;
+        let mut last_error = None;
+        for &(pid, _) in &targets {
+            if let Err(reason) = self.source.kill(pid, signal) {
+                failed += 1;
+                last_error = Some(reason);
+            }
+        }
+        
+        let summary = format!(
+            "Sent {} to {} of {} processes matching '{}'",
+            label,
+            targets.len() - failed,
+            targets.len(),
+            popup.pattern
+        );
+        match last_error {
+            Some(reason) => self.set_status(format!("{summary} ({failed} failed: {reason})"), true),
+            None => self.set_status(summary, false),
+        }
+    }
+    
+    // Seçili process için detay popup'ını aç
+    pub fn open_detail_popup(&mut self) {
+        let Some(row) = self.selected_process_row() else {
+            return;
+        };
+        let now = self.sample_time();
+        self.detail_popup = self.find_process(row.pid)
+            .map(|process| ProcessDetails::from_sample(process, now));
+    }
+    
+    // Popup'taki process hâlâ yaşıyorsa değerlerini güncelle, yoksa kapandı olarak işaretle
+    fn refresh_detail_popup(&mut self) {
+        let Some(details) = &mut self.detail_popup else {
+            return;
+        };
+        let pid = details.pid;
+        let now = self.sample_time();
+        let refreshed = self.find_process(pid).map(|process| ProcessDetails::from_sample(process, now));
+        if let Some(details) = &mut self.detail_popup {
+            match refreshed {
+                Some(refreshed) => *details = refreshed,
+                None => details.exited = true,
+            }
+        }
+    }
+    
+    // Veri kaynağının kısa açıklaması (örn. "Replay 2x") - canlı izlemede None
+    pub fn source_description(&self) -> Option<String> {
+        self.source.describe()
+    }
+    
+    // Kaynağa ulaşılamıyorsa uyarı metni - ekrandaki veri eskidir
+    pub fn source_warning(&self) -> Option<String> {
+        self.source.connection_warning()
+    }
+    
+    // Monitörün kendi CPU yüzdesi ve belleği - her turda zaten yenilenen process listesinden
+    // Kendimizi göremiyorsak (kayıt, uzak makine) None
+    pub fn self_usage(&self) -> Option<(f32, u64)> {
+        let process = self.find_process(self.own_pid?)?;
+        Some((process.cpu_usage, process.memory))
+    }
+    
+    // CPU'nun gerçekte kaç ms'de bir okunduğu - yenileme kaynağın alt sınırından hızlıysa
+    // ekran yine her turda çizilir ama CPU değerleri bu aralıkla değişir
+    pub fn cpu_sample_ms(&self) -> u64 {
+        let floor = self.source.min_cpu_interval().map_or(0, |interval| interval.as_millis() as u64);
+        self.refresh_ms.max(floor)
+    }
+    
+    // Son örnekte PID'e göre process bul
+    fn find_process(&self, pid: u32) -> Option<&ProcessSample> {
+        self.sample.processes.iter().find(|process| process.pid == pid)
+    }
+    
+    // Son örneğin zamanı (epoch saniyesi) - çalışma süreleri buna göre hesaplanır,
+    // böylece oynatmada da kayıt anındaki süreler görünür
+    fn sample_time(&self) -> u64 {
+        self.sample.timestamp.timestamp().max(0) as u64
+    }
+    
+    // Popup'ta sinyal seçimini değiştir
+    pub fn move_kill_selection(&mut self, delta: isize) {
+        if let Some(popup) = &mut self.kill_popup {
+            popup.selected = popup.selected.saturating_add_signed(delta).min(KILL_SIGNALS.len() - 1);
+        }
+    }
+    
+    // Popup'ı kapat, seçili sinyali gönder ve sonucu footer'da göster
+    // Sinyali kaynak gönderir - oynatmada gerçek bir process olmadığı için hata döner
+    pub fn confirm_kill(&mut self) {
+        let Some(popup) = self.kill_popup.take() else {
+            return;
+        };
+        let (signal, label) = KILL_SIGNALS[popup.selected];
+        
+        let result = self.source.kill(popup.pid, signal);
+        
+        match result {
+            Ok(()) => self.set_status(
+                format!("Sent {} to {} ({})", label, popup.name, popup.pid),
+                false,
+            ),
+            Err(reason) => self.set_status(
+                format!("Failed to signal {} ({}): {}", popup.name, popup.pid, reason),
+                true,
+            ),
+        }
+    }
+    
+    // Footer'da birkaç saniye gösterilecek bir mesaj ayarla - mesaj günlüğe de yazılır
+    pub fn set_status(&mut self, text: String, is_error: bool) {
+        self.log_event(if is_error { Severity::Error } else { Severity::Info }, text.clone());
+        self.status_message = Some(StatusMessage {
+            text,
+            is_error,
+            created_at: Instant::now(),
+        });
+    }
+    
+    // Olay günlüğüne kayıt ekle
+    pub fn log_event(&mut self, severity: Severity, text: String) {
+        self.events.push_back(LogEntry {
+            timestamp: chrono::Local::now(),
+            severity,
+            text,
+        });
+        if self.events.len() > MAX_EVENTS {
+            self.events.pop_front();
+        }
+    }
+    
+    // Olay günlüğü popup'ını kaydır - 0 en yeni kayıt
+    pub fn scroll_events(&mut self, delta: isize) {
+        if let Some(offset) = &mut self.events_popup {
+            *offset = offset.saturating_add_signed(delta).min(self.events.len().saturating_sub(1));
+        }
+    }
+    
+    // Süresi dolmamış durum mesajı
+    pub fn active_status(&self) -> Option<&StatusMessage> {
+        self.status_message
+            .as_ref()
+            .filter(|message| message.created_at.elapsed() < STATUS_MESSAGE_DURATION)
+    }
+    
+    // CPU gauge'larını kaydır - delta negatifse yukarı, pozitifse aşağı
+    // Üst sınırı burada çekirdek sayısına göre, görünür alana göre ise çizim sırasında kısıtlıyoruz
+    pub fn scroll_cpu(&mut self, delta: isize) {
+        let max_scroll = self.cpu_rows().len().saturating_sub(1);
+        self.cpu_scroll = self.cpu_scroll.saturating_add_signed(delta).min(max_scroll);
+    }
+    
+    // Terminal yeniden boyutlandırıldıktan sonra kaydırma ve seçimleri yeni düzene göre sınırla
+    // Son çizimdeki alanlar kullanıldığı için yeni boyutta bir kez çizildikten sonra çağrılmalı.
+    // Aksi halde büyüyen pencerede kaydırma eski değerinde kalır ve geri kaydırmak için boşa tuşa basılır
+    pub fn clamp_to_view(&mut self) {
+        if let Some(area) = self.hit_areas.get().cpu_gauges {
+            let visible = area.height.saturating_sub(2) as usize;
+            self.cpu_scroll = self.cpu_scroll.min(self.cpu_rows().len().saturating_sub(visible));
+        }
+        self.move_process_selection(0);
+        self.move_disk_selection(0);
+    }
+    
+    // UI'nin kullanabileceği yardımcı method'lar
+    
+    // Toplam CPU çekirdek sayısını döndür
+    pub fn cpu_count(&self) -> usize {
+        self.sample.cpu_usage.len()
+    }
+    
+    // Boştaki çekirdeklerin gösterimini değiştir: normal → soluk → özet
+    pub fn cycle_idle_cores(&mut self) {
+        self.idle_cores = self.idle_cores.next();
+    }
+    
+    // CPU panelinin satırları - birden fazla NUMA düğümü varsa çekirdekler düğüm başlıkları
+    // altında gruplanır, yoksa düz liste. Hiçbir düğümde görünmeyen çekirdekler sona eklenir
+    // Özet modunda boştaki çekirdekler listeden çıkar ve sonda tek bir satırda sayılır
+    pub fn cpu_rows(&self) -> Vec<CpuRow> {
+        let rows = self.grouped_cpu_rows();
+        if self.idle_cores != IdleCores::Collapse {
+            return rows;
+        }
+        let usage = self.current_cpu_usage();
+        let (idle, mut rows): (Vec<CpuRow>, Vec<CpuRow>) = rows.into_iter().partition(|row| {
+            matches!(row, CpuRow::Core(core) if usage.get(*core).copied().unwrap_or(0.0) < self.idle_core_threshold)
+        });
+        if !idle.is_empty() {
+            rows.push(CpuRow::Idle { count: idle.len() });
+        }
+        rows
+    }
+    
+    fn grouped_cpu_rows(&self) -> Vec<CpuRow> {
+        let cpu_count = self.cpu_count();
+        let nodes = &self.sample.host.numa_nodes;
+        if nodes.len() < 2 {
+            return (0..cpu_count).map(CpuRow::Core).collect();
+        }
+        
+        // Düğüm listeleri CPU numarası verir, satırlar ise sıra numarası kullanır
+        let mut rows = Vec::new();
+        let mut grouped = vec![false; cpu_count];
+        for node in nodes {
+            let cores: Vec<usize> = node.cpus
+                .iter()
+                .filter_map(|cpu| (0..cpu_count).find(|&index| self.cpu_id(index) == *cpu))
+                .collect();
+            if cores.is_empty() {
+                continue;
+            }
+            rows.push(CpuRow::Node { id: node.id, cores: cores.clone() });
+            for cpu in cores {
+                grouped[cpu] = true;
+                rows.push(CpuRow::Core(cpu));
+            }
+        }
+        rows.extend((0..cpu_count).filter(|&cpu| !grouped[cpu]).map(CpuRow::Core));
+        rows
+    }
+    
+    // En son CPU verilerini döndür - UI'de anlık değerleri göstermek için
+    pub fn current_cpu_usage(&self) -> Vec<f32> {
+        self.cpu_history
+            .back() // En son eklenen veri
+            .cloned() // Ownership transferi için klon
+            .unwrap_or_default() // Eğer veri yoksa boş vector döndür
+    }
+    
+    // Ağ geçmişindeki (indirme, yükleme) hızlarının istatistikleri - henüz hız yoksa None
+    pub fn network_stats(&self) -> Option<(RateStats, RateStats)> {
+        let download = RateStats::from_rates(self.network_history.iter().map(|&(down, _)| down))?;
+        let upload = RateStats::from_rates(self.network_history.iter().map(|&(_, up)| up))?;
+        Some((download, upload))
+    }
+    
+    // RAM kullanım yüzdesini hesapla
+    pub fn memory_usage_percent(&self) -> f32 {
+        let used = self.sample.memory_used as f64;
+        let total = self.sample.memory_total as f64;
+        
+        if total > 0.0 {
+            ((used / total) * 100.0) as f32
+        } else {
+            0.0
+        }
+    }
+    
+    // Swap kullanım yüzdesi - swap yoksa 0
+    pub fn swap_usage_percent(&self) -> f32 {
+        if self.sample.swap_total > 0 {
+            (self.sample.swap_used as f64 / self.sample.swap_total as f64 * 100.0) as f32
+        } else {
+            0.0
+        }
+    }
+    
+    // Tek bakışta sistem sağlığı - kurallar health.rs'de
+    pub fn health(&self) -> Health {
+        let disks = self.disk_filter.physical(&self.sample.disks);
+        let inputs = HealthInputs {
+            cpu_average: self.cpu_average,
+            memory_percent: self.memory_usage_percent(),
+            swap_percent: self.swap_usage_percent(),
+            disks: &disks,
+            temperatures: &self.sample.temperatures,
+        };
+        health::assess(&inputs, &self.alerts, &self.color_thresholds)
+    }
+    
+    // İnsan tarafından okunabilir boyut formatı (KiB/MiB/GiB veya KB/MB/GB)
+    // Birim tabanı config'deki byte_units ayarından gelir
+    pub fn format_bytes(&self, bytes: u64) -> String {
+        let (size, unit) = self.byte_units.scale(bytes);
+        format!("{:.1} {}", size, unit)
+    }
+    
+    // Bit/s formatı (Kbps, Mbps, Gbps) - ağ dünyasında birimler 1000 tabanlıdır
+    // Parametre byte/s, içeride 8 ile çarpıp bit'e çeviriyoruz
+    pub fn format_bits(bytes_per_sec: u64) -> String {
+        const UNITS: &[&str] = &["bps", "Kbps", "Mbps", "Gbps", "Tbps"];
+        let mut size = bytes_per_sec as f64 * 8.0;
+        let mut unit_index = 0;
+        
+        while size >= 1000.0 && unit_index < UNITS.len() - 1 {
+            size /= 1000.0;
+            unit_index += 1;
+        }
+        
+        format!("{:.1} {}", size, UNITS[unit_index])
+    }
+    
+    // Ağ hızını kullanıcının seçtiği birimde formatla
+    pub fn format_network_rate(&self, bytes_per_sec: u64) -> String {
+        if self.network_bits {
+            Self::format_bits(bytes_per_sec)
+        } else {
+            format!("{}/s", self.format_bytes(bytes_per_sec))
+        }
+    }
+    
+    // Ağ hızı birimini değiştir (byte/s ↔ bit/s)
+    pub fn toggle_network_units(&mut self) {
+        self.network_bits = !self.network_bits;
+    }
+    
+    // Ağ paneli: hızlar ↔ arayüz sayaçları
+    pub fn toggle_network_counters(&mut self) {
+        self.network_counters = !self.network_counters;
+    }
+    
+    // Ağ grafiğindeki arayüzü değiştir: toplam → ilk arayüz → ... → son arayüz → toplam
+    pub fn cycle_interface(&mut self) {
+        let names: Vec<&String> = self.sample.interfaces.iter().map(|interface| &interface.name).collect();
+        let next = match &self.selected_interface {
+            None => names.first(),
+            Some(current) => names.iter().position(|name| *name == current).and_then(|i| names.get(i + 1)),
+        };
+        self.selected_interface = next.map(|name| name.to_string());
+    }
+    
+    // Ağ grafiğinde çizilecek hız geçmişi - seçili arayüzün veya tüm arayüzlerin toplamı
+    pub fn network_chart_history(&self) -> &VecDeque<(u64, u64)> {
+        self.selected_interface
+            .as_ref()
+            .and_then(|name| self.interface_history.get(name))
+            .unwrap_or(&self.network_history)
+    }
+    
+    // Ağ grafiğinin y eksenini değiştir (doğrusal ↔ logaritmik)
+    pub fn toggle_network_scale(&mut self) {
+        self.network_log_scale = !self.network_log_scale;
+    }
+    
+    // Process listede gizlenmeli mi - config'deki desenler ve (istenirse) monitörün kendisi
+    // Filtre çalışırken kapatılabilir, o zaman her şey görünür
+    fn is_process_ignored(&self, pid: u32, name: &str) -> bool {
+        if !self.process_filter_enabled {
+            return false;
+        }
+        if self.hide_self && self.own_pid == Some(pid) {
+            return true;
+        }
+        self.ignore_processes.iter().any(|pattern| process_name_matches(name, pattern))
+    }
+    
+    // Process filtresini aç/kapat
+    pub fn toggle_process_filter(&mut self) {
+        self.process_filter_enabled = !self.process_filter_enabled;
+        self.selected_process = 0;
+        self.selected_full_process = 0;
+    }
+    
+    // Tüm process'lerin durum dağılımı - gizlenenler de sayılır, zombiler sağlık işaretidir
+    pub fn process_states(&self) -> ProcessStates {
+        ProcessStates::from_statuses(self.sample.processes.iter().map(|process| process.status.as_str()))
+    }
+    
+    // En çok CPU kullanan processler - performans analizi için
+    // Ayarlanan sayıda process ve ek olarak sabitlenenler - hepsi sıralama düzeninde
+    pub fn top_processes(&self) -> Vec<ProcessRow> {
+        let limit = self.process_limit();
+        let mut others = 0;
+        self.sorted_processes()
+            .into_iter()
+            .filter(|process| {
+                if self.pinned_pids.contains(&process.pid) {
+                    return true;
+                }
+                others += 1;
+                others <= limit
+            })
+            .collect()
+    }
+    
+    // Seçili process'i sabitle / sabitlemeyi kaldır
+    pub fn toggle_pin(&mut self) {
+        let Some(process) = self.selected_process_row() else {
+            return;
+        };
+        let text = if self.pinned_pids.remove(&process.pid) {
+            format!("Unpinned {} ({})", process.pid, process.name)
+        } else {
+            self.pinned_pids.insert(process.pid);
+            format!("Pinned {} ({})", process.pid, process.name)
+        };
+        self.set_status(text, false);
+    }
+    
+    // Gizlenmeyen tüm process'ler seçili kritere göre sıralı
+    // Sayısal değerlerde yüksekten alçağa; isim, PID ve yolda artan sırada
+    // Eşitlikte tie_break devreye girer - satırlar turdan tura aynı sırada kalır
+    pub fn sorted_processes(&self) -> Vec<ProcessRow> {
+        let mut processes = self.process_rows();
+        processes.sort_by(|a, b| self.compare_processes(self.process_sort, a, b));
+        processes
+    }
+    
+    fn compare_processes(&self, key: ProcessSortKey, a: &ProcessRow, b: &ProcessRow) -> Ordering {
+        let primary = match key {
+            ProcessSortKey::Cpu => b.cpu_usage.total_cmp(&a.cpu_usage),
+            ProcessSortKey::Memory => b.memory.cmp(&a.memory),
+            ProcessSortKey::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            ProcessSortKey::RunTime => b.run_time.cmp(&a.run_time),
+            ProcessSortKey::Pid => a.pid.cmp(&b.pid),
+            ProcessSortKey::Path => a.exe.cmp(&b.exe),
+            ProcessSortKey::DiskRead => b.disk_read_rate.cmp(&a.disk_read_rate),
+            ProcessSortKey::DiskWrite => b.disk_write_rate.cmp(&a.disk_write_rate),
+        };
+        let secondary = match self.tie_break {
+            TieBreak::Pid => Ordering::Equal,
+            TieBreak::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+        };
+        primary.then(secondary).then(a.pid.cmp(&b.pid))
+    }
+    
+    // En çok bağlantısı olan process'ler (PID, ad, bağlantı sayısı) - veri yoksa None
+    // Bağlantı listesi örnekle aynı anda okunmadığı için arada kapanan process'in adı boş kalabilir
+    pub fn top_talkers(&self) -> Option<Vec<(u32, String, u32)>> {
+        let connections = self.sample.process_connections.as_ref()?;
+        Some(
+            connections
+                .iter()
+                .map(|talker| (talker.pid, self.process_name(talker.pid), talker.connections))
+                .filter(|(pid, name, _)| !self.is_process_ignored(*pid, name))
+                .take(self.network_talkers)
+                .collect(),
+        )
+    }
+    
+    fn process_name(&self, pid: u32) -> String {
+        self.find_process(pid).map(|process| process.name.clone()).unwrap_or_default()
+    }
+    
+    // En çok bellek kullanan process'ler - "RAM neden dolu" popup'ı için
+    // Ana tablonun sıralamasından bağımsızdır, onu değiştirmez
+    pub fn top_memory_processes(&self, limit: usize) -> Vec<ProcessRow> {
+        let mut processes = self.process_rows();
+        processes.sort_by(|a, b| self.compare_processes(ProcessSortKey::Memory, a, b));
+        processes.truncate(limit);
+        processes
+    }
+    
+    // Bu turda en çok CPU kullanan ilk OFFENDER_TOP_N process'in adını sayaca ekle
+    // Hiç CPU kullanmayanlar sayılmaz - boşta bir sistemde sıralama rastgele olurdu
+    fn update_offenders(&mut self) {
+        let mut processes = self.process_rows();
+        processes.retain(|p| p.cpu_usage > 0.0);
+        processes.sort_by(|a, b| self.compare_processes(ProcessSortKey::Cpu, a, b));
+        let names = processes.into_iter().take(OFFENDER_TOP_N).map(|p| p.name).collect();
+        self.offenders.record(self.sample.timestamp, names);
+    }
+    
+    // Gizlenmeyen tüm process'lerin tablo satırları - sırasız
+    fn process_rows(&self) -> Vec<ProcessRow> {
+        let total_memory = self.sample.memory_total;
+        let now = self.sample_time();
+        self.sample
+            .processes
+            .iter()
+            .filter(|p| !self.is_process_ignored(p.pid, &p.name))
+            .map(|p| ProcessRow {
+                pid: p.pid,
+                parent_pid: p.parent_pid,
+                name: p.name.clone(),
+                cpu_usage: p.cpu_usage,
+                memory: p.memory,
+                memory_percent: if total_memory > 0 {
+                    (p.memory as f64 / total_memory as f64 * 100.0) as f32
+                } else {
+                    0.0
+                },
+                exe: p.exe.clone(),
+                run_time: process_run_time(p.start_time, now),
+                disk_read_rate: self.process_io_rates.get(&p.pid).map_or(0, |&(read, _)| read),
+                disk_write_rate: self.process_io_rates.get(&p.pid).map_or(0, |&(_, written)| written),
+                tree: None,
+            })
+            .collect()
+    }
+}
+
+// Process adı desene uyuyor mu
+// Desende '*' veya '?' varsa glob olarak tüm isimle eşleşmeli, yoksa alt metin araması yapılır
+fn process_name_matches(name: &str, pattern: &str) -> bool {
+    if !pattern.contains(['*', '?']) {
+        return name.contains(pattern);
+    }
+    
+    // Basit glob eşleştirme - '*' herhangi bir dizi, '?' tek karakter
+    // Son '*'dan geri dönerek (backtracking) çalışır, özyineleme gerekmez
+    let name: Vec<char> = name.chars().collect();
+    let pattern: Vec<char> = pattern.chars().collect();
+    let (mut n, mut p) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            n += 1;
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, n));
+            p += 1;
+        } else if let Some((star_p, star_n)) = star {
+            p = star_p + 1;
+            n = star_n + 1;
+            star = Some((star_p, star_n + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+// Process tablosunun bir satırı
+// Tuple yerine struct kullanıyoruz - yeni kolon eklemek tüm çağıranları bozmasın
+#[derive(Debug, Clone)]
+pub struct ProcessRow {
+    pub pid: u32,
+    pub parent_pid: Option<u32>,
+    pub name: String,
+    pub cpu_usage: f32,      // CPU kullanımı (%)
+    pub memory: u64,         // RAM kullanımı (byte)
+    pub memory_percent: f32, // Toplam RAM'e oranı (%)
+    pub exe: String,         // Çalıştırılabilir dosyanın tam yolu (bilinmiyorsa boş)
+    pub run_time: u64,       // Process başlayalı geçen süre (saniye)
+    pub disk_read_rate: u64, // Diskten okuma hızı (byte/s)
+    pub disk_write_rate: u64, // Diske yazma hızı (byte/s)
+    pub tree: Option<TreeNode>, // Ağaç görünümündeki yeri - düz listede None
+}
+
+// Process'in başlangıç zamanından (epoch saniyesi) çalışma süresini hesapla
+// Saat kayması yüzünden start_time biraz gelecekte olabilir - bu durumda 0 kabul ediyoruz
+pub fn process_run_time(start_time: u64, now: u64) -> u64 {
+    now.saturating_sub(start_time)
+}
+
+// Bu turda tazelenmeyen bir hız serisinde son değeri tekrar et
+fn hold_last_rate(history: &mut VecDeque<(u64, u64)>, len: usize) {
+    if let Some(&last) = history.back() {
+        history.push_back(last);
+        if history.len() > len {
+            history.pop_front();
+        }
+    }
+}
+
+// CPU panelindeki bir satır - NUMA düğüm başlığı veya tek bir çekirdeğin gauge'u
+#[derive(Debug, Clone, PartialEq)]
+pub enum CpuRow {
+    Node { id: usize, cores: Vec<usize> },
+    Core(usize),
+    Idle { count: usize }, // Özetlenen boştaki çekirdekler
+}
+
+// Son örnekte hangi panellerin verisi eksik geldi - bu paneller son iyi değerleri gösterir
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DataGaps {
+    pub cpu: bool,
+    pub memory: bool,
+}
+
+// Örnekteki çekirdeklerin CPU numaraları - örnek numara vermiyorsa (veya sayı tutmuyorsa) sıra
+fn cpu_ids_of(sample: &Sample) -> Vec<usize> {
+    if sample.cpu_ids.len() == sample.cpu_usage.len() {
+        sample.cpu_ids.clone()
+    } else {
+        (0..sample.cpu_usage.len()).collect()
+    }
+}
+
+// Zamanı gelmeyen kategorilerin değerlerini önceki örnekten taşı
+// Kaynak o kategorileri tazelememiş olabilir (veya tazelese bile) ekranda aralık dolana kadar
+// önceki değerler kalır; böylece her kaynak aynı şekilde davranır
+pub fn carry_over(previous: &Sample, sample: &mut Sample, due: Due) {
+    if !due.contains(Category::Cpu) {
+        sample.cpu_usage = previous.cpu_usage.clone();
+        sample.cpu_frequencies = previous.cpu_frequencies.clone();
+        sample.cpu_ids = previous.cpu_ids.clone();
+        sample.cpu_breakdown = previous.cpu_breakdown;
+    }
+    if !due.contains(Category::Memory) {
+        sample.memory_used = previous.memory_used;
+        sample.memory_total = previous.memory_total;
+        sample.memory_available = previous.memory_available;
+        sample.swap_used = previous.swap_used;
+        sample.swap_total = previous.swap_total;
+        sample.swap_counters = previous.swap_counters;
+        sample.zram = previous.zram;
+    }
+    if !due.contains(Category::Network) {
+        sample.network_received = previous.network_received;
+        sample.network_transmitted = previous.network_transmitted;
+        sample.interfaces = previous.interfaces.clone();
+        sample.connections = previous.connections;
+        sample.process_connections = previous.process_connections.clone();
+    }
+    if !due.contains(Category::Disk) {
+        sample.disks = previous.disks.clone();
+        sample.disk_read = previous.disk_read;
+        sample.disk_written = previous.disk_written;
+        sample.host.reboot_pending = previous.host.reboot_pending;
+    }
+    if !due.contains(Category::Temperature) {
+        sample.temperatures = previous.temperatures.clone();
+        sample.fans = previous.fans.clone();
+        sample.batteries = previous.batteries.clone();
+        sample.gpus = previous.gpus.clone();
+    }
+    if !due.contains(Category::Processes) {
+        sample.processes = previous.processes.clone();
+    }
+}
+
+// Bazı kısıtlı container'larda sistem sıfır çekirdek veya sıfır bellek raporlar, ya da
+// kullanım NaN gelir. Bu alanlar önceki örnekten kopyalanır ki grafikler ve yüzdeler bozulmasın.
+// Önceki örnek de boşsa (ilk ölçüm) değerler boş kalır, sadece eksiklik işaretlenir
+pub fn repair_sample(previous: &Sample, sample: &mut Sample) -> DataGaps {
+    let cpu = sample.cpu_usage.is_empty() || sample.cpu_usage.iter().any(|usage| !usage.is_finite());
+    if cpu {
+        sample.cpu_usage = previous.cpu_usage.clone();
+        sample.cpu_frequencies = previous.cpu_frequencies.clone();
+        sample.cpu_ids = previous.cpu_ids.clone();
+        sample.cpu_breakdown = previous.cpu_breakdown;
+    }
+    
+    let memory = sample.memory_total == 0 || sample.memory_used > sample.memory_total;
+    if memory {
+        sample.memory_used = previous.memory_used;
+        sample.memory_total = previous.memory_total;
+        sample.memory_available = previous.memory_available;
+    }
+    
+    DataGaps { cpu, memory }
+}
+
+// Son çizimde fareyle etkileşilebilen alanlar
+// ui() App'i sadece okuyabildiği için konumlar Cell içinde tutulur ve her çizimde yeniden yazılır.
+// Ekranda olmayan paneller None kalır, böylece gizli bir tabloya tıklanamaz
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct HitAreas {
+    pub cpu_gauges: Option<Rect>,
+    pub process_rows: Option<Rect>, // Başlık satırı hariç tablo gövdesi
+    pub process_offset: usize,      // Tablonun kaydırması - ilk görünen satırın sırası
+    // Tıklanınca sıralamayı değiştiren başlık hücreleri (en fazla kolon sayısı kadar)
+    pub process_headers: [Option<(Rect, ProcessSortKey)>; 9],
+}
+
+// Çalışma boyunca görülen en yüksek değerler - panel başlıklarında gösterilir
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Peaks {
+    pub cpu_average: f32,
+    pub memory_percent: f32,
+    pub download: u64, // byte/s
+    pub upload: u64,
+}
+
+impl Peaks {
+    // Yeni ölçümü karşılaştır, daha yüksek olanları sakla
+    pub fn record(&mut self, cpu_average: f32, memory_percent: f32, download: u64, upload: u64) {
+        self.cpu_average = self.cpu_average.max(cpu_average);
+        self.memory_percent = self.memory_percent.max(memory_percent);
+        self.download = self.download.max(download);
+        self.upload = self.upload.max(upload);
+    }
+}
+
+// Son OFFENDER_WINDOW_SECS saniyede her turun CPU'daki ilk N process adını sayar
+// Anlık listeye hiç 1. olarak düşmeyen ama sürekli üst sıralarda gezen kısa patlamalı
+// process'leri yakalar. Pencereden çıkan turların sayıları geri düşülür ve sıfırlanan adlar
+// silinir; tur sayısı da sınırlı olduğu için hızlı yenilemede bile bellek sınırlı kalır
+#[derive(Debug, Clone, Default)]
+pub struct OffenderTally {
+    ticks: VecDeque<(chrono::DateTime<chrono::Local>, Vec<String>)>,
+    counts: HashMap<String, usize>,
+}
+
+impl OffenderTally {
+    // Bir turun ilk N adını ekle - aynı addan birden fazla process tek sayılır
+    pub fn record(&mut self, time: chrono::DateTime<chrono::Local>, mut names: Vec<String>) {
+        names.sort();
+        names.dedup();
+        for name in &names {
+            *self.counts.entry(name.clone()).or_default() += 1;
+        }
+        self.ticks.push_back((time, names));
+        
+        let cutoff = time - chrono::Duration::seconds(OFFENDER_WINDOW_SECS);
+        while self.ticks.len() > OFFENDER_MAX_TICKS
+            || self.ticks.front().is_some_and(|(tick_time, _)| *tick_time < cutoff)
+        {
+            let Some((_, expired)) = self.ticks.pop_front() else {
+                break;
+            };
+            for name in expired {
+                if let Some(count) = self.counts.get_mut(&name) {
+                    *count -= 1;
+                    if *count == 0 {
+                        self.counts.remove(&name);
+                    }
+                }
+            }
+        }
+    }
+    
+    // Penceredeki tur sayısı - oranların paydası
+    pub fn tick_count(&self) -> usize {
+        self.ticks.len()
+    }
+    
+    // En sık görülenler, çoktan aza (eşitlikte ada göre)
+    pub fn most_frequent(&self, limit: usize) -> Vec<(String, usize)> {
+        let mut entries: Vec<(String, usize)> =
+            self.counts.iter().map(|(name, &count)| (name.clone(), count)).collect();
+        entries.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        entries.truncate(limit);
+        entries
+    }
+    
+    pub fn clear(&mut self) {
+        self.ticks.clear();
+        self.counts.clear();
+    }
+}
+
+// Geçmiş penceresindeki hızların ortalaması, en düşüğü ve en yükseği (byte/s)
+// Anlık hız her tick zıplar; pencere ortalaması sürekli aktarımı daha iyi gösterir
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RateStats {
+    pub average: u64,
+    pub min: u64,
+    pub max: u64,
+}
+
+impl RateStats {
+    // Boş pencerede istatistik yok
+    pub fn from_rates(rates: impl Iterator<Item = u64> + Clone) -> Option<Self> {
+        let count = rates.clone().count() as u64;
+        if count == 0 {
+            return None;
+        }
+        Some(Self {
+            average: rates.clone().sum::<u64>() / count,
+            min: rates.clone().min()?,
+            max: rates.max()?,
+        })
+    }
+}
+
+// Bir başlangıç noktasından beri aktarılan toplam veriyi tutan sayaç
+// Arayüz resetlenirse veya kaybolursa ham sayaç başlangıcın altına düşebilir.
+// Bu durumda o ana kadar birikeni saklayıp yeni okumayı başlangıç kabul ediyoruz,
+// böylece toplam hiçbir zaman negatife düşmez ve geriye gitmez
+#[derive(Debug, Clone)]
+pub struct TransferCounter {
+    baseline: u64, // Şu anki başlangıç noktası (ham sayaç değeri)
+    carried: u64,  // Önceki başlangıç noktalarından devreden toplam
+    last: u64,     // Son okunan ham sayaç değeri
+}
+
+impl TransferCounter {
+    pub fn new(initial: u64) -> Self {
+        Self {
+            baseline: initial,
+            carried: 0,
+            last: initial,
+        }
+    }
+    
+    // Yeni bir ham sayaç okumasını işle
+    pub fn update(&mut self, current: u64) {
+        if current < self.last {
+            // Sayaç sıfırlandı - resetten önceki kısmı devret, yeni başlangıç al
+            self.carried += self.last - self.baseline;
+            self.baseline = current;
+        }
+        self.last = current;
+    }
+    
+    // Başlangıçtan beri aktarılan toplam byte
+    pub fn total(&self) -> u64 {
+        self.carried + (self.last - self.baseline)
+    }
+}
+
+// Verilen süreyi bu yenileme aralığıyla kapsamak için gereken örnek sayısı
+// Çizgi grafiği için en az iki nokta gerekir
+fn history_capacity(history_seconds: u64, refresh_ms: u64) -> usize {
+    (history_seconds * 1000).div_ceil(refresh_ms.max(1)).max(2) as usize
+}
+
+// Kuyruğu baştan (en eskiden) kısaltarak en fazla `cap` elemana indir
+fn trim_front<T>(history: &mut VecDeque<T>, cap: usize) {
+    let excess = history.len().saturating_sub(cap);
+    history.drain(..excess);
+}
+
+// Örnekteki her process'in kümülatif (okunan, yazılan) byte sayaçları
+fn process_io_counters(sample: &Sample) -> HashMap<u32, (u64, u64)> {
+    sample
+        .processes
+        .iter()
+        .map(|process| (process.pid, (process.disk_read, process.disk_written)))
+        .collect()
+}
+
+// Dışarıdan gelen yenileme aralığını desteklenen sınırlar içine al
+pub fn clamp_refresh_ms(refresh_ms: u64) -> u64 {
+    refresh_ms.clamp(REFRESH_STEPS_MS[0], REFRESH_STEPS_MS[REFRESH_STEPS_MS.len() - 1])
+}
+
+// Kümülatif bir sayacın iki okuması arasındaki farktan saniye başına hız hesapla
+// Sayaç sıfırlanırsa (arayüz resetlendi, process kapandı) fark negatif olur - saturating_sub ile 0 alırız
+pub fn rate_per_second(current: u64, previous: u64, elapsed_secs: f64) -> u64 {
+    if elapsed_secs <= 0.0 {
+        return 0;
+    }
+    (current.saturating_sub(previous) as f64 / elapsed_secs) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battery::{BatteryInfo, ChargeState};
+    use crate::numa::NumaNode;
+    use crate::source::{InterfaceCounters, MockSource};
+    use crate::system_info::TemperatureInfo;
+    
+    #[test]
+    fn test_rate_uses_elapsed_time() {
+        assert_eq!(rate_per_second(2000, 1000, 0.5), 2000);
+        assert_eq!(rate_per_second(2000, 1000, 2.0), 500);
+    }
+    
+    #[test]
+    fn test_run_time_clamps_future_start() {
+        assert_eq!(process_run_time(1_000, 1_060), 60);
+        assert_eq!(process_run_time(1_002, 1_000), 0);
+    }
+    
+    #[test]
+    fn test_transfer_counter_survives_reset() {
+        let mut counter = TransferCounter::new(1_000);
+        counter.update(1_500);
+        assert_eq!(counter.total(), 500);
+        
+        // Arayüz resetlendi - sayaç baştan başlıyor
+        counter.update(100);
+        assert_eq!(counter.total(), 500);
+        counter.update(300);
+        assert_eq!(counter.total(), 700);
+    }
+    
+    #[test]
+    fn test_format_bits() {
+        assert_eq!(App::format_bits(0), "0.0 bps");
+        assert_eq!(App::format_bits(125), "1.0 Kbps");
+        assert_eq!(App::format_bits(12_500_000), "100.0 Mbps");
+        assert_eq!(App::format_bits(125_000_000), "1.0 Gbps");
+    }
+    
+    #[test]
+    fn test_rate_handles_counter_reset() {
+        assert_eq!(rate_per_second(100, 5000, 1.0), 0);
+        assert_eq!(rate_per_second(5000, 100, 0.0), 0);
+    }
+    
+    #[test]
+    fn test_process_name_patterns() {
+        assert!(process_name_matches("kworker/0:1-events", "kworker"));
+        assert!(!process_name_matches("bash", "kworker"));
+        assert!(process_name_matches("kworker/0:1", "kworker/*"));
+        assert!(!process_name_matches("my-kworker", "kworker*"));
+        assert!(process_name_matches("chrome", "ch?ome"));
+        assert!(process_name_matches("a-b-c", "*-*-c"));
+    }
+    
+    #[test]
+    fn test_peaks_keep_maximum() {
+        let mut peaks = Peaks::default();
+        peaks.record(50.0, 40.0, 1000, 10);
+        peaks.record(20.0, 60.0, 500, 20);
+        assert_eq!(peaks.cpu_average, 50.0);
+        assert_eq!(peaks.memory_percent, 60.0);
+        assert_eq!(peaks.download, 1000);
+        assert_eq!(peaks.upload, 20);
+    }
+    
+    // Verilen saniyede alınmış, sadece CPU ve ağ sayaçları dolu bir örnek
+    fn mock_sample(seconds: i64, cpu_usage: Vec<f32>, network_received: u64) -> Sample {
+        Sample {
+            timestamp: chrono::DateTime::from_timestamp(seconds, 0).unwrap().into(),
+            cpu_usage,
+            network_received,
+            ..Default::default()
+        }
+    }
+    
+    async fn mock_app(samples: Vec<Sample>) -> App {
+        App::new(Config::default(), ViewState::default(), Box::new(MockSource::new(samples))).await.unwrap()
+    }
+    
+    #[tokio::test]
+    async fn test_restores_view_state() {
+        let saved = ViewState {
+            active_tab: Tab::Processes,
+            process_sort: ProcessSortKey::Memory,
+            theme: ThemeName::Ocean,
+            refresh_ms: 2000,
+        };
+        let source = MockSource::new(vec![mock_sample(0, vec![10.0], 0)]);
+        let app = App::new(Config::default(), saved.clone(), Box::new(source)).await.unwrap();
+    
+        assert_eq!(app.active_tab, Tab::Processes);
+        assert_eq!(app.process_sort, ProcessSortKey::Memory);
+        assert_eq!(app.theme, ThemeName::Ocean);
+        assert_eq!(app.refresh_ms, 2000);
+        assert_eq!(app.view_state(), saved);
+    }
+    
+    #[tokio::test]
+    async fn test_cpu_rows_grouped_by_numa_node() {
+        let mut sample = mock_sample(0, vec![10.0; 5], 0);
+        let mut app = mock_app(vec![sample.clone()]).await;
+        assert_eq!(app.cpu_rows().len(), 5);
+        
+        // İki düğüm - her biri başlık + çekirdekleri; listede olmayan 4. çekirdek sona eklenir
+        sample.host.numa_nodes = vec![
+            NumaNode { id: 0, cpus: vec![0, 2] },
+            NumaNode { id: 1, cpus: vec![1, 3, 9] },
+        ];
+        app.sample = sample;
+        assert_eq!(
+            app.cpu_rows(),
+            vec![
+                CpuRow::Node { id: 0, cores: vec![0, 2] },
+                CpuRow::Core(0),
+                CpuRow::Core(2),
+                CpuRow::Node { id: 1, cores: vec![1, 3] },
+                CpuRow::Core(1),
+                CpuRow::Core(3),
+                CpuRow::Core(4),
+            ]
+        );
+        app.scroll_cpu(isize::MAX);
+        assert_eq!(app.cpu_scroll, 6);
+    }
+    
+    #[tokio::test]
+    async fn test_focus_skips_hidden_panels() {
+        let mut app = mock_app(Vec::new()).await;
+        app.focused_panel = Panel::Memory;
+        app.panels_popup = Some(Section::ALL.iter().position(|&s| s == Section::Processes).unwrap());
+        app.toggle_selected_section();
+        app.focus_next();
+        assert_eq!(app.focused_panel, Panel::Network);
+        
+        // Odaklanılan panelin son bölümü gizlenince odak sıradaki görünen panele geçer
+        app.move_panels_selection(isize::MIN);
+        app.toggle_selected_section();
+        app.focused_panel = Panel::Cpu;
+        app.move_panels_selection(1);
+        app.toggle_selected_section();
+        assert!(!app.panels.panel_visible(Panel::Cpu));
+        assert_eq!(app.focused_panel, Panel::Memory);
+        app.focus_previous();
+        assert_eq!(app.focused_panel, Panel::Alerts);
+    }
+    
+    #[test]
+    fn test_offender_tally_ages_out() {
+        let at = |seconds: i64| chrono::DateTime::from_timestamp(seconds, 0).unwrap().into();
+        let names = |list: &[&str]| list.iter().map(|name| name.to_string()).collect();
+        let mut tally = OffenderTally::default();
+        tally.record(at(0), names(&["make", "cc1", "cc1"]));
+        tally.record(at(30), names(&["cc1"]));
+        assert_eq!(tally.most_frequent(5), vec![("cc1".to_string(), 2), ("make".to_string(), 1)]);
+        
+        // İlk tur pencereden çıktı - "make" tamamen silinir
+        tally.record(at(61), names(&["rustc"]));
+        assert_eq!(tally.tick_count(), 2);
+        assert_eq!(tally.most_frequent(5), vec![("cc1".to_string(), 1), ("rustc".to_string(), 1)]);
+        assert!(!tally.counts.contains_key("make"));
+    }
+    
+    #[tokio::test]
+    async fn test_anomalous_samples_keep_last_good_values() {
+        let good = Sample { memory_used: 2048, memory_total: 4096, ..mock_sample(0, vec![40.0, 60.0], 0) };
+        let empty = mock_sample(1, Vec::new(), 0);
+        let nan = Sample { memory_used: 1024, memory_total: 4096, ..mock_sample(2, vec![f32::NAN, 10.0], 0) };
+        let mut app = mock_app(vec![good, empty, nan]).await;
+        assert_eq!(app.data_gaps, DataGaps::default());
+        
+        // Sıfır çekirdek ve sıfır bellek - son iyi değerler kalır, NaN üretilmez
+        app.update().await.unwrap();
+        assert_eq!(app.data_gaps, DataGaps { cpu: true, memory: true });
+        assert_eq!(app.sample.cpu_usage, vec![40.0, 60.0]);
+        assert_eq!(app.cpu_average, 50.0);
+        assert_eq!(app.memory_usage_percent(), 50.0);
+        
+        // Bellek düzeldi, CPU'da NaN var - sadece CPU eksik sayılır
+        app.update().await.unwrap();
+        assert_eq!(app.data_gaps, DataGaps { cpu: true, memory: false });
+        assert_eq!(app.cpu_average, 50.0);
+        assert_eq!(app.memory_usage_percent(), 25.0);
+    }
+    
+    #[tokio::test]
+    async fn test_empty_first_sample_does_not_produce_nan() {
+        let mut app = mock_app(vec![Sample::default(), Sample::default()]).await;
+        assert_eq!(app.data_gaps, DataGaps { cpu: true, memory: true });
+        app.update().await.unwrap();
+        assert_eq!(app.cpu_average, 0.0);
+        assert_eq!(app.memory_usage_percent(), 0.0);
+    }
+    
+    #[tokio::test]
+    async fn test_cpu_history_sliding_window() {
+        let samples = (0..6).map(|i| mock_sample(i, vec![i as f32 * 10.0], 0)).collect();
+        let mut app = mock_app(samples).await;
+        app.cpu_history_len = 3;
+        for _ in 0..5 {
+            app.update().await.unwrap();
+        }
+        
+        // En eski değerler düşer, son üç örnek kalır
+        let history: Vec<f32> = app.cpu_history.iter().map(|usage| usage[0]).collect();
+        assert_eq!(history, vec![30.0, 40.0, 50.0]);
+        
+        // Kaynak tükendi - güncelleme hiçbir şeyi değiştirmez
+        app.update().await.unwrap();
+        assert_eq!(app.cpu_history.len(), 3);
+    }
+    
+    #[tokio::test]
+    async fn test_cpu_average_across_cores() {
+        let mut app = mock_app(vec![
+            mock_sample(0, vec![0.0; 4], 0),
+            mock_sample(1, vec![10.0, 20.0, 30.0, 100.0], 0),
+        ]).await;
+        app.update().await.unwrap();
+        assert_eq!(app.cpu_count(), 4);
+        assert_eq!(app.cpu_average, 40.0);
+    }
+    
+    #[tokio::test]
+    async fn test_refresh_rate_resizes_history() {
+        let samples = (0..40).map(|i| mock_sample(i, vec![i as f32], 0)).collect();
+        let mut app = mock_app(samples).await;
+        app.history_seconds = 10;
+        app.set_refresh_ms(250);
+        assert_eq!(app.cpu_history_len, 40);
+        for _ in 0..39 {
+            app.update().await.unwrap();
+        }
+        assert_eq!(app.cpu_history.len(), 40);
+        
+        // Aralık iki katına çıkınca aynı 10 saniye yarı sayıda örnekle kapsanır - en yeniler kalır
+        app.set_refresh_ms(500);
+        assert_eq!(app.cpu_history_len, 20);
+        assert_eq!(app.cpu_history.len(), 20);
+        assert_eq!(app.memory_history.len(), 20);
+        assert_eq!(app.cpu_history.back().unwrap()[0], 39.0);
+        
+        // Sınır büyüyünce mevcut geçmiş korunur
+        app.set_refresh_ms(100);
+        assert_eq!(app.cpu_history_len, 100);
+        assert_eq!(app.cpu_history.len(), 20);
+    }
+    
+    #[tokio::test]
+    async fn test_swap_rates_from_page_counters() {
+        let samples = [(0, 100, 50), (1, 100, 50), (3, 612, 50)]
+            .into_iter()
+            .map(|(seconds, pages_in, pages_out)| Sample {
+                swap_counters: Some(SwapCounters { pages_in, pages_out }),
+                ..mock_sample(seconds, vec![0.0], 0)
+            })
+            .collect();
+        let mut app = mock_app(samples).await;
+        app.update().await.unwrap();
+        assert_eq!(app.swap_rates, Some((0, 0)));
+        
+        // 2 saniyede 512 sayfa = 1 MiB/s
+        app.update().await.unwrap();
+        assert_eq!(app.swap_rates, Some((1024 * 1024, 0)));
+    }
+    
+    // Önce belirli sayıda hata veren, sonra örneklere dönen kaynak
+    struct FlakySource {
+        failures: usize,
+        samples: VecDeque<Sample>,
+    }
+    
+    impl MetricsSource for FlakySource {
+        fn next_sample(&mut self) -> Result<Option<Sample>> {
+            // İlk örnek App::new'de istenir, hatalar ondan sonra başlar
+            if self.samples.len() == 1 && self.failures > 0 {
+                self.failures -= 1;
+                anyhow::bail!("system refresh failed");
+            }
+            Ok(self.samples.pop_front())
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_source_errors_logged_once_per_outage() {
+        let samples = VecDeque::from([mock_sample(0, vec![10.0], 0), mock_sample(1, vec![20.0], 0)]);
+        let source = FlakySource { failures: 3, samples };
+        let mut app = App::new(Config::default(), ViewState::default(), Box::new(source)).await.unwrap();
+        
+        for _ in 0..4 {
+            app.update().await.unwrap();
+        }
+        let log: Vec<(Severity, &str)> = app.events.iter().map(|entry| (entry.severity, entry.text.as_str())).collect();
+        assert_eq!(
+            log,
+            vec![(Severity::Error, "Data unavailable: system refresh failed"), (Severity::Info, "Data source recovered")]
+        );
+        assert_eq!(app.sample.cpu_usage, vec![20.0]);
+    }
+    
+    // CPU'yu en fazla 200 ms'de bir okuyabilen kaynak
+    struct SlowCpuSource;
+    
+    impl MetricsSource for SlowCpuSource {
+        fn next_sample(&mut self) -> Result<Option<Sample>> {
+            Ok(None)
+        }
+        
+        fn min_cpu_interval(&self) -> Option<std::time::Duration> {
+            Some(std::time::Duration::from_millis(200))
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_cpu_sample_interval_respects_source_floor() {
+        let mut app = App::new(Config::default(), ViewState::default(), Box::new(SlowCpuSource)).await.unwrap();
+        app.set_refresh_ms(100);
+        assert_eq!(app.cpu_sample_ms(), 200);
+        app.set_refresh_ms(500);
+        assert_eq!(app.cpu_sample_ms(), 500);
+        
+        // Alt sınırı olmayan kaynakta CPU her turda okunur
+        let mut app = mock_app(Vec::new()).await;
+        app.set_refresh_ms(100);
+        assert_eq!(app.cpu_sample_ms(), 100);
+    }
+    
+    #[tokio::test]
+    async fn test_temperature_history_prunes_missing_sensors() {
+        let sample = |seconds: i64, sensors: &[(&str, f32)]| Sample {
+            temperatures: sensors
+                .iter()
+                .map(|&(name, temp)| TemperatureInfo { component_name: name.to_string(), current_temp: temp, max_temp: None, critical_temp: None })
+                .collect(),
+            ..mock_sample(seconds, vec![0.0], 0)
+        };
+        let samples = vec![
+            sample(0, &[("CPU", 60.0), ("NVMe", 40.0)]),
+            sample(1, &[("CPU", 90.0), ("NVMe", 41.0)]),
+            sample(2, &[("CPU", 65.0)]),
+        ];
+        let mut app = mock_app(samples).await;
+        app.update().await.unwrap();
+        assert_eq!(app.temperature_history["NVMe"], [40.0, 41.0]);
+        
+        app.update().await.unwrap();
+        assert_eq!(app.temperature_history["CPU"], [60.0, 90.0, 65.0]);
+        assert!(!app.temperature_history.contains_key("NVMe"));
+    }
+    
+    #[tokio::test]
+    async fn test_full_process_table_selection() {
+        let mut sample = mock_sample(0, vec![0.0], 0);
+        sample.processes = (1..=40)
+            .map(|pid| ProcessSample { pid, name: format!("p{pid}"), cpu_usage: pid as f32, ..Default::default() })
+            .collect();
+        let mut app = mock_app(vec![sample]).await;
+        
+        // Processes sekmesinde sınır yok ve seçim ilk 10 tablosundan bağımsız
+        app.active_tab = Tab::Processes;
+        assert_eq!(app.visible_processes().len(), 40);
+        app.move_process_selection(25);
+        assert_eq!(app.selected_process_row().unwrap().pid, 15);
+        assert_eq!(app.selected_process, 0);
+        app.move_process_selection(isize::MAX);
+        assert_eq!(app.selected_full_process, 39);
+        
+        // Her kolon bir sıralama kriteri - PID artan sırada
+        app.set_process_sort(ProcessSortKey::Pid);
+        assert_eq!(app.selected_process_row().unwrap().pid, 40);
+        app.active_tab = Tab::Overview;
+        assert_eq!(app.selected_process_row().unwrap().pid, 1);
+    }
+    
+    #[tokio::test]
+    async fn test_power_source_switches_refresh_profile() {
+        let on_battery = |seconds, state| {
+            let mut sample = mock_sample(seconds, vec![10.0], 0);
+            sample.batteries = vec![BatteryInfo { percent: 80.0, state, time_to_empty: None, time_to_full: None }];
+            sample
+        };
+        let samples = vec![
+            on_battery(0, ChargeState::Charging),
+            on_battery(1, ChargeState::Charging),
+            on_battery(2, ChargeState::Discharging),
+            on_battery(3, ChargeState::Discharging),
+            on_battery(4, ChargeState::Full),
+        ];
+        let config = Config { power: PowerProfiles { ac_ms: 0, battery_ms: 2000 }, ..Default::default() };
+        let mut app = App::new(config, ViewState::default(), Box::new(MockSource::new(samples))).await.unwrap();
+        app.set_refresh_ms(500);
+        
+        // Açılışta AC - aralık değişmez, günlüğe bir şey yazılmaz
+        app.update().await.unwrap();
+        assert_eq!(app.refresh_ms, 500);
+        assert!(app.events.is_empty());
+        
+        app.update().await.unwrap();
+        app.update().await.unwrap();
+        assert_eq!(app.refresh_ms, 2000);
+        assert_eq!(app.view_state().refresh_ms, 500);
+        
+        // Fişe takılınca önceki aralığa dönülür
+        app.update().await.unwrap();
+        assert_eq!(app.refresh_ms, 500);
+        let log: Vec<&str> = app.events.iter().map(|entry| entry.text.as_str()).collect();
+        assert_eq!(log, vec!["On battery power, refreshing every 2000 ms", "On AC power, refreshing every 500 ms"]);
+    }
+    
+    #[tokio::test]
+    async fn test_process_tree_collapse_keeps_selection() {
+        let mut sample = mock_sample(0, vec![0.0], 0);
+        sample.processes = [(1, None, 0.0), (10, Some(1), 1.0), (11, Some(10), 9.0), (20, Some(1), 5.0)]
+            .into_iter()
+            .map(|(pid, parent_pid, cpu_usage)| ProcessSample { pid, parent_pid, name: format!("p{pid}"), cpu_usage, ..Default::default() })
+            .collect();
+        let mut app = mock_app(vec![sample]).await;
+        app.active_tab = Tab::Processes;
+        let pids = |app: &App| app.visible_processes().iter().map(|p| p.pid).collect::<Vec<_>>();
+        
+        // Düz listede 10 ikinci sırada; ağaca geçince seçim yine onda kalır
+        app.move_process_selection(2);
+        assert_eq!(app.selected_process_row().unwrap().pid, 10);
+        app.toggle_process_tree();
+        assert_eq!(pids(&app), vec![1, 20, 10, 11]);
+        assert_eq!(app.selected_process_row().unwrap().pid, 10);
+        
+        app.toggle_collapse();
+        assert_eq!(pids(&app), vec![1, 20, 10]);
+        assert!(app.selected_process_row().unwrap().tree.unwrap().collapsed);
+        
+        // Toplamlarla 10'un alt ağacı (10%) 20'yi geçer
+        app.toggle_tree_totals();
+        assert_eq!(pids(&app), vec![1, 10, 20]);
+        assert_eq!(app.selected_process_row().unwrap().pid, 10);
+        assert_eq!(app.visible_processes()[0].cpu_usage, 15.0);
+        app.toggle_collapse();
+        assert_eq!(pids(&app), vec![1, 10, 11, 20]);
+    }
+    
+    #[tokio::test]
+    async fn test_pinned_process_stays_in_top_list() {
+        let processes = |pids: &[u32]| -> Vec<ProcessSample> {
+            pids.iter()
+                .map(|&pid| ProcessSample { pid, name: format!("p{pid}"), cpu_usage: pid as f32, ..Default::default() })
+                .collect()
+        };
+        let mut first = mock_sample(0, vec![0.0], 0);
+        first.processes = processes(&(1..=20).collect::<Vec<_>>());
+        let mut second = mock_sample(1, vec![0.0], 0);
+        second.processes = processes(&(2..=20).collect::<Vec<_>>());
+        let mut app = mock_app(vec![first.clone(), first, second]).await;
+        app.process_count = 3;
+        
+        // Tam listenin en altındaki (en az CPU kullanan) 1 sabitlenir
+        app.active_tab = Tab::Processes;
+        app.move_process_selection(isize::MAX);
+        app.toggle_pin();
+        assert!(app.pinned_pids.contains(&1));
+        app.active_tab = Tab::Overview;
+        let pids: Vec<u32> = app.top_processes().iter().map(|p| p.pid).collect();
+        assert_eq!(pids, vec![20, 19, 18, 1]);
+        
+        app.update().await.unwrap();
+        assert!(app.pinned_pids.contains(&1));
+        // Process kapanınca sabitleme kalkar
+        app.update().await.unwrap();
+        assert!(app.pinned_pids.is_empty());
+        assert_eq!(app.top_processes().len(), 3);
+    }
+    
+    #[tokio::test]
+    async fn test_equal_keys_keep_stable_order() {
+        let mut sample = mock_sample(0, vec![0.0], 0);
+        sample.processes = [(30, "beta"), (10, "gamma"), (20, "alpha"), (40, "beta")]
+            .into_iter()
+            .map(|(pid, name)| ProcessSample { pid, name: name.to_string(), cpu_usage: 5.0, memory: 1024, ..Default::default() })
+            .collect();
+        let mut app = mock_app(vec![sample]).await;
+        let pids = |app: &App| app.sorted_processes().iter().map(|p| p.pid).collect::<Vec<_>>();
+        
+        // Girdi sırası değişse de eşit CPU'lu satırlar PID sırasında kalır
+        assert_eq!(pids(&app), vec![10, 20, 30, 40]);
+        app.sample.processes.reverse();
+        assert_eq!(pids(&app), vec![10, 20, 30, 40]);
+        app.set_process_sort(ProcessSortKey::Memory);
+        assert_eq!(pids(&app), vec![10, 20, 30, 40]);
+        
+        // İsim seçilirse önce isim, aynı isimde yine PID
+        app.tie_break = TieBreak::Name;
+        assert_eq!(pids(&app), vec![20, 30, 40, 10]);
+        app.set_process_sort(ProcessSortKey::Name);
+        assert_eq!(pids(&app), vec![20, 30, 40, 10]);
+    }
+    
+    #[tokio::test]
+    async fn test_interface_chart_falls_back_to_aggregate() {
+        let interface = |name: &str, received: u64| InterfaceCounters {
+            name: name.to_string(),
+            received,
+            transmitted: 0,
+        };
+        let mut samples = Vec::new();
+        for (seconds, interfaces) in [
+            (0, vec![interface("eth0", 0)]),
+            (1, vec![interface("eth0", 1000), interface("wg0", 0)]),
+            (2, vec![interface("eth0", 3000), interface("wg0", 500)]),
+            (3, vec![interface("eth0", 4000)]),
+        ] {
+            let mut sample = mock_sample(seconds, vec![10.0], 0);
+            sample.interfaces = interfaces;
+            samples.push(sample);
+        }
+        let mut app = mock_app(samples).await;
+        app.update().await.unwrap();
+        app.update().await.unwrap();
+        assert_eq!(app.interface_history["eth0"].back(), Some(&(2000, 0)));
+        assert_eq!(app.interface_history["wg0"].back(), Some(&(500, 0)));
+        
+        // Tüm arayüzler → eth0 → wg0 → tekrar hepsi
+        app.cycle_interface();
+        assert_eq!(app.selected_interface.as_deref(), Some("eth0"));
+        app.cycle_interface();
+        assert_eq!(app.selected_interface.as_deref(), Some("wg0"));
+        assert_eq!(app.network_chart_history().back(), Some(&(500, 0)));
+        
+        // wg0 kayboldu - grafik toplama döner ve bu not edilir
+        app.update().await.unwrap();
+        assert_eq!(app.selected_interface, None);
+        assert!(!app.interface_history.contains_key("wg0"));
+        assert_eq!(
+            app.status_message.as_ref().unwrap().text,
+            "Interface wg0 disappeared, showing all interfaces"
+        );
+        app.cycle_interface();
+        app.cycle_interface();
+        assert_eq!(app.selected_interface, None);
+    }
+    
+    #[tokio::test]
+    async fn test_idle_cores_collapse_into_summary() {
+        let config = Config {
+            idle_cores: IdleCores::Collapse,
+            ..Default::default()
+        };
+        let sample = mock_sample(0, vec![1.0, 50.0, 0.0, 4.9, 5.0], 0);
+        let mut app = App::new(config, ViewState::default(), Box::new(MockSource::new(vec![sample.clone(), sample]))).await.unwrap();
+        app.update().await.unwrap();
+        assert_eq!(
+            app.cpu_rows(),
+            vec![CpuRow::Core(1), CpuRow::Core(4), CpuRow::Idle { count: 3 }]
+        );
+        
+        // Normal moda dönünce tüm çekirdekler yine ayrı satırda
+        app.cycle_idle_cores();
+        assert_eq!(app.idle_cores, IdleCores::Show);
+        assert_eq!(app.cpu_rows().len(), 5);
+    }
+    
+    #[tokio::test]
+    async fn test_cpu_history_follows_hotplug() {
+        let mut sample = mock_sample(0, vec![10.0, 20.0, 30.0], 0);
+        sample.cpu_ids = vec![0, 1, 2];
+        let mut offline = mock_sample(1, vec![10.0, 30.0], 0);
+        offline.cpu_ids = vec![0, 2];
+        let mut online = mock_sample(2, vec![10.0, 50.0, 30.0], 0);
+        online.cpu_ids = vec![0, 1, 2];
+        let mut app = mock_app(vec![sample.clone(), sample, offline, online]).await;
+        app.update().await.unwrap();
+        
+        // CPU1 kapandı - geçmişte onun sütunu atılır, CPU2 yerinde kalır
+        app.update().await.unwrap();
+        assert_eq!(app.cpu_ids, vec![0, 2]);
+        assert!(app.cpu_history.iter().all(|usage| usage.len() == 2));
+        assert_eq!(app.cpu_history[1], vec![10.0, 30.0]);
+        assert_eq!(app.cpu_id(1), 2);
+        assert_eq!(app.status_message.as_ref().unwrap().text, "CPU 1 went offline");
+        
+        // Tekrar açıldı - geçmişi o turun ortalamasıyla dolar
+        app.update().await.unwrap();
+        assert_eq!(app.cpu_history[1], vec![10.0, 20.0, 30.0]);
+        assert_eq!(app.cpu_history[2], vec![10.0, 20.0, 30.0]);
+        assert_eq!(app.cpu_history[3], vec![10.0, 50.0, 30.0]);
+    }
+    
+    #[tokio::test]
+    async fn test_bulk_kill_by_name() {
+        let process = |pid: u32, name: &str| ProcessSample { pid, name: name.to_string(), ..Default::default() };
+        let mut sample = mock_sample(0, vec![0.0], 0);
+        sample.processes = vec![process(1, "systemd"), process(40, "chrome"), process(30, "chrome_crashpad"), process(50, "bash")];
+        let mut app = mock_app(vec![sample]).await;
+        
+        // Desen seçili process'in adıyla başlar
+        app.open_bulk_kill_popup();
+        let selected = app.selected_process_row().unwrap().name;
+        assert_eq!(app.bulk_kill_popup.as_ref().unwrap().pattern, selected);
+        app.bulk_kill_popup.as_mut().unwrap().pattern.clear();
+        "chrome".chars().for_each(|c| app.edit_bulk_kill_pattern(Some(c)));
+        app.review_bulk_kill();
+        let pids: Vec<u32> = app.bulk_kill_matches().iter().map(|process| process.pid).collect();
+        assert_eq!(pids, vec![30, 40]);
+        assert!(app.bulk_kill_popup.as_ref().unwrap().confirming);
+        
+        // Sahte kaynak sinyal gönderemez - hepsi başarısız sayılır
+        app.confirm_bulk_kill(false);
+        assert!(app.bulk_kill_popup.is_none());
+        let status = app.status_message.as_ref().unwrap();
+        assert!(status.is_error);
+        assert!(status.text.starts_with("Sent TERM (graceful) to 0 of 2 processes matching 'chrome' (2 failed"), "{}", status.text);
+        
+        // Kritik process eşleşirse açık onay olmadan hiçbir şey gönderilmez
+        app.open_bulk_kill_popup();
+        app.bulk_kill_popup.as_mut().unwrap().pattern = "s".to_string();
+        app.review_bulk_kill();
+        app.confirm_bulk_kill(false);
+        assert!(app.bulk_kill_popup.is_some());
+        
+        // Eşleşme yoksa onay aşamasına geçilmez
+        "zzz".chars().for_each(|c| app.edit_bulk_kill_pattern(Some(c)));
+        app.bulk_kill_popup.as_mut().unwrap().confirming = false;
+        app.review_bulk_kill();
+        assert!(!app.bulk_kill_popup.as_ref().unwrap().confirming);
+    }
+    
+    #[tokio::test]
+    async fn test_first_network_speed_discarded() {
+        let mut app = mock_app(vec![
+            mock_sample(0, vec![0.0], 0),
+            mock_sample(1, vec![0.0], 0),
+            // Arayüzler ilk kez göründü - açılıştan beri biriken 50 GB
+            mock_sample(2, vec![0.0], 50_000_000_000),
+            mock_sample(3, vec![0.0], 50_000_001_000),
+        ]).await;
+        
+        app.update().await.unwrap();
+        app.update().await.unwrap();
+        assert!(app.network_history.is_empty());
+        assert_eq!(app.peaks.download, 0);
+        
+        // Sonraki hız gerçek trafiği gösterir
+        app.update().await.unwrap();
+        assert_eq!(app.network_history.back(), Some(&(1_000, 0)));
+        assert_eq!(app.peaks.download, 1_000);
+    }
+    
+    #[tokio::test]
+    async fn test_pause_on_alert_and_resume() {
+        let mut config = Config { pause_on_alert: true, ..Default::default() };
+        config.alerts.sustain_secs = 0;
+        let samples = vec![
+            mock_sample(0, vec![10.0], 0),
+            mock_sample(1, vec![10.0], 1_000),
+            mock_sample(2, vec![99.0], 2_000),
+            // Duraklama sırasında 60 saniye geçti
+            mock_sample(62, vec![10.0], 62_000),
+            mock_sample(63, vec![10.0], 63_000),
+            mock_sample(64, vec![10.0], 64_000),
+        ];
+        let mut app = App::new(config, ViewState::default(), Box::new(MockSource::new(samples))).await.unwrap();
+        
+        app.update().await.unwrap();
+        app.update().await.unwrap();
+        assert!(app.paused);
+        assert!(app.pause_reason.as_deref().is_some_and(|reason| reason.contains("CPU")));
+        let history_len = app.network_history.len();
+        
+        // Devam edince sayaçlar sıfırdan başlar - duraklamayı kapsayan fark hız olarak sayılmaz
+        app.toggle_pause();
+        assert!(!app.paused && app.pause_reason.is_none());
+        app.update().await.unwrap();
+        app.update().await.unwrap();
+        assert_eq!(app.network_history.len(), history_len);
+        app.update().await.unwrap();
+        assert_eq!(app.network_history.back(), Some(&(1_000, 0)));
+        
+        // Elle dondurmanın nedeni yok
+        app.toggle_pause();
+        assert!(app.paused && app.pause_reason.is_none());
+    }
+    
+    #[tokio::test]
+    async fn test_slow_categories_keep_previous_values() {
+        let mut config = Config::default();
+        config.refresh.net_ms = 60_000;
+        config.refresh.proc_ms = 60_000;
+        config.refresh.temp_ms = 60_000;
+        let process = |pid| ProcessSample { pid, name: format!("p{pid}"), ..Default::default() };
+        let mut first = mock_sample(0, vec![10.0], 1_000);
+        first.processes = vec![process(1)];
+        first.gpus = vec![crate::gpu::GpuInfo {
+            name: "gpu0".to_string(),
+            utilization: 40,
+            memory_used: 0,
+            memory_total: 0,
+            temperature: None,
+        }];
+        let mut second = mock_sample(1, vec![50.0], 9_000);
+        second.processes = vec![process(2)];
+        let mut app = App::new(config, ViewState::default(), Box::new(MockSource::new(vec![first, second]))).await.unwrap();
+        
+        app.update().await.unwrap();
+        // CPU her turda, ağ ve process'ler kendi aralıklarında
+        assert_eq!(app.sample.cpu_usage, vec![50.0]);
+        assert_eq!(app.sample.network_received, 1_000);
+        assert_eq!(app.sample.processes[0].pid, 1);
+        // Kaynak zamanı gelmeyen sensörleri okumadı - GPU önceki örnekten gelir
+        assert_eq!(app.sample.gpus.len(), 1);
+        assert!(app.network_history.is_empty());
+    }
+    
+    #[test]
+    fn test_hold_last_rate_keeps_window() {
+        let mut history: VecDeque<(u64, u64)> = VecDeque::new();
+        hold_last_rate(&mut history, 2);
+        assert!(history.is_empty());
+        history.extend([(1, 2), (3, 4)]);
+        hold_last_rate(&mut history, 2);
+        assert_eq!(history, VecDeque::from([(3, 4), (3, 4)]));
+    }
+    
+    #[tokio::test]
+    async fn test_event_log_records_status_and_alerts() {
+        let mut config = Config::default();
+        config.alerts.sustain_secs = 0;
+        let samples = vec![mock_sample(0, vec![10.0], 0), mock_sample(1, vec![99.0], 0)];
+        let mut app = App::new(config, ViewState::default(), Box::new(MockSource::new(samples))).await.unwrap();
+        
+        app.set_status("Exported processes".to_string(), false);
+        app.set_status("Failed to kill 42".to_string(), true);
+        app.update().await.unwrap();
+        let log: Vec<(Severity, &str)> = app.events.iter().map(|entry| (entry.severity, entry.text.as_str())).collect();
+        assert_eq!(log[0], (Severity::Info, "Exported processes"));
+        assert_eq!(log[1], (Severity::Error, "Failed to kill 42"));
+        assert_eq!(log[2].0, Severity::Warning);
+        assert!(log[2].1.contains("CPU"));
+        
+        // Kaydırma kayıt sayısıyla sınırlı, günlük en fazla MAX_EVENTS kayıt tutar
+        app.events_popup = Some(0);
+        app.scroll_events(isize::MAX);
+        assert_eq!(app.events_popup, Some(app.events.len() - 1));
+        for i in 0..MAX_EVENTS {
+            app.log_event(Severity::Info, i.to_string());
+        }
+        assert_eq!(app.events.len(), MAX_EVENTS);
+        assert_eq!(app.events.front().unwrap().text, "0");
+    }
+    
+    #[tokio::test]
+    async fn test_copy_snapshot_falls_back_to_file() {
+        let mut app = mock_app(vec![mock_sample(0, vec![10.0], 0)]).await;
+        app.copy_snapshot("screen text".to_string());
+        let status = app.status_message.clone().unwrap();
+        assert!(!status.is_error);
+        // Panoya erişilemeyen ortamda (testlerde genelde) metin dosyaya yazılır
+        if let Some((_, path)) = status.text.split_once("saved snapshot to ") {
+            assert_eq!(std::fs::read_to_string(path).unwrap(), "screen text");
+            std::fs::remove_file(path).unwrap();
+        } else {
+            assert_eq!(status.text, "Copied screen to clipboard");
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_network_delta_uses_sample_time() {
+        let mut app = mock_app(vec![
+            mock_sample(0, vec![0.0], 1_000),
+            mock_sample(1, vec![0.0], 1_000),
+            mock_sample(1, vec![0.0], 1_000),
+            mock_sample(3, vec![0.0], 5_000),
+            // Sayaç resetlendi - negatif hız yerine sıfır
+            mock_sample(4, vec![0.0], 200),
+        ]).await;
+        
+        // İlk güncelleme sadece önceki sayacı kaydeder, ikincisinin hızı atılır
+        app.update().await.unwrap();
+        app.update().await.unwrap();
+        assert!(app.network_history.is_empty());
+        assert!(app.network_stats().is_none());
+        
+        // 2 saniyede 4000 byte
+        app.update().await.unwrap();
+        assert_eq!(app.network_history.back(), Some(&(2_000, 0)));
+        
+        app.update().await.unwrap();
+        assert_eq!(app.network_history.back(), Some(&(0, 0)));
+        
+        // Pencere: 2000 ve 0
+        let (download, upload) = app.network_stats().unwrap();
+        assert_eq!(download, RateStats { average: 1_000, min: 0, max: 2_000 });
+        assert_eq!(upload, RateStats::default());
+    }
+    
+    #[tokio::test]
+    async fn test_disk_history_prunes_vanished_mounts() {
+        let disk = |mount: &str, usage_percent: f32| DiskInfo {
+            name: String::new(),
+            mount_point: mount.to_string(),
+            total_space: 100,
+            available_space: 0,
+            used_space: 0,
+            usage_percent,
+            file_system: "ext4".to_string(),
+        };
+        let sample = |seconds: i64, disks: Vec<DiskInfo>| Sample {
+            disks,
+            ..mock_sample(seconds, vec![0.0], 0)
+        };
+        let mut app = mock_app(vec![
+            sample(0, Vec::new()),
+            sample(0, vec![disk("/", 10.0), disk("/media/usb", 50.0)]),
+            // Aralık dolmadan gelen örnek geçmişe eklenmez
+            sample(5, vec![disk("/", 11.0), disk("/media/usb", 50.0)]),
+            sample(10, vec![disk("/", 12.0)]),
+        ]).await;
+        for _ in 0..3 {
+            app.update().await.unwrap();
+        }
+        
+        assert_eq!(app.disk_history.len(), 1);
+        assert_eq!(app.disk_history["/"], VecDeque::from(vec![10.0, 12.0]));
+    }
+    
+    #[tokio::test]
+    async fn test_worst_disk_needs_warning_level() {
+        let disk = |mount: &str, usage_percent: f32, file_system: &str| DiskInfo {
+            name: String::new(),
+            mount_point: mount.to_string(),
+            total_space: 100,
+            available_space: 0,
+            used_space: 0,
+            usage_percent,
+            file_system: file_system.to_string(),
+        };
+        let mut app = mock_app(vec![mock_sample(0, vec![0.0], 0)]).await;
+        app.sample.disks = vec![disk("/", 40.0, "ext4"), disk("/data", 60.0, "ext4"), disk("/snap/core", 100.0, "squashfs")];
+        // Uyarı eşiği (%70) altında özet gösterilmez; squashfs sayılmaz
+        assert!(app.worst_disk().is_none());
+        
+        app.sample.disks[0].usage_percent = 96.0;
+        assert_eq!(app.worst_disk().unwrap().mount_point, "/");
+    }
+    
+    #[tokio::test]
+    async fn test_process_io_rates_per_pid() {
+        let process = |pid: u32, disk_read: u64, disk_written: u64| ProcessSample {
+            pid,
+            name: format!("p{pid}"),
+            disk_read,
+            disk_written,
+            ..Default::default()
+        };
+        let samples = vec![
+            Sample { processes: vec![process(1, 1000, 0), process(2, 500, 500)], ..mock_sample(0, vec![0.0], 0) },
+            // 2 kapandı, 3 yeni başladı
+            Sample { processes: vec![process(1, 5000, 2000), process(3, 800, 0)], ..mock_sample(2, vec![0.0], 0) },
+        ];
+        let mut app = mock_app(samples).await;
+        app.update().await.unwrap();
+        
+        assert_eq!(app.process_io_rates.get(&1), Some(&(2000, 1000)));
+        // Önceki değeri olmayan process sıfırdan başlamış sayılır
+        assert_eq!(app.process_io_rates.get(&3), Some(&(400, 0)));
+        assert!(!app.process_io_rates.contains_key(&2));
+        assert!(!app.prev_process_io.contains_key(&2));
+        
+        app.set_process_sort(ProcessSortKey::DiskRead);
+        let order: Vec<u32> = app.sorted_processes().iter().map(|p| p.pid).collect();
+        assert_eq!(order, vec![1, 3]);
+    }
+    
+    #[tokio::test]
+    async fn test_top_talkers_names_and_limit() {
+        let process = |pid: u32, name: &str| ProcessSample { pid, name: name.to_string(), ..Default::default() };
+        let talker = |pid: u32, connections: u32| crate::talkers::ProcessConnections { pid, connections };
+        let sample = Sample {
+            processes: vec![process(1, "firefox"), process(2, "kworker/0"), process(3, "postgres")],
+            process_connections: Some(vec![talker(1, 20), talker(2, 9), talker(3, 4), talker(4, 1)]),
+            ..mock_sample(0, vec![0.0], 0)
+        };
+        let mut app = mock_app(vec![sample]).await;
+        app.ignore_processes = vec!["kworker/*".to_string()];
+        app.network_talkers = 2;
+        
+        let talkers = app.top_talkers().unwrap();
+        assert_eq!(talkers, vec![(1, "firefox".to_string(), 20), (3, "postgres".to_string(), 4)]);
+        
+        // Özellik kapalıyken (veya Linux dışında) liste yok
+        app.sample.process_connections = None;
+        assert_eq!(app.top_talkers(), None);
+    }
+    
+    #[tokio::test]
+    async fn test_top_memory_ignores_table_sort() {
+        let process = |pid: u32, memory: u64, cpu_usage: f32| ProcessSample { pid, name: format!("p{pid}"), cpu_usage, memory, ..Default::default() };
+        let sample = Sample {
+            memory_total: 1000,
+            processes: vec![process(1, 100, 90.0), process(2, 500, 1.0), process(3, 300, 50.0)],
+            ..mock_sample(0, vec![0.0], 0)
+        };
+        let mut app = mock_app(vec![sample]).await;
+        app.process_sort = ProcessSortKey::Cpu;
+        
+        let top: Vec<u32> = app.top_memory_processes(2).iter().map(|p| p.pid).collect();
+        assert_eq!(top, vec![2, 3]);
+        assert_eq!(app.top_memory_processes(1)[0].memory_percent, 50.0);
+        assert_eq!(app.process_sort, ProcessSortKey::Cpu);
+        assert_eq!(app.top_processes()[0].pid, 1);
+    }
+    
+    #[tokio::test]
+    async fn test_process_count_steps_and_auto_limit() {
+        let mut app = mock_app(Vec::new()).await;
+        assert_eq!(app.process_limit(), 10);
+        app.more_processes();
+        assert_eq!(app.process_count, 15);
+        app.fewer_processes();
+        app.fewer_processes();
+        app.fewer_processes();
+        assert_eq!(app.process_count, 5);
         
-        // İlk 10 process'i döndür
-        processes.into_iter().take(10).collect()
+        // Otomatik mod tablo gövdesinin yüksekliğini kullanır
+        app.process_count = 0;
+        app.hit_areas.set(HitAreas {
+            process_rows: Some(Rect { x: 0, y: 0, width: 40, height: 23 }),
+            ..Default::default()
+        });
+        assert_eq!(app.process_limit(), 23);
+        app.more_processes();
+        assert_eq!(app.process_count, 30);
     }
 }
\ No newline at end of file
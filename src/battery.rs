@@ -0,0 +1,161 @@
+// battery.rs - Laptop batarya bilgilerini toplayan modül
+// sysinfo batarya bilgisi vermediği için `battery` crate'ini kullanıyoruz.
+// Crate sadece `battery` özelliği açıkken derlenir; kapalıyken collector hep boş liste döner.
+// Bataryası olmayan masaüstü ve sunucularda da liste boş kalır, panel hiç çizilmez
+
+use serde::{Deserialize, Serialize};
+
+// Bataryanın şarj durumu
+// Özellik kapalıyken hiçbir değer üretilmez, UI yine de derlenebilsin diye tip hep var
+#[cfg_attr(not(feature = "battery"), allow(dead_code))]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ChargeState {
+    Charging,
+    Discharging,
+    Full,
+    Unknown,
+}
+
+impl ChargeState {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChargeState::Charging => "Charging",
+            ChargeState::Discharging => "Discharging",
+            ChargeState::Full => "Full",
+            ChargeState::Unknown => "Unknown",
+        }
+    }
+}
+
+// Tek bir bataryanın anlık durumu
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatteryInfo {
+    pub percent: f32,
+    pub state: ChargeState,
+    pub time_to_empty: Option<u64>, // saniye - sadece deşarj olurken
+    pub time_to_full: Option<u64>,  // saniye - sadece şarj olurken
+}
+
+// Bilgisayarın o an beslendiği kaynak
+// `battery` crate'i adaptörü ayrıca raporlamaz - deşarj olan bir batarya varsa bataryadayız.
+// Bataryası olmayan (veya özellik kapalı) sistemler hep AC sayılır
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerSource {
+    Ac,
+    Battery,
+}
+
+impl PowerSource {
+    pub fn detect(batteries: &[BatteryInfo]) -> PowerSource {
+        if batteries.iter().any(|battery| battery.state == ChargeState::Discharging) {
+            PowerSource::Battery
+        } else {
+            PowerSource::Ac
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PowerSource::Ac => "AC",
+            PowerSource::Battery => "battery",
+        }
+    }
+}
+
+// [power] tablosu - güç kaynağına göre yenileme aralığı (ms)
+// 0 o kaynakta aralığa dokunmaz; ikisi de 0 ise (varsayılan) özellik kapalıdır
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct PowerProfiles {
+    pub ac_ms: u64,
+    pub battery_ms: u64,
+}
+
+impl PowerProfiles {
+    pub fn enabled(&self) -> bool {
+        self.ac_ms != 0 || self.battery_ms != 0
+    }
+}
+
+// Batarya seviyesini kategorize et - renk kodlaması için
+#[derive(Debug, Clone, PartialEq)]
+pub enum BatteryLevel {
+    High,
+    Low,
+    Critical,
+}
+
+pub fn categorize_battery(percent: f32) -> BatteryLevel {
+    match percent as u32 {
+        0..=10 => BatteryLevel::Critical, // Kritik - kırmızı
+        11..=30 => BatteryLevel::Low,     // Düşük - sarı
+        _ => BatteryLevel::High,          // Yeterli - yeşil
+    }
+}
+
+// Batarya bilgilerini toplayan struct
+// Manager açılamazsa (desteklenmeyen platform, izin sorunu) hata vermek yerine boş liste döneriz
+pub struct BatteryCollector {
+    #[cfg(feature = "battery")]
+    manager: Option<battery::Manager>,
+}
+
+impl BatteryCollector {
+    pub fn new() -> Self {
+        Self {
+            #[cfg(feature = "battery")]
+            manager: battery::Manager::new().ok(),
+        }
+    }
+
+    // Sistemdeki tüm bataryaları oku
+    #[cfg(feature = "battery")]
+    pub fn collect(&self) -> Vec<BatteryInfo> {
+        let Some(manager) = &self.manager else {
+            return Vec::new();
+        };
+        let Ok(batteries) = manager.batteries() else {
+            return Vec::new();
+        };
+        batteries
+            .flatten()
+            .map(|battery| BatteryInfo {
+                percent: battery.state_of_charge().value * 100.0,
+                state: match battery.state() {
+                    battery::State::Charging => ChargeState::Charging,
+                    battery::State::Discharging => ChargeState::Discharging,
+                    battery::State::Full => ChargeState::Full,
+                    _ => ChargeState::Unknown,
+                },
+                time_to_empty: battery.time_to_empty().map(|time| time.value as u64),
+                time_to_full: battery.time_to_full().map(|time| time.value as u64),
+            })
+            .collect()
+    }
+
+    // Özellik kapalıyken batarya desteği yok
+    #[cfg(not(feature = "battery"))]
+    pub fn collect(&self) -> Vec<BatteryInfo> {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_battery_categorization() {
+        assert_eq!(categorize_battery(95.0), BatteryLevel::High);
+        assert_eq!(categorize_battery(25.0), BatteryLevel::Low);
+        assert_eq!(categorize_battery(5.0), BatteryLevel::Critical);
+    }
+
+    #[test]
+    fn test_power_source_detection() {
+        let battery = |state| BatteryInfo { percent: 50.0, state, time_to_empty: None, time_to_full: None };
+        assert_eq!(PowerSource::detect(&[]), PowerSource::Ac);
+        assert_eq!(PowerSource::detect(&[battery(ChargeState::Charging), battery(ChargeState::Full)]), PowerSource::Ac);
+        assert_eq!(PowerSource::detect(&[battery(ChargeState::Full), battery(ChargeState::Discharging)]), PowerSource::Battery);
+    }
+}
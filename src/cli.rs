@@ -0,0 +1,77 @@
+// cli.rs - Komut satırı argümanları
+// clap'in derive API'si ile struct alanları doğrudan argümanlara dönüşür
+
+use clap::Parser;
+use std::path::PathBuf;
+
+#[derive(Debug, Parser)]
+#[command(version, about = "Lightweight terminal system monitor")]
+pub struct Cli {
+    /// Expose Prometheus metrics on this port (e.g. 9100) at /metrics
+    #[arg(long, value_name = "PORT")]
+    pub metrics_port: Option<u16>,
+
+    /// Run without the terminal UI (only useful together with --metrics-port)
+    #[arg(long, requires = "metrics_port")]
+    pub no_tui: bool,
+
+    /// Push metrics to InfluxDB at this URL (e.g. http://localhost:8086) in line protocol
+    #[arg(long, value_name = "URL")]
+    pub influx_url: Option<String>,
+
+    /// InfluxDB database (bucket) to write into
+    #[arg(long, value_name = "DB", default_value = "sysmon", requires = "influx_url")]
+    pub influx_db: String,
+
+    /// Print one frame of the UI as plain text to stdout and exit
+    #[arg(long, conflicts_with = "no_tui")]
+    pub snapshot: bool,
+
+    /// Print the metrics snapshot as one JSON object per line on every refresh, without a UI
+    #[arg(long, conflicts_with_all = ["snapshot", "no_tui"])]
+    pub stream_json: bool,
+
+    /// Refresh interval in milliseconds (overrides the saved one for this run)
+    #[arg(long, value_name = "MS")]
+    pub refresh_ms: Option<u64>,
+
+    /// Read-only mode: disable killing processes and every other signal-sending action
+    #[arg(long)]
+    pub safe: bool,
+
+    /// Do not capture the mouse (keeps the terminal's own text selection working)
+    #[arg(long)]
+    pub no_mouse: bool,
+
+    /// Record every update to this file (JSON Lines) for later replay
+    #[arg(long, value_name = "FILE")]
+    pub record: Option<PathBuf>,
+
+    /// Replay a recorded session instead of monitoring this machine
+    #[arg(long, value_name = "FILE", conflicts_with = "record")]
+    pub replay: Option<PathBuf>,
+
+    /// Replay speed multiplier (2 = twice as fast)
+    #[arg(long, value_name = "FACTOR", default_value_t = 1.0, requires = "replay")]
+    pub speed: f64,
+
+    /// Feed the UI with synthetic oscillating data (development and demos)
+    #[arg(long, hide = true, conflicts_with_all = ["replay", "remote"])]
+    pub demo: bool,
+
+    /// Monitor a remote machine over SSH (runs --agent there)
+    #[arg(long, value_name = "USER@HOST", conflicts_with = "replay")]
+    pub remote: Option<String>,
+
+    /// Command that starts the agent on the remote machine
+    #[arg(long, value_name = "CMD", default_value = "rust-system-monitor --agent", requires = "remote")]
+    pub remote_command: String,
+
+    /// Print a sample per interval as JSON Lines to stdout without a UI (used by --remote)
+    #[arg(long, conflicts_with_all = ["remote", "replay", "record", "snapshot", "no_tui"])]
+    pub agent: bool,
+
+    /// Sampling interval of --agent in milliseconds
+    #[arg(long, value_name = "MS", default_value_t = 1000, requires = "agent")]
+    pub agent_interval_ms: u64,
+}
@@ -0,0 +1,27 @@
+// clipboard.rs - Metni sistem panosuna kopyalama (clipboard özelliği ile)
+// Başsız sunucularda ve SSH oturumlarında genelde pano yoktur; o zaman hata döner ve çağıran
+// metni dosyaya yazar. Özellik kapalıyken de aynı yol izlenir.
+// X11'de pano içeriği onu sunan process yaşadığı sürece durur - bu yüzden bağlantı açık tutulur
+
+#[derive(Default)]
+pub struct Clipboard {
+    #[cfg(feature = "clipboard")]
+    inner: Option<arboard::Clipboard>,
+}
+
+impl Clipboard {
+    // Pano ilk kopyalamada açılır - açılamazsa bir sonraki denemede tekrar denenir
+    #[cfg(feature = "clipboard")]
+    pub fn copy(&mut self, text: &str) -> Result<(), String> {
+        let clipboard = match &mut self.inner {
+            Some(clipboard) => clipboard,
+            None => self.inner.insert(arboard::Clipboard::new().map_err(|err| err.to_string())?),
+        };
+        clipboard.set_text(text).map_err(|err| err.to_string())
+    }
+
+    #[cfg(not(feature = "clipboard"))]
+    pub fn copy(&mut self, _text: &str) -> Result<(), String> {
+        Err("built without clipboard support".to_string())
+    }
+}
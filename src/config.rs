@@ -0,0 +1,463 @@
+// config.rs - Kullanıcı ayarlarını yükleyen modül
+// Ayarlar ~/.config/rust-system-monitor/config.toml dosyasından okunur
+// Dosya yoksa veya bir alan eksikse varsayılan değerler kullanılır
+
+use anyhow::{anyhow, bail, Context, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{Deserialize, Deserializer};
+use std::{collections::HashMap, env, fmt::Display, fs, ops::RangeInclusive, path::PathBuf, str::FromStr};
+
+use crate::alerts::AlertThresholds;
+use crate::battery::PowerProfiles;
+use crate::app::{IdleCores, TieBreak, REFRESH_STEPS_MS};
+use crate::input::{normalize_key, Action};
+use crate::panels::{PanelVisibility, Section};
+use crate::schedule::RefreshIntervals;
+use crate::system_info::{ByteUnits, ColorThresholds, DiskFilter};
+
+// Uygulamanın tüm ayarlarını tutan ana struct
+// #[serde(default)] sayesinde kullanıcı sadece değiştirmek istediği alanları yazabilir
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    // Dolu disk veya kritik sıcaklıkta masaüstü bildirimi gönder
+    pub notify_on_critical: bool,
+    
+    // Bir uyarı tetiklendiğinde ekranı dondur - tetikleyen durum değişmeden okunabilsin ('p' ile devam)
+    pub pause_on_alert: bool,
+    
+    // Salt okunur mod - process öldürme ve sinyal gönderen tüm eylemler kapalı (--safe ile de açılır)
+    pub read_only: bool,
+    
+    // 'q'/Esc'e basınca hemen çıkmak yerine "Quit? (y/n)" sor
+    pub confirm_quit: bool,
+    
+    // Açılıştaki yenileme aralığı (ms) - verilirse son oturumdan kalan hız yerine kullanılır
+    pub refresh_ms: Option<u64>,
+    
+    // Ağ hızlarını bit/s (Mbps) olarak göster - varsayılan byte/s (MB/s)
+    pub network_bits: bool,
+    
+    // Ağ grafiğinde logaritmik y ekseni - düşük hızlardaki ayrıntı büyük patlamalarda kaybolmaz
+    pub network_log_scale: bool,
+    
+    // CPU geçmiş grafiğindeki kayan ortalamanın pencere boyu (örnek sayısı)
+    // 1 yumuşatma yok demek; gauge'lar her zaman anlık değeri gösterir
+    pub cpu_smoothing: usize,
+    
+    // Boştaki çekirdekler: "show", "dim" (soluk) veya "collapse" (tek satırda özet); 'D' ile değişir
+    // Eşiğin altındaki çekirdekler boşta sayılır (%)
+    pub idle_cores: IdleCores,
+    pub idle_core_threshold: f32,
+    
+    // Grafiklerin kapsadığı süre (saniye) - örnek sayısı yenileme aralığına göre hesaplanır
+    pub history_seconds: u64,
+    
+    // Başlıktaki uptime bu kadar günden sonra sarı, iki katından sonra kırmızı olur (0 = kapalı)
+    pub uptime_warning_days: u64,
+    
+    // Process tablosundaki satır sayısı - 0 panele sığdığı kadar gösterir
+    pub process_count: usize,
+    
+    // "Network by Process" listesindeki process sayısı (net_talkers özelliği ile, Linux)
+    pub network_talkers: usize,
+    
+    // Process listesinden gizlenecek isimler - "kworker" gibi alt metin veya "kworker/*" gibi glob
+    pub ignore_processes: Vec<String>,
+    
+    // Monitörün kendi process'ini listede gösterme
+    pub hide_self: bool,
+    
+    // Ağaç görünümünde ebeveynin CPU, bellek ve disk değerleri alt ağacın toplamı olsun ('A' ile değişir)
+    pub process_tree_totals: bool,
+    
+    // Sıralama kriterinde eşit kalan process'lerin sırası: "pid" veya "name"
+    pub process_tie_break: TieBreak,
+    
+    // Başlıkta her başarılı yenilemede ilerleyen küçük nabız göstergesi
+    pub heartbeat: bool,
+    
+    // Emoji ve Braille yerine sade ASCII - Unicode desteği zayıf terminaller ve fontlar için
+    pub ascii_only: bool,
+    
+    // Byte birimleri: "binary" (1024, KiB/MiB) veya "decimal" (1000, KB/MB)
+    pub byte_units: ByteUnits,
+    
+    // [alerts] tablosu - eşik değerleri ve ne kadar süre aşılması gerektiği
+    pub alerts: AlertThresholds,
+    
+    // [disks] tablosu - disk listesinde gizlenecek sahte dosya sistemleri
+    pub disks: DiskFilter,
+    
+    // [refresh] tablosu - kategori başına yenileme aralıkları ("proc_ms = 2000" gibi)
+    pub refresh: RefreshIntervals,
+    
+    // [power] tablosu - AC ve batarya için ayrı yenileme aralıkları (battery özelliği ile)
+    pub power: PowerProfiles,
+    
+    // [panels] tablosu - açılışta gizlenecek bölümler ("cpu_chart = false" gibi), 'V' ile değişir
+    pub panels: PanelVisibility,
+    
+    // [thresholds] tablosu - gauge, sıcaklık ve disk renklerinin değiştiği sınırlar
+    pub thresholds: ColorThresholds,
+    
+    // [keybindings] tablosu - "ctrl+c" = "quit" gibi tuş → eylem atamaları
+    // Atanmayan tuşlar varsayılan davranışlarını korur
+    #[serde(deserialize_with = "deserialize_keybindings")]
+    pub keybindings: HashMap<KeyEvent, Action>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            notify_on_critical: false,
+            pause_on_alert: false,
+            read_only: false,
+            confirm_quit: false,
+            refresh_ms: None,
+            network_bits: false,
+            network_log_scale: false,
+            cpu_smoothing: 1,
+            idle_cores: IdleCores::default(),
+            idle_core_threshold: 5.0,
+            history_seconds: 60,
+            uptime_warning_days: 30,
+            process_count: 10,
+            network_talkers: 5,
+            ignore_processes: Vec::new(),
+            hide_self: false,
+            process_tree_totals: false,
+            process_tie_break: TieBreak::default(),
+            heartbeat: true,
+            ascii_only: false,
+            byte_units: ByteUnits::default(),
+            alerts: AlertThresholds::default(),
+            disks: DiskFilter::default(),
+            refresh: RefreshIntervals::default(),
+            power: PowerProfiles::default(),
+            thresholds: ColorThresholds::default(),
+            panels: PanelVisibility::default(),
+            keybindings: HashMap::new(),
+        }
+    }
+}
+
+impl Config {
+    // Varsayılan konumdaki config dosyasını yükle, üzerine SYSMON_* ortam değişkenlerini uygula
+    // Dosya hiç yoksa hata değil, varsayılan ayarlar döner. Yok sayılan ortam değerleri için
+    // uyarılar da döner - main bunları gösterir
+    pub fn load() -> Result<(Self, Vec<String>)> {
+        let mut config = match config_path() {
+            Some(path) if path.exists() => {
+                let contents = fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read config file {}", path.display()))?;
+                Self::from_toml(&contents)
+                    .with_context(|| format!("Invalid config file {}", path.display()))?
+            }
+            _ => Self::default(),
+        };
+        let warnings = config.apply_env(|name| env::var(name).ok())?;
+        Ok((config, warnings))
+    }
+    
+    // Ortam değişkenlerindeki ayarlar config dosyasını ezer (komut satırı da bunları)
+    // Config dosyası bağlamanın zahmetli olduğu konteynerler için. Hatalı değer, hatalı config
+    // dosyası gibi açılışı durdurur - yanlış yazılmış bir eşik sessizce varsayılana dönmesin.
+    // Sayı olarak geçerli ama aralık dışındaki değerler (örn. %500) uyarıyla yok sayılır
+    pub fn apply_env(&mut self, var: impl Fn(&str) -> Option<String>) -> Result<Vec<String>> {
+        let var = |name: &'static str| var(name).map(|value| (name, value));
+        let mut warnings = Vec::new();
+        let max_refresh_ms = REFRESH_STEPS_MS[REFRESH_STEPS_MS.len() - 1];
+        if let Some((name, value)) = var("SYSMON_REFRESH_MS") {
+            let refresh_ms = parse_env(name, &value)?;
+            if let Some(refresh_ms) = in_range(name, refresh_ms, REFRESH_STEPS_MS[0]..=max_refresh_ms, &mut warnings) {
+                self.refresh_ms = Some(refresh_ms);
+            }
+        }
+        if let Some((name, value)) = var("SYSMON_CPU_WARN") {
+            if let Some(percent) = in_range(name, parse_env(name, &value)?, 0.0..=100.0, &mut warnings) {
+                self.alerts.cpu_percent = percent;
+            }
+        }
+        if let Some((name, value)) = var("SYSMON_MEMORY_WARN") {
+            if let Some(percent) = in_range(name, parse_env(name, &value)?, 0.0..=100.0, &mut warnings) {
+                self.alerts.memory_percent = percent;
+            }
+        }
+        if let Some((name, value)) = var("SYSMON_ALERT_SUSTAIN_SECS") {
+            self.alerts.sustain_secs = parse_env(name, &value)?;
+        }
+        if let Some((name, value)) = var("SYSMON_USAGE_COLORS") {
+            self.thresholds.usage = parse_env_list(name, &value)?;
+        }
+        if let Some((name, value)) = var("SYSMON_DISK_COLORS") {
+            self.thresholds.disk = parse_env_list(name, &value)?;
+        }
+        if let Some((name, value)) = var("SYSMON_HIDE_PANELS") {
+            for key in value.split(',').map(str::trim).filter(|key| !key.is_empty()) {
+                let Some(section) = Section::from_key(key) else {
+                    bail!("{name}: unknown panel \"{key}\"");
+                };
+                self.panels.set_visible(section, false);
+            }
+        }
+        if let Some((name, value)) = var("SYSMON_READ_ONLY") {
+            self.read_only = match value.trim().to_ascii_lowercase().as_str() {
+                "1" | "true" | "yes" | "on" => true,
+                "0" | "false" | "no" | "off" | "" => false,
+                _ => bail!("{name}: expected true or false, got \"{value}\""),
+            };
+        }
+        self.thresholds.validate()?;
+        Ok(warnings)
+    }
+
+    // TOML metninden config oluştur - testlerde de kullanılır
+    pub fn from_toml(contents: &str) -> Result<Self> {
+        let config: Self = toml::from_str(contents)?;
+        config.thresholds.validate()?;
+        config.refresh.validate()?;
+        Ok(config)
+    }
+}
+
+// [keybindings] tablosunu oku - bilinmeyen tuş veya eylem adı config hatasıdır,
+// sessizce yok saymak kullanıcının neden tuşunun çalışmadığını anlamasını zorlaştırır
+fn deserialize_keybindings<'de, D>(deserializer: D) -> Result<HashMap<KeyEvent, Action>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let raw = HashMap::<String, String>::deserialize(deserializer)?;
+    raw.into_iter()
+        .map(|(key, action)| {
+            let key = parse_key(&key).map_err(serde::de::Error::custom)?;
+            let action = Action::from_name(&action)
+                .ok_or_else(|| serde::de::Error::custom(format!("unknown action \"{action}\"")))?;
+            Ok((key, action))
+        })
+        .collect()
+}
+
+// "ctrl+c", "k", "F1", "shift+tab" gibi bir tuş tanımını KeyEvent'e çevir
+// Değiştiriciler '+' ile ayrılır, son parça tuşun kendisidir (büyük/küçük harf duyarsız adlar)
+pub fn parse_key(text: &str) -> Result<KeyEvent> {
+    let mut parts: Vec<&str> = text.split('+').collect();
+    // "+" tuşunun kendisi - "ctrl++" gibi yazımlarda son parça boş kalır
+    let key = match parts.pop() {
+        Some("") if text.ends_with('+') => {
+            parts.pop();
+            "+"
+        }
+        Some(key) => key,
+        None => bail!("empty key"),
+    };
+    
+    let mut modifiers = KeyModifiers::NONE;
+    for modifier in parts {
+        modifiers |= match modifier.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => KeyModifiers::CONTROL,
+            "alt" => KeyModifiers::ALT,
+            "shift" => KeyModifiers::SHIFT,
+            _ => bail!("unknown modifier \"{modifier}\" in key \"{text}\""),
+        };
+    }
+    
+    let mut chars = key.chars();
+    let code = match (chars.next(), chars.next()) {
+        (Some(c), None) => KeyCode::Char(c),
+        _ => match key.to_ascii_lowercase().as_str() {
+            "enter" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "space" => KeyCode::Char(' '),
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "insert" => KeyCode::Insert,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" | "pgup" => KeyCode::PageUp,
+            "pagedown" | "pgdn" => KeyCode::PageDown,
+            name => match name.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                Some(n @ 1..=24) => KeyCode::F(n),
+                _ => bail!("unknown key \"{text}\""),
+            },
+        },
+    };
+    
+    // Shift + harf büyük harf demektir, Shift + Tab ise BackTab
+    let code = match code {
+        KeyCode::Char(c) if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::Char(c.to_ascii_uppercase()),
+        KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+        code => code,
+    };
+    Ok(normalize_key(KeyEvent::new(code, modifiers)))
+}
+
+// Tek bir ortam değişkeni değeri - hata mesajında değişkenin adı geçer
+fn parse_env<T: FromStr>(name: &str, value: &str) -> Result<T> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| anyhow!("{name}: invalid value \"{value}\""))
+}
+
+// Değer aralıktaysa döner; değilse uyarı eklenir ve None döner - önceki ayar geçerli kalır
+fn in_range<T: PartialOrd + Display>(name: &str, value: T, range: RangeInclusive<T>, warnings: &mut Vec<String>) -> Option<T> {
+    if range.contains(&value) {
+        return Some(value);
+    }
+    warnings.push(format!("{name}: {value} is outside {}-{}, ignored", range.start(), range.end()));
+    None
+}
+
+// "50,80" gibi virgülle ayrılmış eşik listesi - sayı adedi config'deki dizilerle aynı olmalı
+fn parse_env_list<const N: usize>(name: &str, value: &str) -> Result<[f32; N]> {
+    let values = value.split(',').map(|part| parse_env(name, part)).collect::<Result<Vec<f32>>>()?;
+    values
+        .try_into()
+        .map_err(|_| anyhow!("{name}: expected {N} comma-separated values, got \"{value}\""))
+}
+
+// Config dosyasının yolu - XDG standardına uyuyoruz
+// Önce $XDG_CONFIG_HOME, yoksa ~/.config kullanılır
+pub fn config_path() -> Option<PathBuf> {
+    let base = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(base.join("rust-system-monitor").join("config.toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_config_uses_defaults() {
+        let config = Config::from_toml("[alerts]\ncpu_percent = 75.0\n").unwrap();
+        assert_eq!(config.alerts.cpu_percent, 75.0);
+        assert_eq!(config.alerts.memory_percent, AlertThresholds::default().memory_percent);
+    }
+
+    #[test]
+    fn test_ignore_processes() {
+        let config = Config::from_toml("ignore_processes = [\"kworker/*\", \"rcu_\"]\nhide_self = true\n").unwrap();
+        assert_eq!(config.ignore_processes, vec!["kworker/*", "rcu_"]);
+        assert!(config.hide_self);
+    }
+
+    #[test]
+    fn test_decimal_byte_units() {
+        let config = Config::from_toml("byte_units = \"decimal\"\n").unwrap();
+        assert_eq!(config.byte_units, ByteUnits::Decimal);
+    }
+
+    #[test]
+    fn test_empty_config_is_default() {
+        let config = Config::from_toml("").unwrap();
+        assert_eq!(config.alerts.sustain_secs, AlertThresholds::default().sustain_secs);
+        assert!(!config.notify_on_critical);
+        assert!(!config.confirm_quit);
+        assert_eq!(config.cpu_smoothing, 1);
+        assert_eq!(config.byte_units, ByteUnits::Binary);
+        assert!(config.keybindings.is_empty());
+    }
+
+    #[test]
+    fn test_thresholds_table() {
+        let config = Config::from_toml("[thresholds]\nusage = [60.0, 85.0]\n").unwrap();
+        assert_eq!(config.thresholds.usage, [60.0, 85.0]);
+        assert_eq!(config.thresholds.disk, ColorThresholds::default().disk);
+        assert!(Config::from_toml("[thresholds]\ntemperature = [40.0, 80.0, 75.0, 85.0]\n").is_err());
+    }
+
+    #[test]
+    fn test_refresh_table() {
+        let config = Config::from_toml("[refresh]\ncpu_ms = 250\nproc_ms = 2000\n").unwrap();
+        assert_eq!(config.refresh.proc_ms, 2_000);
+        assert_eq!(config.refresh.net_ms, 0);
+        // CPU sysinfo'nun ölçebildiğinden sık okunamaz
+        assert!(Config::from_toml("[refresh]\ncpu_ms = 50\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_key() {
+        assert_eq!(parse_key("k").unwrap(), KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE));
+        assert_eq!(parse_key("ctrl+c").unwrap(), KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        assert_eq!(parse_key("F1").unwrap(), KeyEvent::new(KeyCode::F(1), KeyModifiers::NONE));
+        assert_eq!(parse_key("shift+g").unwrap(), KeyEvent::new(KeyCode::Char('G'), KeyModifiers::NONE));
+        assert_eq!(parse_key("shift+tab").unwrap(), KeyEvent::new(KeyCode::BackTab, KeyModifiers::NONE));
+        assert_eq!(parse_key("+").unwrap(), KeyEvent::new(KeyCode::Char('+'), KeyModifiers::NONE));
+        assert!(parse_key("hyper+x").is_err());
+        assert!(parse_key("F99").is_err());
+    }
+
+    #[test]
+    fn test_keybindings_table() {
+        let config = Config::from_toml("[keybindings]\n\"ctrl+c\" = \"quit\"\nF5 = \"faster_refresh\"\n").unwrap();
+        assert_eq!(
+            config.keybindings.get(&KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+            Some(&Action::Quit)
+        );
+        assert_eq!(
+            config.keybindings.get(&KeyEvent::new(KeyCode::F(5), KeyModifiers::NONE)),
+            Some(&Action::FasterRefresh)
+        );
+        assert!(Config::from_toml("[keybindings]\nk = \"fly\"\n").is_err());
+    }
+    
+    #[test]
+    fn test_env_overrides_config_file() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| vars.iter().find(|(key, _)| *key == name).map(|(_, value)| value.to_string())
+        };
+        let mut config = Config::from_toml("refresh_ms = 2000\n[alerts]\ncpu_percent = 75.0\nmemory_percent = 80.0\n").unwrap();
+        config
+            .apply_env(env(&[
+                ("SYSMON_REFRESH_MS", "500"),
+                ("SYSMON_CPU_WARN", "60"),
+                ("SYSMON_USAGE_COLORS", "40, 70"),
+                ("SYSMON_HIDE_PANELS", "cpu_chart,network"),
+                ("SYSMON_READ_ONLY", "true"),
+            ]))
+            .unwrap();
+        assert_eq!(config.refresh_ms, Some(500));
+        assert_eq!(config.alerts.cpu_percent, 60.0);
+        // Ortamda olmayan ayar config dosyasındaki değerini korur
+        assert_eq!(config.alerts.memory_percent, 80.0);
+        assert_eq!(config.thresholds.usage, [40.0, 70.0]);
+        assert!(!config.panels.cpu_chart && !config.panels.network && config.panels.cpu_gauges);
+        assert!(config.read_only);
+        
+        // Aralık dışındaki değerler açılışı durdurmaz - uyarılır ve önceki ayar kalır
+        let warnings = config
+            .apply_env(env(&[("SYSMON_REFRESH_MS", "0"), ("SYSMON_CPU_WARN", "500"), ("SYSMON_MEMORY_WARN", "-5")]))
+            .unwrap();
+        assert_eq!(
+            warnings,
+            vec![
+                "SYSMON_REFRESH_MS: 0 is outside 100-5000, ignored",
+                "SYSMON_CPU_WARN: 500 is outside 0-100, ignored",
+                "SYSMON_MEMORY_WARN: -5 is outside 0-100, ignored",
+            ]
+        );
+        assert_eq!(config.refresh_ms, Some(500));
+        assert_eq!(config.alerts.cpu_percent, 60.0);
+        assert_eq!(config.alerts.memory_percent, 80.0);
+        
+        // Hatalı değerler açılışı durdurur
+        for vars in [
+            &[("SYSMON_CPU_WARN", "high")][..],
+            &[("SYSMON_USAGE_COLORS", "50")],
+            &[("SYSMON_USAGE_COLORS", "80,50")],
+            &[("SYSMON_HIDE_PANELS", "gpu")],
+            &[("SYSMON_READ_ONLY", "maybe")],
+        ] {
+            assert!(Config::default().apply_env(env(vars)).is_err(), "{vars:?}");
+        }
+    }
+}
@@ -0,0 +1,85 @@
+// connections.rs - Durumlarına göre TCP bağlantı sayıları
+// sysinfo soketleri kapsamaz. Linux'ta /proc/net/tcp ve /proc/net/tcp6 doğrudan okunur.
+// Sürekli artan ESTABLISHED / CLOSE_WAIT sayısı bağlantı sızıntısının en kolay işaretidir.
+// Diğer platformlarda (veya proc dosyaları okunamazsa) sayılar yoktur ve panel gizlenir
+
+use serde::{Deserialize, Serialize};
+
+// Bir ölçümdeki TCP soket sayıları - IPv4 ve IPv6 toplamı
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConnectionCounts {
+    pub established: u32,
+    pub listen: u32,
+    pub time_wait: u32,
+    pub close_wait: u32, // Karşı taraf kapattı ama uygulama soketi kapatmadı
+    pub other: u32,      // SYN_SENT, FIN_WAIT vb. geçiş durumları
+}
+
+impl ConnectionCounts {
+    pub fn total(&self) -> u32 {
+        self.established + self.listen + self.time_wait + self.close_wait + self.other
+    }
+}
+
+// /proc/net/tcp{,6} içeriğindeki soketleri say
+// İlk satır başlık; her satırın 4. sütunu ("st") onaltılık durum kodu
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub fn count_proc_net_tcp(contents: &str, counts: &mut ConnectionCounts) {
+    for line in contents.lines().skip(1) {
+        let Some(state) = line.split_whitespace().nth(3) else {
+            continue;
+        };
+        // Çekirdeğin include/net/tcp_states.h sıralaması
+        match state {
+            "01" => counts.established += 1,
+            "06" => counts.time_wait += 1,
+            "08" => counts.close_wait += 1,
+            "0A" => counts.listen += 1,
+            _ => counts.other += 1,
+        }
+    }
+}
+
+// IPv4 ve IPv6 tablolarını oku - IPv6 kapalı bir sistemde tcp6 olmayabilir,
+// ikisi de okunamazsa None
+#[cfg(target_os = "linux")]
+pub fn read_connections() -> Option<ConnectionCounts> {
+    let mut counts = ConnectionCounts::default();
+    let mut found = false;
+    for path in ["/proc/net/tcp", "/proc/net/tcp6"] {
+        if let Ok(contents) = std::fs::read_to_string(path) {
+            count_proc_net_tcp(&contents, &mut counts);
+            found = true;
+        }
+    }
+    found.then_some(counts)
+}
+
+// /proc/net olmayan platformlarda bağlantı paneli gösterilmez
+#[cfg(not(target_os = "linux"))]
+pub fn read_connections() -> Option<ConnectionCounts> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_proc_net_tcp() {
+        let contents = "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode\n\
+           0: 00000000:0016 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 1 1 0 100 0 0 10 0\n\
+           1: 0100007F:1F90 0100007F:C350 01 00000000:00000000 00:00000000 00000000  1000        0 2 1 0 20 4 30 10 -1\n\
+           2: 0100007F:1F90 0100007F:C352 06 00000000:00000000 03:00000F2A 00000000     0        0 0 3 0\n\
+           3: 0100007F:1F90 0100007F:C354 08 00000000:00000000 00:00000000 00000000  1000        0 3 1 0 20 4 30 10 -1\n\
+           4: 0100007F:1F90 0100007F:C356 02 00000000:00000000 00:00000000 00000000  1000        0 4 1 0 20 4 30 10 -1\n";
+        let mut counts = ConnectionCounts::default();
+        count_proc_net_tcp(contents, &mut counts);
+        count_proc_net_tcp(contents, &mut counts);
+        assert_eq!(
+            counts,
+            ConnectionCounts { established: 2, listen: 2, time_wait: 2, close_wait: 2, other: 2 }
+        );
+        assert_eq!(counts.total(), 10);
+    }
+}
@@ -0,0 +1,70 @@
+// container.rs - Konteyner içinde mi çalışıyoruz?
+// Docker/Podman/Kubernetes içinde sysinfo'nun sensör ve disk listeleri çoğu zaman boş döner:
+// /sys/class/hwmon ve blok aygıtları konteynere bağlanmamıştır. Boş liste tek başına "bu
+// makinede sensör yok" ile "bu ortamda görülemiyor" arasında ayrım yapmaz; paneller doğru
+// mesajı gösterebilsin diye çalışma ortamı bilinen işaretlerden tahmin edilir
+
+use std::fs;
+use std::path::Path;
+
+// PID 1'in cgroup yolunda geçen çalışma ortamı adları - ilk eşleşen kullanılır
+const CGROUP_MARKERS: &[(&str, &str)] = &[
+    ("kubepods", "Kubernetes"),
+    ("libpod", "Podman"),
+    ("docker", "Docker"),
+    ("containerd", "containerd"),
+    ("lxc", "LXC"),
+];
+
+// Konteynerin türü - işaret yoksa None. `root` testlerde sahte bir kök dizindir
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub fn detect_container_from(root: &Path) -> Option<String> {
+    if root.join(".dockerenv").exists() {
+        return Some("Docker".to_string());
+    }
+    if root.join("run/.containerenv").exists() {
+        return Some("Podman".to_string());
+    }
+    // cgroup v1'de yol konteyner kimliğini içerir. cgroup v2'de içeriden bakınca genelde
+    // sadece "0::/" görünür - o durumda yukarıdaki dosyalar tek ipucudur
+    let cgroup = fs::read_to_string(root.join("proc/1/cgroup")).ok()?;
+    CGROUP_MARKERS
+        .iter()
+        .find(|(marker, _)| cgroup.contains(marker))
+        .map(|(_, name)| name.to_string())
+}
+
+#[cfg(target_os = "linux")]
+pub fn detect_container() -> Option<String> {
+    detect_container_from(Path::new("/"))
+}
+
+// Diğer platformlarda konteyner tespiti yapılmaz
+#[cfg(not(target_os = "linux"))]
+pub fn detect_container() -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_container_markers() {
+        let root = std::env::temp_dir().join(format!("sysmon-container-{}", std::process::id()));
+        fs::create_dir_all(root.join("proc/1")).unwrap();
+
+        // Sıradan bir makine: cgroup v2 kökü
+        fs::write(root.join("proc/1/cgroup"), "0::/init.scope\n").unwrap();
+        assert_eq!(detect_container_from(&root), None);
+
+        fs::write(root.join("proc/1/cgroup"), "12:pids:/kubepods/besteffort/pod1234/abcd\n").unwrap();
+        assert_eq!(detect_container_from(&root).as_deref(), Some("Kubernetes"));
+
+        fs::write(root.join(".dockerenv"), "").unwrap();
+        let detected = detect_container_from(&root);
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(detected.as_deref(), Some("Docker"));
+        assert_eq!(detect_container_from(Path::new("/nonexistent")), None);
+    }
+}
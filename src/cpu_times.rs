@@ -0,0 +1,147 @@
+// cpu_times.rs - CPU zamanının user / system / iowait / idle dağılımı
+// sysinfo'nun Cpu tipi sadece toplam kullanımı verir. Yükün kullanıcı alanında mı
+// çekirdekte mi olduğunu görmek için Linux'ta /proc/stat doğrudan okunur.
+// Diğer platformlarda collector hiçbir şey döndürmez ve dağılım çubuğu gizlenir
+
+use serde::{Deserialize, Serialize};
+
+// /proc/stat'taki "cpu" satırı - açılıştan beri geçen süre (jiffy cinsinden)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CpuTimes {
+    pub user: u64,
+    pub nice: u64,
+    pub system: u64,
+    pub idle: u64,
+    pub iowait: u64,
+    pub irq: u64,
+    pub softirq: u64,
+    pub steal: u64,
+}
+
+impl CpuTimes {
+    fn total(&self) -> u64 {
+        self.user + self.nice + self.system + self.idle + self.iowait + self.irq + self.softirq + self.steal
+    }
+}
+
+// İki ölçüm arasındaki yüzdelik dağılım - toplamı 100
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct CpuBreakdown {
+    pub user: f32,   // user + nice
+    pub system: f32, // system + irq + softirq
+    pub iowait: f32,
+    pub steal: f32,  // Sanal makinelerde hypervisor'ın aldığı pay
+    pub idle: f32,
+}
+
+impl CpuBreakdown {
+    // İki ölçüm arasındaki farktan dağılımı hesapla
+    // Sayaçlar geriye gittiyse (ör. CPU hotplug) veya zaman geçmediyse None
+    pub fn between(previous: &CpuTimes, current: &CpuTimes) -> Option<Self> {
+        let total = current.total().checked_sub(previous.total())?;
+        if total == 0 {
+            return None;
+        }
+        let percent = |now: u64, before: u64| now.saturating_sub(before) as f32 * 100.0 / total as f32;
+        Some(Self {
+            user: percent(current.user + current.nice, previous.user + previous.nice),
+            system: percent(
+                current.system + current.irq + current.softirq,
+                previous.system + previous.irq + previous.softirq,
+            ),
+            iowait: percent(current.iowait, previous.iowait),
+            steal: percent(current.steal, previous.steal),
+            idle: percent(current.idle, previous.idle),
+        })
+    }
+}
+
+// /proc/stat içeriğinden toplam "cpu" satırını oku
+// Eski çekirdeklerde sondaki alanlar olmayabilir, eksikler 0 sayılır
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub fn parse_proc_stat(contents: &str) -> Option<CpuTimes> {
+    let line = contents.lines().find(|line| line.starts_with("cpu "))?;
+    let values: Vec<u64> = line
+        .split_whitespace()
+        .skip(1)
+        .map(|value| value.parse().ok())
+        .collect::<Option<_>>()?;
+    if values.len() < 4 {
+        return None;
+    }
+    let field = |index: usize| values.get(index).copied().unwrap_or(0);
+    Some(CpuTimes {
+        user: field(0),
+        nice: field(1),
+        system: field(2),
+        idle: field(3),
+        iowait: field(4),
+        irq: field(5),
+        softirq: field(6),
+        steal: field(7),
+    })
+}
+
+// Her güncellemede bir önceki ölçümü hatırlayıp aradaki dağılımı hesaplayan collector
+#[derive(Debug, Default)]
+pub struct CpuTimesCollector {
+    previous: Option<CpuTimes>,
+}
+
+impl CpuTimesCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Yeni ölçüm al - ilk çağrıda karşılaştıracak ölçüm olmadığı için None
+    pub fn sample(&mut self) -> Option<CpuBreakdown> {
+        let current = read_cpu_times()?;
+        let breakdown = self
+            .previous
+            .and_then(|previous| CpuBreakdown::between(&previous, &current));
+        self.previous = Some(current);
+        breakdown
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn read_cpu_times() -> Option<CpuTimes> {
+    parse_proc_stat(&std::fs::read_to_string("/proc/stat").ok()?)
+}
+
+// /proc/stat olmayan platformlarda dağılım gösterilmez
+#[cfg(not(target_os = "linux"))]
+fn read_cpu_times() -> Option<CpuTimes> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_proc_stat() {
+        let contents = "cpu  100 5 50 800 20 1 4 0 0 0\ncpu0 100 5 50 800 20 1 4 0 0 0\nintr 1 2 3\n";
+        let times = parse_proc_stat(contents).unwrap();
+        assert_eq!(times.user, 100);
+        assert_eq!(times.nice, 5);
+        assert_eq!(times.iowait, 20);
+        assert_eq!(times.softirq, 4);
+        assert!(parse_proc_stat("intr 1 2 3\n").is_none());
+    }
+
+    #[test]
+    fn test_breakdown_between_samples() {
+        let previous = CpuTimes { user: 100, system: 50, idle: 850, ..Default::default() };
+        let current = CpuTimes { user: 150, system: 60, idle: 880, iowait: 10, ..Default::default() };
+        let breakdown = CpuBreakdown::between(&previous, &current).unwrap();
+        assert_eq!(breakdown.user, 50.0);
+        assert_eq!(breakdown.system, 10.0);
+        assert_eq!(breakdown.iowait, 10.0);
+        assert_eq!(breakdown.idle, 30.0);
+
+        // Zaman geçmediyse veya sayaç geriye gittiyse dağılım yok
+        assert!(CpuBreakdown::between(&current, &current).is_none());
+        assert!(CpuBreakdown::between(&current, &previous).is_none());
+    }
+}
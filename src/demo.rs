@@ -0,0 +1,255 @@
+// demo.rs - Sahte ama hareketli metrikler üreten kaynak (--demo)
+// Kırmızı gauge'ları, uyarıları veya log ölçekli ağ grafiğini görmek için makineyi gerçekten
+// zorlamak gerekmesin diye: değerler farklı periyotlarda salınır ve her eşiği düzenli olarak
+// geçer. Düzen üzerinde çalışırken de her panel dolu olduğu için elle test aracı olarak kullanılır
+
+use anyhow::Result;
+use chrono::{Duration as ChronoDuration, Local};
+use std::f64::consts::TAU;
+use std::time::Instant;
+
+use crate::connections::ConnectionCounts;
+use crate::fans::FanInfo;
+use crate::source::{HostInfo, InterfaceCounters, MetricsSource, ProcessSample, Sample};
+use crate::system_info::{DiskInfo, TemperatureInfo};
+use crate::talkers::ProcessConnections;
+use crate::vmstat::SwapCounters;
+use crate::zram::ZramStats;
+
+const DEMO_CORES: usize = 8;
+const GIB: u64 = 1024 * 1024 * 1024;
+const DEMO_MEMORY: u64 = 16 * GIB;
+const DEMO_DISK: u64 = 512 * GIB;
+
+// Sahte process'ler - (ad, ortalama CPU yüzdesi, bellek GiB)
+const DEMO_PROCESSES: &[(&str, f64, f64)] = &[
+    ("cargo", 60.0, 1.5),
+    ("rust-analyzer", 25.0, 2.5),
+    ("firefox", 15.0, 3.0),
+    ("postgres", 8.0, 1.0),
+    ("node", 5.0, 0.8),
+    ("sshd", 0.5, 0.01),
+    ("systemd", 0.2, 0.02),
+];
+
+pub struct DemoSource {
+    started: Instant,
+    // Açılıştan beri biriken sayaçlar - hızlar App'te bunların farkından hesaplanır
+    network: (f64, f64),
+    disk_io: (f64, f64),
+    swap: (f64, f64), // Sayfa cinsinden
+    last_secs: f64,
+}
+
+impl DemoSource {
+    pub fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            network: (0.0, 0.0),
+            disk_io: (0.0, 0.0),
+            swap: (0.0, 0.0),
+            last_secs: 0.0,
+        }
+    }
+
+    // Başlangıçtan `secs` saniye sonraki örnek - zamana göre belirlenir, testlerde doğrudan çağrılır
+    fn sample_at(&mut self, secs: f64) -> Sample {
+        let elapsed = (secs - self.last_secs).max(0.0);
+        self.last_secs = secs;
+
+        // Her çekirdek kendi fazında salınır. CPU0 her dakikanın ilk 20 saniyesinde tek başına
+        // dolu kalır (tek çekirdek uyarısı); 3 dakikalık döngünün sonunda hepsi birden yüklenir
+        let burst = wave(secs, 180.0, 0.0) > 0.8;
+        let cpu_usage: Vec<f32> = (0..DEMO_CORES)
+            .map(|core| {
+                let usage = if core == 0 && secs % 60.0 < 20.0 {
+                    99.0
+                } else if burst {
+                    85.0 + 15.0 * wave(secs, 7.0, core as f64 / DEMO_CORES as f64)
+                } else {
+                    5.0 + 40.0 * wave(secs, 30.0 + core as f64 * 5.0, core as f64 / DEMO_CORES as f64)
+                };
+                usage as f32
+            })
+            .collect();
+
+        // Bellek %30 ile %98 arasında - üst uçta bellek uyarısı tetiklenir
+        let memory_used = (DEMO_MEMORY as f64 * (0.30 + 0.68 * wave(secs, 90.0, 0.0))) as u64;
+        // Bellek dolmak üzereyken swap trafiği patlar (~8 MB/s) - swap uyarısı da görünsün
+        let thrashing = wave(secs, 90.0, 0.0) > 0.9;
+        self.swap.0 += if thrashing { 2000.0 } else { 2.0 } * elapsed;
+        self.swap.1 += if thrashing { 1500.0 } else { 5.0 } * elapsed;
+
+        // Ağ: boşta ~1 KB/s, patlamada ~100 MB/s - log eksenin farkı görülsün diye üstel salınım
+        let download = 10f64.powf(3.0 + 5.0 * wave(secs, 40.0, 0.0));
+        let upload = 10f64.powf(2.5 + 4.0 * wave(secs, 55.0, 0.3));
+        self.network.0 += download * elapsed;
+        self.network.1 += upload * elapsed;
+        self.disk_io.0 += 50e6 * wave(secs, 25.0, 0.5) * elapsed;
+        self.disk_io.1 += 20e6 * wave(secs, 35.0, 0.1) * elapsed;
+
+        let now = Local::now();
+        let boot_time = (now - ChronoDuration::days(3)).timestamp() as u64;
+        // Sistem geneli disk I/O'su process'ler arasında ortalama CPU paylarına göre bölünür
+        let cpu_total: f64 = DEMO_PROCESSES.iter().map(|&(_, cpu, _)| cpu).sum();
+        let processes = DEMO_PROCESSES
+            .iter()
+            .enumerate()
+            .map(|(index, &(name, cpu, memory))| ProcessSample {
+                pid: 1000 + index as u32 * 111,
+                parent_pid: Some(1),
+                name: name.to_string(),
+                status: if cpu >= 5.0 { "Runnable" } else { "Sleeping" }.to_string(),
+                cpu_usage: (cpu * (0.5 + wave(secs, 10.0 + index as f64 * 3.0, 0.0))) as f32,
+                memory: (memory * GIB as f64) as u64,
+                virtual_memory: (memory * 4.0 * GIB as f64) as u64,
+                start_time: boot_time + index as u64 * 600,
+                exe: format!("/usr/bin/{name}"),
+                cmd: vec![name.to_string()],
+                disk_read: (self.disk_io.0 * cpu / cpu_total) as u64,
+                disk_written: (self.disk_io.1 * cpu / cpu_total) as u64,
+            })
+            .collect();
+
+        // İkinci disk yavaşça dolup boşalır - uyarı ve kritik renkleri sırayla görünür
+        let data_usage = 60.0 + 39.0 * wave(secs, 120.0, 0.0);
+        let disks = [("/dev/nvme0n1p2", "/", 42.0), ("/dev/sda1", "/data", data_usage)]
+            .iter()
+            .map(|&(name, mount_point, usage)| {
+                let used_space = (DEMO_DISK as f64 * usage / 100.0) as u64;
+                DiskInfo {
+                    name: name.to_string(),
+                    mount_point: mount_point.to_string(),
+                    total_space: DEMO_DISK,
+                    available_space: DEMO_DISK - used_space,
+                    used_space,
+                    usage_percent: usage as f32,
+                    file_system: "ext4".to_string(),
+                }
+            })
+            .collect();
+
+        // CPU sıcaklığı yükle birlikte artar ve zirvede kritik sınırı geçer
+        let cpu_temp = 35.0 + 60.0 * wave(secs, 75.0, 0.0);
+        let temperatures = vec![
+            TemperatureInfo {
+                component_name: "CPU Package".to_string(),
+                current_temp: cpu_temp as f32,
+                max_temp: Some(100.0),
+                critical_temp: Some(90.0),
+            },
+            TemperatureInfo {
+                component_name: "NVMe".to_string(),
+                current_temp: (40.0 + 15.0 * wave(secs, 100.0, 0.2)) as f32,
+                max_temp: None,
+                critical_temp: Some(80.0),
+            },
+        ];
+        let swap_used = (2.0 * GIB as f64 * wave(secs, 150.0, 0.0)) as u64;
+
+        Sample {
+            timestamp: now,
+            uptime: 3 * 24 * 3600 + secs as u64,
+            host: HostInfo {
+                host_name: Some("demo".to_string()),
+                os_name: Some("Demo Linux".to_string()),
+                os_version: Some("1.0".to_string()),
+                kernel_version: Some("6.0.0-demo".to_string()),
+                boot_time,
+                physical_core_count: Some(DEMO_CORES / 2),
+                cpu_brand: "Demo CPU".to_string(),
+                ..Default::default()
+            },
+            cpu_usage,
+            cpu_frequencies: (0..DEMO_CORES).map(|core| 2000 + 400 * (core as u64 % 4)).collect(),
+            cpu_ids: (0..DEMO_CORES).collect(),
+            cpu_breakdown: None,
+            memory_used,
+            memory_total: DEMO_MEMORY,
+            memory_available: DEMO_MEMORY - memory_used,
+            swap_used,
+            swap_total: 4 * GIB,
+            network_received: self.network.0 as u64,
+            network_transmitted: self.network.1 as u64,
+            interfaces: vec![InterfaceCounters {
+                name: "eth0".to_string(),
+                received: self.network.0 as u64,
+                transmitted: self.network.1 as u64,
+            }],
+            disk_read: self.disk_io.0 as u64,
+            disk_written: self.disk_io.1 as u64,
+            processes,
+            disks,
+            temperatures,
+            fans: vec![FanInfo {
+                label: "CPU Fan".to_string(),
+                rpm: (800.0 + 1600.0 * (cpu_temp - 35.0) / 60.0) as u32,
+            }],
+            batteries: Vec::new(),
+            gpus: Vec::new(),
+            connections: Some(ConnectionCounts {
+                established: 20 + (30.0 * wave(secs, 45.0, 0.0)) as u32,
+                listen: 6,
+                time_wait: (80.0 * wave(secs, 20.0, 0.4)) as u32,
+                close_wait: (10.0 * wave(secs, 140.0, 0.0)) as u32,
+                other: 1,
+            }),
+            // Bağlantıların çoğu tarayıcıda ve veritabanında (PID'ler DEMO_PROCESSES sırasından)
+            process_connections: Some(vec![
+                ProcessConnections { pid: 1222, connections: 12 + (20.0 * wave(secs, 45.0, 0.0)) as u32 },
+                ProcessConnections { pid: 1333, connections: 8 },
+                ProcessConnections { pid: 1444, connections: 3 },
+                ProcessConnections { pid: 1555, connections: 1 },
+            ]),
+            swap_counters: Some(SwapCounters {
+                pages_in: self.swap.0 as u64,
+                pages_out: self.swap.1 as u64,
+            }),
+            // Swap zram üzerinde, yaklaşık 3:1 sıkışıyor
+            zram: Some(ZramStats {
+                original: swap_used,
+                compressed: swap_used / 3,
+                memory_used: swap_used / 3 + swap_used / 30,
+            }),
+        }
+    }
+}
+
+// 0 ile 1 arasında salınan dalga - period saniye, phase periyodun kesri
+fn wave(secs: f64, period: f64, phase: f64) -> f64 {
+    0.5 - 0.5 * (TAU * (secs / period + phase)).cos()
+}
+
+impl MetricsSource for DemoSource {
+    fn next_sample(&mut self) -> Result<Option<Sample>> {
+        let secs = self.started.elapsed().as_secs_f64();
+        Ok(Some(self.sample_at(secs)))
+    }
+
+    fn describe(&self) -> Option<String> {
+        Some("Demo".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_demo_samples_stay_in_range() {
+        let mut source = DemoSource::new();
+        let mut previous = source.sample_at(0.0);
+        let mut pegged = false;
+        for tick in 1..=400 {
+            let sample = source.sample_at(tick as f64 * 0.5);
+            assert!(sample.cpu_usage.iter().all(|usage| (0.0..=100.0).contains(usage)));
+            assert!(sample.memory_used <= sample.memory_total);
+            assert!(sample.network_received >= previous.network_received);
+            assert!(sample.disks.iter().all(|disk| disk.usage_percent < 100.0));
+            pegged |= sample.cpu_usage[0] > 95.0 && sample.cpu_usage[1..].iter().all(|usage| *usage < 50.0);
+            previous = sample;
+        }
+        // Tek çekirdek uyarısının koşulu en az bir kez oluşmalı
+        assert!(pegged);
+    }
+}
@@ -0,0 +1,140 @@
+// diff.rs - Referans snapshot ile şimdiki durum arasındaki fark
+// Bir iş yükünden önce referans alınır ('B'), sonra ne değiştiği tek ekranda görülür:
+// hangi process'ler başladı veya kapandı, bellek ne kadar arttı, o arada ne kadar veri
+// aktarıldı. PID'ler yeniden kullanılabildiği için process kimliği PID ve ad birlikte
+
+use std::collections::HashMap;
+
+use crate::snapshot::{ProcessSnapshot, Snapshot};
+
+// Her iki snapshot'ta da olan bir process'in bellek değişimi
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcessChange {
+    pub pid: u32,
+    pub name: String,
+    pub memory_before: u64,
+    pub memory_after: u64,
+}
+
+impl ProcessChange {
+    pub fn memory_delta(&self) -> i64 {
+        self.memory_after as i64 - self.memory_before as i64
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnapshotDiff {
+    pub elapsed_secs: i64,
+    pub cpu_average: (f32, f32), // Önce, şimdi
+    pub memory_used: (u64, u64),
+    pub swap_used: (u64, u64),
+    // Referanstan beri aktarılan veri - sayaç sıfırlandıysa (arayüz kapandı) 0
+    pub network_received: u64,
+    pub network_transmitted: u64,
+    pub appeared: Vec<ProcessSnapshot>, // Belleğe göre büyükten küçüğe
+    pub disappeared: Vec<ProcessSnapshot>,
+    pub changed: Vec<ProcessChange>, // Bellek değişiminin büyüklüğüne göre, değişmeyenler yok
+}
+
+pub fn diff_snapshots(baseline: &Snapshot, current: &Snapshot) -> SnapshotDiff {
+    let key = |process: &ProcessSnapshot| (process.pid, process.name.clone());
+    let before: HashMap<_, &ProcessSnapshot> = baseline.processes.iter().map(|process| (key(process), process)).collect();
+    let after: HashMap<_, &ProcessSnapshot> = current.processes.iter().map(|process| (key(process), process)).collect();
+
+    let mut appeared: Vec<ProcessSnapshot> = current
+        .processes
+        .iter()
+        .filter(|process| !before.contains_key(&key(process)))
+        .cloned()
+        .collect();
+    let mut disappeared: Vec<ProcessSnapshot> = baseline
+        .processes
+        .iter()
+        .filter(|process| !after.contains_key(&key(process)))
+        .cloned()
+        .collect();
+    let mut changed: Vec<ProcessChange> = current
+        .processes
+        .iter()
+        .filter_map(|process| {
+            let old = before.get(&key(process))?;
+            (old.memory != process.memory).then(|| ProcessChange {
+                pid: process.pid,
+                name: process.name.clone(),
+                memory_before: old.memory,
+                memory_after: process.memory,
+            })
+        })
+        .collect();
+
+    // Eşitlikte PID'e göre - liste her yenilemede titremesin
+    appeared.sort_by(|a, b| b.memory.cmp(&a.memory).then(a.pid.cmp(&b.pid)));
+    disappeared.sort_by(|a, b| b.memory.cmp(&a.memory).then(a.pid.cmp(&b.pid)));
+    changed.sort_by(|a, b| {
+        b.memory_delta()
+            .unsigned_abs()
+            .cmp(&a.memory_delta().unsigned_abs())
+            .then(a.pid.cmp(&b.pid))
+    });
+
+    SnapshotDiff {
+        elapsed_secs: (current.timestamp - baseline.timestamp).num_seconds(),
+        cpu_average: (baseline.cpu_average, current.cpu_average),
+        memory_used: (baseline.memory_used, current.memory_used),
+        swap_used: (baseline.swap_used, current.swap_used),
+        network_received: current.network_received.saturating_sub(baseline.network_received),
+        network_transmitted: current.network_transmitted.saturating_sub(baseline.network_transmitted),
+        appeared,
+        disappeared,
+        changed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::DateTime;
+
+    fn snapshot(seconds: i64, memory_used: u64, network_received: u64, processes: &[(u32, &str, u64)]) -> Snapshot {
+        Snapshot {
+            timestamp: DateTime::from_timestamp(seconds, 0).unwrap().into(),
+            cpu_usage: Vec::new(),
+            cpu_ids: Vec::new(),
+            cpu_average: 0.0,
+            memory_used,
+            memory_total: 0,
+            swap_used: 0,
+            swap_total: 0,
+            network_rx_rate: 0,
+            network_tx_rate: 0,
+            network_received,
+            network_transmitted: 0,
+            disk_read_rate: 0,
+            disk_write_rate: 0,
+            disks: Vec::new(),
+            temperatures: Vec::new(),
+            processes: processes
+                .iter()
+                .map(|&(pid, name, memory)| ProcessSnapshot { pid, name: name.to_string(), cpu_usage: 0.0, memory })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_diff_against_baseline() {
+        // 20 kapandı, 30 başladı, 10 büyüdü, 40 aynı kaldı; 50'nin PID'i başka bir process'e geçti
+        let baseline = snapshot(0, 1000, 500, &[(10, "db", 100), (20, "job", 50), (40, "sshd", 5), (50, "old", 7)]);
+        let current = snapshot(90, 1600, 200, &[(10, "db", 400), (30, "build", 900), (40, "sshd", 5), (50, "new", 7)]);
+        let diff = diff_snapshots(&baseline, &current);
+
+        assert_eq!(diff.elapsed_secs, 90);
+        assert_eq!(diff.memory_used, (1000, 1600));
+        // Sayaç geriye gitti (arayüz yeniden açıldı) - negatif yerine 0
+        assert_eq!(diff.network_received, 0);
+        let pids = |processes: &[ProcessSnapshot]| processes.iter().map(|process| process.pid).collect::<Vec<_>>();
+        assert_eq!(pids(&diff.appeared), vec![30, 50]);
+        assert_eq!(pids(&diff.disappeared), vec![20, 50]);
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].memory_delta(), 300);
+    }
+}
@@ -0,0 +1,143 @@
+// export.rs - Ekrandaki verinin dosyaya dökülmesi
+// Process listesi bir olaydan önce ve sonra alınıp karşılaştırılabilsin diye, grafik geçmişi
+// gnuplot/Excel gibi araçlarda çizilebilsin diye zaman damgalı CSV dosyalarına yazılır.
+// Dosyalar çalışma dizinine kaydedilir
+
+use anyhow::Result;
+use chrono::{DateTime, Local};
+use std::collections::VecDeque;
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::app::process_run_time;
+use crate::source::ProcessSample;
+
+// Tüm process'ler CSV olarak - ilk listedeki sınır ve gizleme filtresi burada uygulanmaz
+// PID'e göre sıralı ki iki döküm satır satır karşılaştırılabilsin
+pub fn processes_csv(processes: &[ProcessSample], now: u64) -> String {
+    let mut sorted: Vec<&ProcessSample> = processes.iter().collect();
+    sorted.sort_by_key(|process| process.pid);
+
+    let mut out = String::from("pid,name,cpu_percent,memory_bytes,path,run_time_secs\n");
+    for process in sorted {
+        let _ = writeln!(
+            out,
+            "{},{},{:.1},{},{},{}",
+            process.pid,
+            csv_field(&process.name),
+            process.cpu_usage,
+            process.memory,
+            csv_field(&process.exe),
+            process_run_time(process.start_time, now),
+        );
+    }
+    out
+}
+
+// Grafik geçmişi CSV olarak - her seri bir kolon, ilk kolon son örneğe göre saniye (-59.0 ... 0.0)
+// Ekrandaki CPU grafiği sadece ortalamayı çizer; çekirdek başına değerler burada ayrı kolonlardır.
+// Geçmişler farklı uzunlukta olabilir (ağ hızı ikinci örnekten başlar) - en yeni örnekten geriye
+// hizalanır, eksik hücreler boş kalır
+pub fn history_csv(
+    cpu: &VecDeque<Vec<f32>>,
+    core_ids: &[usize],
+    memory: &VecDeque<(u64, u64)>,
+    network: &VecDeque<(u64, u64)>,
+    refresh_ms: u64,
+) -> String {
+    let mut out = String::from("seconds");
+    for id in core_ids {
+        let _ = write!(out, ",cpu{}_percent", id);
+    }
+    out.push_str(",cpu_average_percent,memory_used_bytes,memory_total_bytes,download_bytes_per_sec,upload_bytes_per_sec\n");
+
+    // Sondan sayılan indekse göre eleman - o kadar eski örnek yoksa None
+    fn from_end<T>(history: &VecDeque<T>, age: usize) -> Option<&T> {
+        history.len().checked_sub(age + 1).and_then(|index| history.get(index))
+    }
+
+    let rows = cpu.len().max(memory.len()).max(network.len());
+    for age in (0..rows).rev() {
+        let seconds = (age as u64 * refresh_ms) as f64 / 1000.0;
+        let _ = write!(out, "{}{:.1}", if age > 0 { "-" } else { "" }, seconds);
+        let cores = from_end(cpu, age);
+        for index in 0..core_ids.len() {
+            out.push(',');
+            if let Some(usage) = cores.and_then(|cores| cores.get(index)) {
+                let _ = write!(out, "{:.1}", usage);
+            }
+        }
+        out.push(',');
+        if let Some(cores) = cores.filter(|cores| !cores.is_empty()) {
+            let _ = write!(out, "{:.1}", cores.iter().sum::<f32>() / cores.len() as f32);
+        }
+        let _ = match from_end(memory, age) {
+            Some((used, total)) => write!(out, ",{},{}", used, total),
+            None => write!(out, ",,"),
+        };
+        let _ = match from_end(network, age) {
+            Some((download, upload)) => writeln!(out, ",{},{}", download, upload),
+            None => writeln!(out, ",,"),
+        };
+    }
+    out
+}
+
+// Virgül, tırnak veya satır sonu içeren alanlar tırnak içine alınır, içteki tırnaklar ikilenir
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// Dosyayı "<önek>-YYYYMMDD-HHMMSS.<uzantı>" adıyla yaz ve yolunu döndür
+pub fn write_timestamped(prefix: &str, extension: &str, time: DateTime<Local>, contents: &str) -> Result<PathBuf> {
+    let path = PathBuf::from(format!("{}-{}.{}", prefix, time.format("%Y%m%d-%H%M%S"), extension));
+    fs::write(&path, contents)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_processes_csv() {
+        let process = |pid: u32, name: &str, exe: &str| ProcessSample {
+            pid,
+            name: name.to_string(),
+            cpu_usage: 12.34,
+            memory: 2048,
+            start_time: 100,
+            exe: exe.to_string(),
+            ..Default::default()
+        };
+        let processes = vec![process(20, "my \"app\"", "/opt/a,b/app"), process(3, "init", "/sbin/init")];
+        let csv = processes_csv(&processes, 160);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "pid,name,cpu_percent,memory_bytes,path,run_time_secs");
+        assert_eq!(lines[1], "3,init,12.3,2048,/sbin/init,60");
+        assert_eq!(lines[2], "20,\"my \"\"app\"\"\",12.3,2048,\"/opt/a,b/app\",60");
+    }
+
+    #[test]
+    fn test_history_csv_aligns_series_from_newest() {
+        let cpu: VecDeque<Vec<f32>> = vec![vec![10.0, 20.0], vec![30.0, 50.0], vec![0.0, 100.0]].into();
+        let memory: VecDeque<(u64, u64)> = vec![(1, 8), (2, 8), (3, 8)].into();
+        let network: VecDeque<(u64, u64)> = vec![(100, 10), (200, 20)].into();
+        let csv = history_csv(&cpu, &[0, 4], &memory, &network, 500);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "seconds,cpu0_percent,cpu4_percent,cpu_average_percent,memory_used_bytes,memory_total_bytes,download_bytes_per_sec,upload_bytes_per_sec"
+        );
+        // En eski satırda ağ hızı henüz yok
+        assert_eq!(lines[1], "-1.0,10.0,20.0,15.0,1,8,,");
+        assert_eq!(lines[2], "-0.5,30.0,50.0,40.0,2,8,100,10");
+        assert_eq!(lines[3], "0.0,0.0,100.0,50.0,3,8,200,20");
+        assert_eq!(lines.len(), 4);
+    }
+}
@@ -0,0 +1,100 @@
+// fans.rs - Fan hızları (RPM)
+// sysinfo'nun Component tipi fanları kapsamaz. Linux'ta hwmon sürücüleri her fan için
+// /sys/class/hwmon/hwmonN/fanK_input dosyasında RPM değeri sunar; varsa fanK_label insan
+// okunabilir adı verir. Gömülü denetleyicili laptoplarda çoğu zaman hiç fan dosyası yoktur,
+// o zaman liste boş kalır ve fan satırları gösterilmez
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+// Tek bir fan sensörü
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FanInfo {
+    pub label: String,
+    pub rpm: u32,
+}
+
+// hwmon kökündeki tüm çiplerin fan girişlerini oku - çip ve fan numarasına göre sıralı
+// Okunamayan dosyalar sessizce atlanır (sürücü uyku durumunda hata dönebilir)
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub fn read_fans_from(root: &Path) -> Vec<FanInfo> {
+    let Ok(chips) = fs::read_dir(root) else {
+        return Vec::new();
+    };
+    let mut chips: Vec<_> = chips.flatten().map(|entry| entry.path()).collect();
+    chips.sort();
+
+    let mut fans = Vec::new();
+    for chip in chips {
+        let chip_name = read_trimmed(&chip.join("name")).unwrap_or_else(|| "hwmon".to_string());
+        let Ok(entries) = fs::read_dir(&chip) else {
+            continue;
+        };
+        let mut indices: Vec<u32> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.file_name();
+                name.to_str()?.strip_prefix("fan")?.strip_suffix("_input")?.parse().ok()
+            })
+            .collect();
+        indices.sort_unstable();
+
+        for index in indices {
+            let Some(rpm) = read_trimmed(&chip.join(format!("fan{index}_input"))).and_then(|text| text.parse().ok())
+            else {
+                continue;
+            };
+            let label = read_trimmed(&chip.join(format!("fan{index}_label")))
+                .filter(|label| !label.is_empty())
+                .unwrap_or_else(|| format!("{chip_name} fan{index}"));
+            fans.push(FanInfo { label, rpm });
+        }
+    }
+    fans
+}
+
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn read_trimmed(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok().map(|text| text.trim().to_string())
+}
+
+#[cfg(target_os = "linux")]
+pub fn read_fans() -> Vec<FanInfo> {
+    read_fans_from(Path::new("/sys/class/hwmon"))
+}
+
+// hwmon olmayan platformlarda fan satırları gösterilmez
+#[cfg(not(target_os = "linux"))]
+pub fn read_fans() -> Vec<FanInfo> {
+    Vec::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_fans_from_hwmon_tree() {
+        let root = std::env::temp_dir().join(format!("sysmon-fans-{}", std::process::id()));
+        let chip = root.join("hwmon0");
+        fs::create_dir_all(&chip).unwrap();
+        fs::write(chip.join("name"), "nct6775\n").unwrap();
+        fs::write(chip.join("fan1_input"), "1200\n").unwrap();
+        fs::write(chip.join("fan1_label"), "CPU Fan\n").unwrap();
+        fs::write(chip.join("fan2_input"), "0\n").unwrap();
+        fs::write(chip.join("temp1_input"), "45000\n").unwrap();
+        fs::create_dir_all(root.join("hwmon1")).unwrap();
+
+        let fans = read_fans_from(&root);
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(
+            fans,
+            vec![
+                FanInfo { label: "CPU Fan".to_string(), rpm: 1200 },
+                FanInfo { label: "nct6775 fan2".to_string(), rpm: 0 },
+            ]
+        );
+        assert!(read_fans_from(Path::new("/nonexistent/hwmon")).is_empty());
+    }
+}
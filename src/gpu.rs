@@ -0,0 +1,93 @@
+// gpu.rs - NVIDIA GPU bilgilerini toplayan modül
+// NVML (NVIDIA Management Library) üzerinden kullanım, VRAM ve sıcaklık okunur.
+// Crate sadece `nvidia` özelliği açıkken derlenir. NVML sürücüyle birlikte gelen bir
+// kütüphane olduğu için sürücü veya GPU yoksa başlatma başarısız olur - bu durumda
+// hata vermek yerine boş liste döneriz ve GPU paneli hiç çizilmez
+
+use serde::{Deserialize, Serialize};
+
+// Tek bir GPU'nun anlık durumu
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GpuInfo {
+    pub name: String,
+    pub utilization: u32,          // GPU kullanımı (%)
+    pub memory_used: u64,          // VRAM (byte)
+    pub memory_total: u64,
+    pub temperature: Option<u32>,  // Celsius - bazı kartlar bildirmez
+}
+
+impl GpuInfo {
+    // VRAM kullanım yüzdesi
+    pub fn memory_percent(&self) -> f32 {
+        if self.memory_total > 0 {
+            (self.memory_used as f64 / self.memory_total as f64 * 100.0) as f32
+        } else {
+            0.0
+        }
+    }
+}
+
+// GPU bilgilerini toplayan struct - SystemInfoCollector ile aynı yapıda
+pub struct GpuCollector {
+    #[cfg(feature = "nvidia")]
+    nvml: Option<nvml_wrapper::Nvml>,
+}
+
+impl GpuCollector {
+    // Yeni bir collector oluştur - NVML başlatılamazsa collector boş çalışır
+    pub fn new() -> Self {
+        Self {
+            #[cfg(feature = "nvidia")]
+            nvml: nvml_wrapper::Nvml::init().ok(),
+        }
+    }
+
+    // Tüm GPU'ların bilgilerini topla
+    // Tek bir değer okunamazsa (desteklenmeyen kart) o GPU yine listelenir, eksik alan 0/None olur
+    #[cfg(feature = "nvidia")]
+    pub fn get_gpu_info(&self) -> Vec<GpuInfo> {
+        use nvml_wrapper::enum_wrappers::device::TemperatureSensor;
+
+        let Some(nvml) = &self.nvml else {
+            return Vec::new();
+        };
+        let count = nvml.device_count().unwrap_or(0);
+        (0..count)
+            .filter_map(|index| nvml.device_by_index(index).ok())
+            .map(|device| {
+                let memory = device.memory_info().ok();
+                GpuInfo {
+                    name: device.name().unwrap_or_else(|_| "NVIDIA GPU".to_string()),
+                    utilization: device.utilization_rates().map(|rates| rates.gpu).unwrap_or(0),
+                    memory_used: memory.as_ref().map(|m| m.used).unwrap_or(0),
+                    memory_total: memory.as_ref().map(|m| m.total).unwrap_or(0),
+                    temperature: device.temperature(TemperatureSensor::Gpu).ok(),
+                }
+            })
+            .collect()
+    }
+
+    // Özellik kapalıyken GPU desteği yok
+    #[cfg(not(feature = "nvidia"))]
+    pub fn get_gpu_info(&self) -> Vec<GpuInfo> {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_percent() {
+        let gpu = GpuInfo {
+            name: String::new(),
+            utilization: 0,
+            memory_used: 2048,
+            memory_total: 8192,
+            temperature: None,
+        };
+        assert_eq!(gpu.memory_percent(), 25.0);
+        assert_eq!(GpuInfo { memory_total: 0, ..gpu }.memory_percent(), 0.0);
+    }
+}
@@ -0,0 +1,168 @@
+// health.rs - Tek bakışta sistem sağlığı (Good / Warning / Critical)
+// Ortak ekranlarda herkes tek tek gauge okumak istemez; mevcut renk kategorileri tek bir
+// duruma indirgenir. Kurallar:
+//   - CPU ve RAM: kırmızı seviye (yüksek kullanım) Warning, süreli uyarı tetiklendiyse Critical
+//   - Swap: kırmızı doluluk Warning (dolu ama sessiz swap zararsız olabilir), sürekli swap-in Critical
+//   - Disk: uyarı/kritik doluluk Warning, dolu disk Critical - sadece fiziksel diskler
+//   - Sıcaklık: sıcak sensör Warning, kritik sensör Critical
+// Genel durum en kötü tek faktördür. Faktörler listelenir ki sarının nedeni görülebilsin
+
+use crate::alerts::{AlertKind, AlertMonitor};
+use crate::system_info::{
+    categorize_disk_usage, categorize_temperature, categorize_usage, ColorThresholds, DiskInfo, DiskUsageCategory,
+    TemperatureCategory, TemperatureInfo, UsageLevel,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HealthLevel {
+    Good,
+    Warning,
+    Critical,
+}
+
+impl HealthLevel {
+    pub fn label(&self) -> &'static str {
+        match self {
+            HealthLevel::Good => "Good",
+            HealthLevel::Warning => "Warning",
+            HealthLevel::Critical => "Critical",
+        }
+    }
+}
+
+// Durumu Good'dan kötüleştiren tek bir neden
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthFactor {
+    pub level: HealthLevel,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Health {
+    pub level: HealthLevel,
+    pub factors: Vec<HealthFactor>, // En kötüden iyiye doğru
+}
+
+// Değerlendirmede kullanılan anlık değerler
+pub struct HealthInputs<'a> {
+    pub cpu_average: f32,
+    pub memory_percent: f32,
+    pub swap_percent: f32,
+    pub disks: &'a [DiskInfo], // Sadece fiziksel diskler
+    pub temperatures: &'a [TemperatureInfo],
+}
+
+pub fn assess(inputs: &HealthInputs, alerts: &AlertMonitor, thresholds: &ColorThresholds) -> Health {
+    let mut factors = Vec::new();
+    let mut add = |level: HealthLevel, reason: String| factors.push(HealthFactor { level, reason });
+
+    let usage_high = |percent: f32| categorize_usage(percent, thresholds) == UsageLevel::High;
+    if alerts.is_active(AlertKind::HighCpu) {
+        add(HealthLevel::Critical, format!("CPU {:.0}% sustained", inputs.cpu_average));
+    } else if usage_high(inputs.cpu_average) {
+        add(HealthLevel::Warning, format!("CPU {:.0}%", inputs.cpu_average));
+    }
+    if alerts.is_active(AlertKind::HighMemory) {
+        add(HealthLevel::Critical, format!("memory {:.0}% sustained", inputs.memory_percent));
+    } else if usage_high(inputs.memory_percent) {
+        add(HealthLevel::Warning, format!("memory {:.0}%", inputs.memory_percent));
+    }
+    if alerts.is_active(AlertKind::SwapThrashing) {
+        add(HealthLevel::Critical, "swapping heavily".to_string());
+    } else if usage_high(inputs.swap_percent) {
+        add(HealthLevel::Warning, format!("swap {:.0}% full", inputs.swap_percent));
+    }
+
+    for disk in inputs.disks {
+        let level = match categorize_disk_usage(disk.usage_percent, thresholds) {
+            DiskUsageCategory::Normal => continue,
+            DiskUsageCategory::Warning | DiskUsageCategory::Critical => HealthLevel::Warning,
+            DiskUsageCategory::Full => HealthLevel::Critical,
+        };
+        add(level, format!("{} {:.0}% full", disk.mount_point, disk.usage_percent));
+    }
+
+    for temp in inputs.temperatures {
+        let level = match categorize_temperature(temp.current_temp, thresholds) {
+            TemperatureCategory::Hot => HealthLevel::Warning,
+            TemperatureCategory::Critical => HealthLevel::Critical,
+            _ => continue,
+        };
+        add(level, format!("{} {:.0}°C", temp.component_name, temp.current_temp));
+    }
+
+    // Aynı seviyedekiler eklenme sırasında kalır (CPU, RAM, swap, disk, sıcaklık)
+    factors.sort_by_key(|factor| std::cmp::Reverse(factor.level));
+    Health {
+        level: factors.first().map_or(HealthLevel::Good, |factor| factor.level),
+        factors,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alerts::AlertThresholds;
+    use std::time::{Duration, Instant};
+
+    fn disk(mount_point: &str, usage_percent: f32) -> DiskInfo {
+        DiskInfo {
+            name: "sda1".to_string(),
+            mount_point: mount_point.to_string(),
+            total_space: 100,
+            available_space: 0,
+            used_space: 0,
+            usage_percent,
+            file_system: "ext4".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_worst_factor_drives_health() {
+        let alerts = AlertMonitor::new(AlertThresholds::default());
+        let thresholds = ColorThresholds::default();
+        let mut inputs = HealthInputs {
+            cpu_average: 20.0,
+            memory_percent: 40.0,
+            swap_percent: 0.0,
+            disks: &[],
+            temperatures: &[],
+        };
+        let health = assess(&inputs, &alerts, &thresholds);
+        assert_eq!(health.level, HealthLevel::Good);
+        assert!(health.factors.is_empty());
+
+        let disks = [disk("/", 50.0), disk("/data", 90.0)];
+        inputs.memory_percent = 85.0;
+        inputs.disks = &disks;
+        let health = assess(&inputs, &alerts, &thresholds);
+        assert_eq!(health.level, HealthLevel::Warning);
+        let reasons: Vec<&str> = health.factors.iter().map(|factor| factor.reason.as_str()).collect();
+        assert_eq!(reasons, vec!["memory 85%", "/data 90% full"]);
+
+        // Dolu disk en kötü faktör olarak başa geçer
+        let disks = [disk("/", 97.0)];
+        inputs.disks = &disks;
+        let health = assess(&inputs, &alerts, &thresholds);
+        assert_eq!(health.level, HealthLevel::Critical);
+        assert_eq!(health.factors[0].reason, "/ 97% full");
+    }
+
+    #[test]
+    fn test_sustained_alert_is_critical() {
+        let mut alerts = AlertMonitor::new(AlertThresholds::default());
+        let start = Instant::now();
+        alerts.evaluate(99.0, 10.0, start);
+        alerts.evaluate(99.0, 10.0, start + Duration::from_secs(10));
+        let inputs = HealthInputs {
+            cpu_average: 99.0,
+            memory_percent: 10.0,
+            swap_percent: 0.0,
+            disks: &[],
+            temperatures: &[],
+        };
+        let health = assess(&inputs, &alerts, &ColorThresholds::default());
+        assert_eq!(health.level, HealthLevel::Critical);
+        assert_eq!(health.factors[0].reason, "CPU 99% sustained");
+    }
+}
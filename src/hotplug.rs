@@ -0,0 +1,61 @@
+// hotplug.rs - Çevrimiçi CPU çekirdekleri
+// Linux'ta çekirdekler çalışırken kapatılıp açılabilir (cpu hotplug). Kapalı bir çekirdek
+// listeden düştüğünde sıra numaraları kayar; gauge'lar "CPU3" yerine yanlış çekirdeği
+// gösterebilir. Her çekirdeğin durumu /sys/devices/system/cpu/cpuN/online dosyasındadır.
+// cpu0 gibi kapatılamayan çekirdeklerde bu dosya yoktur - onlar her zaman çevrimiçidir
+
+use std::fs;
+use std::path::Path;
+
+// Çevrimiçi çekirdeklerin numaraları, artan sırada
+// Dizin okunamazsa (konteyner, eski çekirdek) boş liste - çağıran taraf filtre uygulamaz
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub fn read_online_cpus_from(root: &Path) -> Vec<usize> {
+    let Ok(entries) = fs::read_dir(root) else {
+        return Vec::new();
+    };
+    let mut online: Vec<usize> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let id = entry.file_name().to_str()?.strip_prefix("cpu")?.parse().ok()?;
+            let state = fs::read_to_string(entry.path().join("online")).unwrap_or_else(|_| "1".to_string());
+            (state.trim() != "0").then_some(id)
+        })
+        .collect();
+    online.sort_unstable();
+    online
+}
+
+#[cfg(target_os = "linux")]
+pub fn read_online_cpus() -> Vec<usize> {
+    read_online_cpus_from(Path::new("/sys/devices/system/cpu"))
+}
+
+// Hotplug olmayan platformlarda sysinfo'nun listesi olduğu gibi kullanılır
+#[cfg(not(target_os = "linux"))]
+pub fn read_online_cpus() -> Vec<usize> {
+    Vec::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_online_cpus_from_sysfs_tree() {
+        let root = std::env::temp_dir().join(format!("sysmon-hotplug-{}", std::process::id()));
+        for (cpu, online) in [("cpu0", None), ("cpu1", Some("0\n")), ("cpu2", Some("1\n")), ("cpu10", Some("1\n"))] {
+            fs::create_dir_all(root.join(cpu)).unwrap();
+            if let Some(online) = online {
+                fs::write(root.join(cpu).join("online"), online).unwrap();
+            }
+        }
+        // cpufreq, cpuidle gibi diğer dizinler atlanır
+        fs::create_dir_all(root.join("cpufreq")).unwrap();
+
+        let online = read_online_cpus_from(&root);
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(online, vec![0, 2, 10]);
+        assert!(read_online_cpus_from(Path::new("/nonexistent/cpu")).is_empty());
+    }
+}
@@ -0,0 +1,203 @@
+// influx.rs - InfluxDB line protocol çıktısı
+// --influx-url verilirse her güncellemedeki snapshot line protocol'e çevrilip InfluxDB'nin
+// /write endpoint'ine gönderilir. Gönderim ayrı bir task'te yapılır ve birkaç örnek
+// biriktirilip tek istekte yollanır. Sunucuya ulaşılamazsa arayüz beklemez - o grup atılır
+// ve bir uyarı üretilir
+
+use std::fmt::Write;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+use crate::snapshot::Snapshot;
+
+// Tek istekte gönderilen örnek sayısı - varsayılan 250 ms yenilemede saniyede ~1 istek
+const BATCH_SIZE: usize = 4;
+
+// Gönderilmeyi bekleyen örnek kuyruğu - sunucu yavaşsa dolar ve yeni örnekler atılır
+const QUEUE_SIZE: usize = 64;
+
+// Tek bir isteğin en fazla süresi - takılan bir sunucu kuyruğu sonsuza kadar bekletmesin
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+// Arka plandaki gönderici task'e bağlantı
+pub struct InfluxExporter {
+    samples: mpsc::Sender<Snapshot>,
+    warning_sender: mpsc::UnboundedSender<String>,
+    warnings: mpsc::UnboundedReceiver<String>,
+}
+
+impl InfluxExporter {
+    // Gönderici task'i başlat - url InfluxDB'nin kök adresi (örn. http://localhost:8086)
+    pub fn spawn(url: &str, database: &str, host: String) -> Self {
+        let (samples, receiver) = mpsc::channel(QUEUE_SIZE);
+        let (warning_sender, warnings) = mpsc::unbounded_channel();
+        let endpoint = write_endpoint(url, database);
+        tokio::spawn(send_batches(endpoint, host, receiver, warning_sender.clone()));
+        Self { samples, warning_sender, warnings }
+    }
+
+    // Snapshot'ı gönderim kuyruğuna ekle - hiçbir zaman beklemez
+    pub fn push(&mut self, snapshot: Snapshot) {
+        if self.samples.try_send(snapshot).is_err() {
+            let _ = self.warning_sender.send("InfluxDB queue full, sample dropped".to_string());
+        }
+    }
+
+    // Sıradaki uyarı (dolu kuyruk, başarısız istek vb.)
+    pub fn take_warning(&mut self) -> Option<String> {
+        self.warnings.try_recv().ok()
+    }
+}
+
+// InfluxDB 1.x uyumlu yazma adresi - 2.x de bu adresi destekler
+fn write_endpoint(url: &str, database: &str) -> String {
+    format!("{}/write?db={}&precision=ns", url.trim_end_matches('/'), database)
+}
+
+// Kuyruktan örnekleri topla, BATCH_SIZE'a ulaşınca tek istekte gönder
+// Uygulama kapanınca kanal kapanır ve task biter - yarım kalan grup atılır
+async fn send_batches(
+    endpoint: String,
+    host: String,
+    mut receiver: mpsc::Receiver<Snapshot>,
+    warnings: mpsc::UnboundedSender<String>,
+) {
+    let client = reqwest::Client::new();
+    let mut batch = String::new();
+    let mut count = 0;
+    while let Some(snapshot) = receiver.recv().await {
+        batch.push_str(&render_line_protocol(&snapshot, &host));
+        count += 1;
+        if count < BATCH_SIZE {
+            continue;
+        }
+
+        let result = client
+            .post(&endpoint)
+            .timeout(REQUEST_TIMEOUT)
+            .body(std::mem::take(&mut batch))
+            .send()
+            .await
+            .and_then(|response| response.error_for_status());
+        if let Err(err) = result {
+            let _ = warnings.send(format!("InfluxDB write failed, {} samples dropped: {}", count, err));
+        }
+        count = 0;
+    }
+}
+
+// Snapshot'ı InfluxDB line protocol'e çevir - her ölçüm türü ayrı bir measurement
+// Tam sayı alanlar `i` son ekiyle yazılır, aksi halde InfluxDB onları float sayar
+pub fn render_line_protocol(snapshot: &Snapshot, host: &str) -> String {
+    let mut out = String::new();
+    let host = escape_tag(host);
+    let timestamp = snapshot.timestamp.timestamp_nanos_opt().unwrap_or_default();
+
+    for (index, usage) in snapshot.cpu_usage.iter().enumerate() {
+        let _ = writeln!(out, "cpu,host={},core={} usage={} {}", host, snapshot.cpu_id(index), usage, timestamp);
+    }
+    let _ = writeln!(out, "cpu,host={},core=all usage={} {}", host, snapshot.cpu_average, timestamp);
+    let _ = writeln!(
+        out,
+        "memory,host={} used={}i,total={}i,swap_used={}i,swap_total={}i {}",
+        host, snapshot.memory_used, snapshot.memory_total, snapshot.swap_used, snapshot.swap_total, timestamp
+    );
+    let _ = writeln!(
+        out,
+        "network,host={} rx_rate={}i,tx_rate={}i {}",
+        host, snapshot.network_rx_rate, snapshot.network_tx_rate, timestamp
+    );
+    let _ = writeln!(
+        out,
+        "diskio,host={} read_rate={}i,write_rate={}i {}",
+        host, snapshot.disk_read_rate, snapshot.disk_write_rate, timestamp
+    );
+    for disk in &snapshot.disks {
+        let _ = writeln!(
+            out,
+            "disk,host={},mount={} used={}i,total={}i,usage_percent={} {}",
+            host,
+            escape_tag(&disk.mount_point),
+            disk.used_space,
+            disk.total_space,
+            disk.usage_percent,
+            timestamp
+        );
+    }
+    for temp in &snapshot.temperatures {
+        let _ = writeln!(
+            out,
+            "temperature,host={},component={} celsius={} {}",
+            host,
+            escape_tag(&temp.component_name),
+            temp.current_temp,
+            timestamp
+        );
+    }
+
+    out
+}
+
+// Tag değerlerinde virgül, eşittir ve boşluk ters bölü ile kaçışlanmalı
+fn escape_tag(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace(',', "\\,")
+        .replace('=', "\\=")
+        .replace(' ', "\\ ");
+    // Boş tag değeri geçersiz - isimsiz sensör vb. için yer tutucu
+    if escaped.is_empty() {
+        "unknown".to_string()
+    } else {
+        escaped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system_info::DiskInfo;
+    use chrono::DateTime;
+
+    #[test]
+    fn test_render_line_protocol() {
+        let snapshot = Snapshot {
+            timestamp: DateTime::from_timestamp(1_700_000_000, 0).unwrap().into(),
+            cpu_usage: vec![10.0, 20.5],
+            cpu_ids: vec![0, 1],
+            cpu_average: 15.25,
+            memory_used: 1024,
+            memory_total: 4096,
+            swap_used: 0,
+            swap_total: 0,
+            network_rx_rate: 100,
+            network_tx_rate: 50,
+            network_received: 0,
+            network_transmitted: 0,
+            disk_read_rate: 0,
+            disk_write_rate: 0,
+            disks: vec![DiskInfo {
+                name: String::new(),
+                mount_point: "/mnt/my disk".to_string(),
+                total_space: 100,
+                available_space: 60,
+                used_space: 40,
+                usage_percent: 40.0,
+                file_system: "ext4".to_string(),
+            }],
+            temperatures: Vec::new(),
+            processes: Vec::new(),
+        };
+        let text = render_line_protocol(&snapshot, "box");
+        assert!(text.contains("cpu,host=box,core=1 usage=20.5 1700000000000000000\n"));
+        assert!(text.contains("memory,host=box used=1024i,total=4096i,swap_used=0i,swap_total=0i 1700000000000000000\n"));
+        assert!(text.contains("disk,host=box,mount=/mnt/my\\ disk used=40i,total=100i,usage_percent=40 "));
+    }
+
+    #[test]
+    fn test_escape_tag_and_endpoint() {
+        assert_eq!(escape_tag("a b,c=d"), "a\\ b\\,c\\=d");
+        assert_eq!(escape_tag(""), "unknown");
+        assert_eq!(write_endpoint("http://db:8086/", "sysmon"), "http://db:8086/write?db=sysmon&precision=ns");
+    }
+}
@@ -0,0 +1,687 @@
+// input.rs - Klavye girişini uygulama eylemlerine çeviren modül
+// Tuşlar önce bir Action'a dönüşür, sonra Action uygulanır. Böylece aynı eylem
+// birden fazla tuşa bağlanabilir (örneğin hem ↓ hem 'j' aşağı gider)
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::Rect;
+use std::collections::HashMap;
+
+use crate::app::{App, Panel, Tab};
+
+// Kullanıcının tetikleyebileceği eylemler
+// Config'deki [keybindings] tablosunda ACTION_NAMES'teki adlarla anılırlar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Quit,
+    Back,               // Popup'ı kapat, popup yoksa çık
+    Select,             // Enter - seçimi onayla / detay aç
+    Up,
+    Down,
+    Top,                // Listenin başına atla
+    Bottom,             // Listenin sonuna atla
+    PageUp,             // Bir sayfa yukarı
+    PageDown,           // Bir sayfa aşağı
+    NextTab,
+    PreviousTab,
+    GoToTab(Tab),
+    FocusNext,
+    FocusPrevious,
+    ToggleZoom,
+    ToggleCompact,
+    ToggleCoreHeatmap,  // Çekirdek gauge'ları ↔ tek satırlık ısı haritası
+    CycleIdleCores,     // Boştaki çekirdekler: normal → soluk → tek satırlık özet
+    CycleSort,
+    ToggleProcessFilter,
+    ToggleProcessTree,  // Processes sekmesi: düz liste ↔ ebeveyn/çocuk ağacı
+    ToggleCollapse,     // Ağaçta seçili düğümün çocuklarını gizle/göster
+    ToggleTreeTotals,   // Ağaçta ebeveynler: kendi değerleri ↔ alt ağaç toplamı
+    KillProcess,
+    TogglePin,          // Seçili process'i ilk N dışına düşse de tabloda tut
+    KillByName,         // Ada uyan tüm process'lere sinyal gönder
+    ShowTopMemory,      // En çok bellek kullananlar popup'ı
+    ShowOffenders,      // Son dakikada CPU'da en sık ilk sıralara girenler
+    ShowHealth,         // Sistem sağlığını kötüleştiren nedenler
+    CaptureBaseline,    // Şimdiki durumu karşılaştırma referansı olarak sakla
+    ShowDiff,           // Referanstan beri değişenler
+    ShowEvents,         // Oturum boyunca olanların günlüğü
+    ShowPanels,         // Bölümleri göster/gizle listesi
+    MoreProcesses,
+    FewerProcesses,
+    ExportProcesses,    // Tüm process listesini CSV'ye yaz
+    ExportCharts,       // Grafik geçmişini CSV'ye yaz
+    CopySnapshot,       // Ekranın metin halini panoya kopyala
+    CycleTheme,
+    ToggleNetworkUnits,
+    ToggleNetworkScale, // Ağ grafiği: doğrusal ↔ logaritmik eksen
+    ToggleNetworkCounters, // Ağ paneli: hızlar ↔ arayüz başına toplam sayaçlar
+    CycleInterface,     // Ağ grafiği: tüm arayüzler → tek tek arayüzler
+    ToggleAllDisks,
+    ToggleDiskGauges,   // Disk tablosu ↔ gauge ızgarası
+    ResetHistory,
+    TogglePause,        // Ekranı dondur / devam et
+    FasterRefresh,
+    SlowerRefresh,
+}
+
+impl Action {
+    // Process'lere sinyal gönderebilen eylemler - salt okunur modda hiç uygulanmaz
+    pub fn is_destructive(&self) -> bool {
+        matches!(self, Action::KillProcess | Action::KillByName)
+    }
+}
+
+// Config dosyasında kullanılan eylem adları
+const ACTION_NAMES: &[(&str, Action)] = &[
+    ("quit", Action::Quit),
+    ("back", Action::Back),
+    ("select", Action::Select),
+    ("up", Action::Up),
+    ("down", Action::Down),
+    ("top", Action::Top),
+    ("bottom", Action::Bottom),
+    ("page_up", Action::PageUp),
+    ("page_down", Action::PageDown),
+    ("next_tab", Action::NextTab),
+    ("previous_tab", Action::PreviousTab),
+    ("tab_overview", Action::GoToTab(Tab::Overview)),
+    ("tab_system", Action::GoToTab(Tab::System)),
+    ("tab_processes", Action::GoToTab(Tab::Processes)),
+    ("focus_next", Action::FocusNext),
+    ("focus_previous", Action::FocusPrevious),
+    ("toggle_zoom", Action::ToggleZoom),
+    ("toggle_compact", Action::ToggleCompact),
+    ("toggle_core_heatmap", Action::ToggleCoreHeatmap),
+    ("cycle_idle_cores", Action::CycleIdleCores),
+    ("cycle_sort", Action::CycleSort),
+    ("toggle_process_filter", Action::ToggleProcessFilter),
+    ("toggle_process_tree", Action::ToggleProcessTree),
+    ("toggle_collapse", Action::ToggleCollapse),
+    ("toggle_tree_totals", Action::ToggleTreeTotals),
+    ("kill_process", Action::KillProcess),
+    ("toggle_pin", Action::TogglePin),
+    ("kill_by_name", Action::KillByName),
+    ("export_processes", Action::ExportProcesses),
+    ("export_charts", Action::ExportCharts),
+    ("copy_snapshot", Action::CopySnapshot),
+    ("top_memory", Action::ShowTopMemory),
+    ("top_offenders", Action::ShowOffenders),
+    ("health", Action::ShowHealth),
+    ("capture_baseline", Action::CaptureBaseline),
+    ("diff", Action::ShowDiff),
+    ("events", Action::ShowEvents),
+    ("panels", Action::ShowPanels),
+    ("more_processes", Action::MoreProcesses),
+    ("fewer_processes", Action::FewerProcesses),
+    ("cycle_theme", Action::CycleTheme),
+    ("toggle_network_units", Action::ToggleNetworkUnits),
+    ("toggle_network_scale", Action::ToggleNetworkScale),
+    ("toggle_network_counters", Action::ToggleNetworkCounters),
+    ("cycle_interface", Action::CycleInterface),
+    ("toggle_all_disks", Action::ToggleAllDisks),
+    ("toggle_disk_gauges", Action::ToggleDiskGauges),
+    ("reset_history", Action::ResetHistory),
+    ("toggle_pause", Action::TogglePause),
+    ("faster_refresh", Action::FasterRefresh),
+    ("slower_refresh", Action::SlowerRefresh),
+];
+
+impl Action {
+    // Config'deki addan eylemi bul
+    pub fn from_name(name: &str) -> Option<Self> {
+        ACTION_NAMES
+            .iter()
+            .find(|(action_name, _)| *action_name == name)
+            .map(|(_, action)| *action)
+    }
+}
+
+// Tuşu karşılaştırılabilir hale getir
+// Terminaller büyük harfleri SHIFT ile birlikte gönderir ama karakter zaten büyük harftir,
+// Shift+Tab ise BackTab olarak gelir - bu yüzden SHIFT'i bu durumlarda atıyoruz.
+// Olay türü ve durum bayrakları da eşleşmeyi bozmasın diye sıfırlanır
+pub fn normalize_key(key: KeyEvent) -> KeyEvent {
+    let mut modifiers = key.modifiers;
+    if matches!(key.code, KeyCode::Char(_) | KeyCode::BackTab) {
+        modifiers.remove(KeyModifiers::SHIFT);
+    }
+    KeyEvent::new(key.code, modifiers)
+}
+
+// Tuş → eylem çevirisi
+// Kullanıcı atamaları varsayılanların önüne geçer; atanmamış tuşlar varsayılan davranışı korur.
+// Böylece kullanıcı 'q'yu açıkça başka bir eyleme bağlamadıkça 'q' her zaman çıkış yapar
+// "gg" gibi iki tuşlu diziler için önceki tuşu hatırlamamız gerekiyor
+#[derive(Debug, Default)]
+pub struct InputHandler {
+    bindings: HashMap<KeyEvent, Action>,
+    pending_g: bool,
+}
+
+impl InputHandler {
+    pub fn new(bindings: HashMap<KeyEvent, Action>) -> Self {
+        Self {
+            bindings,
+            pending_g: false,
+        }
+    }
+
+    // Footer'da gösterilecek tuş etiketleri - bu atamalara göre
+    pub fn key_labels(&self) -> KeyLabels {
+        KeyLabels::new(&self.bindings)
+    }
+
+    // Tuşu eyleme çevir - tanımsız tuşlar ve yarım kalan diziler None döner
+    pub fn action_for(&mut self, key: KeyEvent) -> Option<Action> {
+        let key = normalize_key(key);
+        if let Some(action) = self.bindings.get(&key) {
+            self.pending_g = false;
+            return Some(*action);
+        }
+        
+        // 'g' tek başına bir şey yapmaz, ikinci 'g' gelirse listenin başına atlar
+        // Araya başka bir tuş girerse dizi iptal olur ve o tuş normal işlenir
+        if key == KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE) {
+            if self.pending_g {
+                self.pending_g = false;
+                return Some(Action::Top);
+            }
+            self.pending_g = true;
+            return None;
+        }
+        self.pending_g = false;
+        default_action(key)
+    }
+
+    // Tuşu işle - uygulamadan çıkılması gerekiyorsa true döner
+    pub fn handle_key(&mut self, app: &mut App, key: KeyEvent) -> bool {
+        // Çıkış onayı tuş atamalarından bağımsızdır: sadece 'y' çıkar, diğer her tuş vazgeçer
+        if app.quit_prompt {
+            app.quit_prompt = false;
+            return matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y'));
+        }
+        // Toplu kill popup'ında desen yazılır - tuşlar eylemlere çevrilmez
+        if app.bulk_kill_popup.is_some() {
+            handle_bulk_kill_key(app, key);
+            return false;
+        }
+        match self.action_for(key) {
+            Some(action) => apply(app, action),
+            None => false,
+        }
+    }
+
+    // Fare olayını işle - tıklama ve tekerlek son çizimdeki alanlara göre eşlenir
+    // Popup'lar sadece klavyeyle kullanılır; açıkken fare yok sayılır
+    pub fn handle_mouse(&mut self, app: &mut App, mouse: MouseEvent) {
+        self.pending_g = false;
+        if app.popup_open() {
+            return;
+        }
+        let hits = app.hit_areas.get();
+        let (column, row) = (mouse.column, mouse.row);
+        let over = |area: Option<Rect>| area.is_some_and(|area| contains(area, column, row));
+
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                // Kolon başlığına tıklamak o kolona göre sıralar
+                if let Some((_, key)) = hits.process_headers.iter().flatten().find(|(area, _)| contains(*area, column, row)) {
+                    app.focused_panel = Panel::Processes;
+                    app.set_process_sort(*key);
+                } else if let Some(index) = hits.process_rows.and_then(|area| row_at(area, hits.process_offset, column, row)) {
+                    app.focused_panel = Panel::Processes;
+                    app.select_process(index);
+                } else if over(hits.cpu_gauges) {
+                    app.focused_panel = Panel::Cpu;
+                }
+            }
+            MouseEventKind::ScrollUp | MouseEventKind::ScrollDown => {
+                let delta = if mouse.kind == MouseEventKind::ScrollUp { -1 } else { 1 };
+                if over(hits.process_rows) {
+                    app.move_process_selection(delta);
+                } else if over(hits.cpu_gauges) {
+                    app.scroll_cpu(delta);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+// Her eylemin footer'da gösterilecek tuş etiketleri
+// Kullanıcı bir eyleme tuş atadıysa sadece o tuşlar gösterilir; atamadıysa varsayılan tuşlardan
+// başka bir eyleme atanmamış olanlar. Hiç tuşu kalmayan eylemin listesi boştur
+#[derive(Debug, Clone)]
+pub struct KeyLabels(Vec<(Action, Vec<String>)>);
+
+impl KeyLabels {
+    fn new(bindings: &HashMap<KeyEvent, Action>) -> Self {
+        let labels = ACTION_NAMES
+            .iter()
+            .map(|&(_, action)| {
+                let mut user: Vec<String> = bindings
+                    .iter()
+                    .filter(|(_, bound)| **bound == action)
+                    .map(|(key, _)| key_label(*key))
+                    .collect();
+                if !user.is_empty() {
+                    // HashMap sırası her çalıştırmada değişir - footer titremesin
+                    user.sort();
+                    return (action, user);
+                }
+                let mut defaults = Vec::new();
+                let g = KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE);
+                if action == Action::Top && !bindings.contains_key(&g) {
+                    defaults.push("gg".to_string());
+                }
+                defaults.extend(
+                    default_keys()
+                        .filter(|key| !bindings.contains_key(key) && default_action(*key) == Some(action))
+                        .map(key_label),
+                );
+                (action, defaults)
+            })
+            .collect();
+        Self(labels)
+    }
+
+    pub fn get(&self, action: Action) -> &[String] {
+        self.0
+            .iter()
+            .find(|(candidate, _)| *candidate == action)
+            .map(|(_, labels)| labels.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+// Varsayılan atamalar
+impl Default for KeyLabels {
+    fn default() -> Self {
+        Self::new(&HashMap::new())
+    }
+}
+
+// Varsayılan atamaları taranacak tuşlar - etiket sırası da bu: önce oklar ve özel tuşlar, sonra harfler
+fn default_keys() -> impl Iterator<Item = KeyEvent> {
+    let special = [
+        KeyCode::Up,
+        KeyCode::Down,
+        KeyCode::Left,
+        KeyCode::Right,
+        KeyCode::PageUp,
+        KeyCode::PageDown,
+        KeyCode::Enter,
+        KeyCode::Esc,
+        KeyCode::Tab,
+        KeyCode::BackTab,
+    ];
+    special
+        .into_iter()
+        .chain((b' '..=b'~').map(|c| KeyCode::Char(c as char)))
+        .chain([KeyCode::Home, KeyCode::End])
+        .map(|code| KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+// Tuşun ekrandaki adı - değiştiriciler config'deki yazımla aynı ("ctrl+c")
+pub fn key_label(key: KeyEvent) -> String {
+    let name = match key.code {
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Up => "↑".to_string(),
+        KeyCode::Down => "↓".to_string(),
+        KeyCode::Left => "←".to_string(),
+        KeyCode::Right => "→".to_string(),
+        KeyCode::PageUp => "PgUp".to_string(),
+        KeyCode::PageDown => "PgDn".to_string(),
+        KeyCode::Esc => "ESC".to_string(),
+        KeyCode::BackTab => "Shift+Tab".to_string(),
+        KeyCode::Delete => "Del".to_string(),
+        KeyCode::Insert => "Ins".to_string(),
+        KeyCode::F(n) => format!("F{n}"),
+        code => format!("{code:?}"),
+    };
+    let mut label = String::new();
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        label.push_str("ctrl+");
+    }
+    if key.modifiers.contains(KeyModifiers::ALT) {
+        label.push_str("alt+");
+    }
+    label + &name
+}
+
+// Toplu kill popup'ının tuşları - atamalardan bağımsızdır, yazılan harfler desene eklenir
+// Onay aşamasında 'y' gönderir; listede kritik process varsa sadece büyük 'Y' kabul edilir
+fn handle_bulk_kill_key(app: &mut App, key: KeyEvent) {
+    let confirming = app.bulk_kill_popup.as_ref().is_some_and(|popup| popup.confirming);
+    if confirming {
+        match key.code {
+            KeyCode::Char('y') => app.confirm_bulk_kill(false),
+            KeyCode::Char('Y') => app.confirm_bulk_kill(true),
+            // Vazgeçince desene geri dönülür
+            _ => {
+                if let Some(popup) = &mut app.bulk_kill_popup {
+                    popup.confirming = false;
+                }
+            }
+        }
+        return;
+    }
+    match key.code {
+        KeyCode::Esc => app.bulk_kill_popup = None,
+        KeyCode::Enter => app.review_bulk_kill(),
+        KeyCode::Backspace => app.edit_bulk_kill_pattern(None),
+        KeyCode::Char(c) if !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {
+            app.edit_bulk_kill_pattern(Some(c))
+        }
+        _ => {}
+    }
+}
+
+// Nokta alanın içinde mi
+fn contains(area: Rect, column: u16, row: u16) -> bool {
+    column >= area.x && column < area.right() && row >= area.y && row < area.bottom()
+}
+
+// Tablo gövdesindeki bir noktanın listedeki satır sırası - gövde dışındaysa None
+fn row_at(area: Rect, offset: usize, column: u16, row: u16) -> Option<usize> {
+    contains(area, column, row).then(|| offset + (row - area.y) as usize)
+}
+
+// Varsayılan tuş atamaları - oklar ve vim tuşları aynı eylemlere gider
+// Ctrl/Alt ile basılan tuşların varsayılan bir anlamı yok, onlar sadece config ile atanabilir
+fn default_action(key: KeyEvent) -> Option<Action> {
+    if key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) {
+        return None;
+    }
+    let action = match key.code {
+        KeyCode::Char('q') => Action::Quit,
+        KeyCode::Esc => Action::Back,
+        KeyCode::Enter => Action::Select,
+        KeyCode::Up | KeyCode::Char('k') => Action::Up,
+        KeyCode::Down | KeyCode::Char('j') => Action::Down,
+        KeyCode::Home => Action::Top,
+        KeyCode::End | KeyCode::Char('G') => Action::Bottom,
+        KeyCode::PageUp => Action::PageUp,
+        KeyCode::PageDown => Action::PageDown,
+        KeyCode::Right | KeyCode::Char('l') => Action::NextTab,
+        KeyCode::Left | KeyCode::Char('h') => Action::PreviousTab,
+        KeyCode::Char('1') => Action::GoToTab(Tab::Overview),
+        KeyCode::Char('2') => Action::GoToTab(Tab::System),
+        KeyCode::Char('3') => Action::GoToTab(Tab::Processes),
+        KeyCode::Tab => Action::FocusNext,
+        KeyCode::BackTab => Action::FocusPrevious,
+        KeyCode::Char('z') => Action::ToggleZoom,
+        KeyCode::Char('c') => Action::ToggleCompact,
+        KeyCode::Char('m') => Action::ToggleCoreHeatmap,
+        KeyCode::Char('D') => Action::CycleIdleCores,
+        KeyCode::Char('s') => Action::CycleSort,
+        KeyCode::Char('i') => Action::ToggleProcessFilter,
+        KeyCode::Char('T') => Action::ToggleProcessTree,
+        KeyCode::Char(' ') => Action::ToggleCollapse,
+        KeyCode::Char('A') => Action::ToggleTreeTotals,
+        KeyCode::Char('x') => Action::KillProcess,
+        KeyCode::Char('P') => Action::TogglePin,
+        KeyCode::Char('X') => Action::KillByName,
+        KeyCode::Char('M') => Action::ShowTopMemory,
+        KeyCode::Char('O') => Action::ShowOffenders,
+        KeyCode::Char('H') => Action::ShowHealth,
+        KeyCode::Char('B') => Action::CaptureBaseline,
+        KeyCode::Char('d') => Action::ShowDiff,
+        KeyCode::Char('v') => Action::ShowEvents,
+        KeyCode::Char('V') => Action::ShowPanels,
+        KeyCode::Char(']') => Action::MoreProcesses,
+        KeyCode::Char('[') => Action::FewerProcesses,
+        KeyCode::Char('e') => Action::ExportProcesses,
+        KeyCode::Char('E') => Action::ExportCharts,
+        KeyCode::Char('y') => Action::CopySnapshot,
+        KeyCode::Char('t') => Action::CycleTheme,
+        KeyCode::Char('b') => Action::ToggleNetworkUnits,
+        KeyCode::Char('L') => Action::ToggleNetworkScale,
+        KeyCode::Char('n') => Action::ToggleNetworkCounters,
+        KeyCode::Char('I') => Action::CycleInterface,
+        KeyCode::Char('a') => Action::ToggleAllDisks,
+        KeyCode::Char('U') => Action::ToggleDiskGauges,
+        KeyCode::Char('r') => Action::ResetHistory,
+        KeyCode::Char('p') => Action::TogglePause,
+        KeyCode::Char('+') => Action::FasterRefresh,
+        KeyCode::Char('-') => Action::SlowerRefresh,
+        _ => return None,
+    };
+    Some(action)
+}
+
+// Eylemi uygula - uygulamadan çıkılması gerekiyorsa true döner
+fn apply(app: &mut App, action: Action) -> bool {
+    // Salt okunur modda sinyal gönderen popup'lar hiç açılmaz - onay adımlarına da ulaşılamaz
+    if app.read_only && action.is_destructive() {
+        app.set_status("Read-only mode: sending signals is disabled".to_string(), true);
+        return false;
+    }
+    // Popup açıksa eylemler önce popup'a gider - 'q' bile popup'ı kapatmaz, uygulamadan çıkmaz
+    if app.kill_popup.is_some() {
+        match action {
+            Action::Up => app.move_kill_selection(-1),
+            Action::Down => app.move_kill_selection(1),
+            Action::Select => app.confirm_kill(),
+            Action::Back => app.kill_popup = None,
+            _ => {}
+        }
+        return false;
+    }
+    if app.detail_popup.is_some() {
+        if matches!(action, Action::Back | Action::Select) {
+            app.detail_popup = None;
+        }
+        return false;
+    }
+    if app.memory_popup {
+        if matches!(action, Action::Back | Action::Select | Action::ShowTopMemory) {
+            app.memory_popup = false;
+        }
+        return false;
+    }
+    if app.panels_popup.is_some() {
+        match action {
+            Action::Up => app.move_panels_selection(-1),
+            Action::Down => app.move_panels_selection(1),
+            Action::Top => app.move_panels_selection(isize::MIN),
+            Action::Bottom => app.move_panels_selection(isize::MAX),
+            Action::Select => app.toggle_selected_section(),
+            Action::Back | Action::ShowPanels => app.panels_popup = None,
+            _ => {}
+        }
+        return false;
+    }
+    if app.offenders_popup {
+        if matches!(action, Action::Back | Action::Select | Action::ShowOffenders) {
+            app.offenders_popup = false;
+        }
+        return false;
+    }
+    if app.health_popup {
+        if matches!(action, Action::Back | Action::Select | Action::ShowHealth) {
+            app.health_popup = false;
+        }
+        return false;
+    }
+    if app.diff_popup {
+        match action {
+            Action::CaptureBaseline => app.capture_baseline(), // Referansı şimdiye taşı
+            Action::Back | Action::Select | Action::ShowDiff => app.diff_popup = false,
+            _ => {}
+        }
+        return false;
+    }
+    if app.events_popup.is_some() {
+        match action {
+            Action::Up => app.scroll_events(-1),
+            Action::Down => app.scroll_events(1),
+            Action::PageUp => app.scroll_events(-10),
+            Action::PageDown => app.scroll_events(10),
+            Action::Top => app.scroll_events(isize::MIN),
+            Action::Bottom => app.scroll_events(isize::MAX),
+            Action::Back | Action::Select | Action::ShowEvents => app.events_popup = None,
+            _ => {}
+        }
+        return false;
+    }
+    match action {
+        // confirm_quit açıksa önce onay popup'ı gösterilir, çıkışa 'y' karar verir
+        Action::Quit | Action::Back if app.confirm_quit => app.quit_prompt = true,
+        Action::Quit | Action::Back => return true,
+        Action::FocusNext => app.focus_next(),          // Sonraki panele odaklan
+        Action::FocusPrevious => app.focus_previous(),  // Önceki panele odaklan
+        Action::ToggleZoom => app.toggle_zoom(),        // Odaklanılan paneli büyüt/küçült
+        Action::ToggleCompact => app.toggle_compact(),  // Sade düzeni aç/kapat
+        Action::ToggleCoreHeatmap => app.toggle_core_heatmap(), // Çekirdekleri ısı haritası olarak göster
+        Action::CycleIdleCores => app.cycle_idle_cores(),       // Boştaki çekirdekler: normal → soluk → özet
+        Action::CycleSort => app.cycle_process_sort(),  // Process sıralamasını değiştir
+        Action::ToggleProcessFilter => app.toggle_process_filter(), // Gizlenen process'leri göster/gizle
+        Action::ToggleProcessTree => app.toggle_process_tree(), // Düz liste ↔ process ağacı
+        Action::ToggleCollapse => app.toggle_collapse(),  // Ağaç düğümünü aç/kapat
+        Action::ToggleTreeTotals => app.toggle_tree_totals(), // Ebeveynlerde alt ağaç toplamı
+        Action::KillProcess => app.open_kill_popup(),   // Seçili process'e sinyal gönder
+        Action::TogglePin => app.toggle_pin(),          // Seçili process'i sabitle
+        Action::KillByName => app.open_bulk_kill_popup(), // Ada uyan tüm process'lere sinyal gönder
+        Action::ShowTopMemory => app.memory_popup = true, // En çok bellek kullananları göster
+        Action::ShowOffenders => app.offenders_popup = true, // CPU'da sık görülenleri göster
+        Action::ShowHealth => app.health_popup = true,
+        Action::CaptureBaseline => app.capture_baseline(), // Karşılaştırma referansı al
+        Action::ShowDiff => app.open_diff_popup(),      // Referanstan beri değişenler
+        Action::ShowEvents => app.events_popup = Some(0),  // Olay günlüğünü göster
+        Action::ShowPanels => app.open_panels_popup(),  // Bölümleri göster/gizle
+        Action::MoreProcesses => app.more_processes(),  // Process listesini uzat
+        Action::FewerProcesses => app.fewer_processes(), // Process listesini kısalt
+        Action::ExportProcesses => app.export_processes(), // Process listesini dosyaya dök
+        Action::ExportCharts => app.export_charts(),
+        // --snapshot ile aynı metin; boyut okunamazsa (çıktı yönlendirilmiş) 120x40
+        Action::CopySnapshot => {
+            let (width, height) = crossterm::terminal::size().unwrap_or((120, 40));
+            let screen = crate::ui::render_to_string(app, width, height);
+            app.copy_snapshot(screen);
+        }
+        Action::CycleTheme => app.cycle_theme(),        // Renk temasını değiştir
+        Action::ToggleNetworkUnits => app.toggle_network_units(), // Ağ hızı: bit/s ↔ byte/s
+        Action::ToggleNetworkScale => app.toggle_network_scale(), // Ağ grafiği: doğrusal ↔ log
+        Action::ToggleNetworkCounters => app.toggle_network_counters(), // Hızlar ↔ arayüz sayaçları
+        Action::CycleInterface => app.cycle_interface(),    // Grafikte sonraki arayüz
+        Action::ToggleAllDisks => app.toggle_all_disks(),   // Sahte dosya sistemlerini göster/gizle
+        Action::ToggleDiskGauges => app.toggle_disk_gauges(), // Disk tablosu ↔ gauge ızgarası
+        Action::ResetHistory => app.reset_history(),        // Grafikleri sıfırla
+        Action::TogglePause => app.toggle_pause(),          // Ekranı dondur / devam et
+        Action::FasterRefresh => app.faster_refresh(),  // Daha sık güncelle
+        Action::SlowerRefresh => app.slower_refresh(),  // Daha seyrek güncelle
+        Action::NextTab => app.next_tab(),
+        Action::PreviousTab => app.previous_tab(),
+        Action::GoToTab(tab) => app.active_tab = tab,
+        // Yukarı/aşağı odaklanılan paneli kaydırır: CPU'da çekirdek listesi, process'lerde seçim
+        // Sayfa, son çizimde tablonun gövdesine sığan satır sayısı kadardır
+        Action::Up | Action::Down | Action::Top | Action::Bottom | Action::PageUp | Action::PageDown => {
+            let page = app.process_page_size() as isize;
+            let delta = match action {
+                Action::Up => -1,
+                Action::Down => 1,
+                Action::PageUp => -page,
+                Action::PageDown => page,
+                Action::Top => isize::MIN,
+                _ => isize::MAX,
+            };
+            // System ve Processes sekmelerinde paneller arası odak yok - oklar tek tabloyu kaydırır
+            match app.focused_panel {
+                _ if app.active_tab == Tab::System => app.move_disk_selection(delta),
+                _ if app.active_tab == Tab::Processes => app.move_process_selection(delta),
+                Panel::Cpu => app.scroll_cpu(delta),
+                Panel::Processes => app.move_process_selection(delta),
+                _ => {}
+            }
+        }
+        Action::Select if app.active_tab == Tab::Processes || app.focused_panel == Panel::Processes => app.open_detail_popup(),
+        Action::Select => {}
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_row_at_maps_clicks_to_rows() {
+        let body = Rect { x: 10, y: 5, width: 20, height: 4 };
+        assert_eq!(row_at(body, 0, 10, 5), Some(0));
+        assert_eq!(row_at(body, 3, 29, 8), Some(6));
+        // Gövdenin dışı - başlık satırı, kenarlık
+        assert_eq!(row_at(body, 0, 10, 4), None);
+        assert_eq!(row_at(body, 0, 30, 5), None);
+        assert_eq!(row_at(body, 0, 10, 9), None);
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[tokio::test]
+    async fn test_read_only_blocks_signal_actions() {
+        let sample = crate::source::Sample {
+            cpu_usage: vec![0.0],
+            processes: vec![crate::source::ProcessSample { pid: 42, name: "victim".to_string(), ..Default::default() }],
+            ..Default::default()
+        };
+        let source = crate::source::MockSource::new(vec![sample]);
+        let config = crate::config::Config { read_only: true, ..Default::default() };
+        let mut app = App::new(config, crate::state::ViewState::default(), Box::new(source)).await.unwrap();
+        app.focused_panel = Panel::Processes;
+        let mut input = InputHandler::default();
+
+        assert!(!input.handle_key(&mut app, key(KeyCode::Char('x'))));
+        assert!(!input.handle_key(&mut app, key(KeyCode::Char('X'))));
+        assert!(app.kill_popup.is_none() && app.bulk_kill_popup.is_none());
+        assert!(app.active_status().unwrap().is_error);
+        // Diğer eylemler çalışmaya devam eder
+        input.handle_key(&mut app, key(KeyCode::Enter));
+        assert_eq!(app.detail_popup.as_ref().map(|popup| popup.pid), Some(42));
+    }
+
+    #[test]
+    fn test_vim_keys_match_arrows() {
+        let mut input = InputHandler::default();
+        assert_eq!(input.action_for(key(KeyCode::Char('j'))), input.action_for(key(KeyCode::Down)));
+        assert_eq!(input.action_for(key(KeyCode::Char('k'))), input.action_for(key(KeyCode::Up)));
+        assert_eq!(input.action_for(key(KeyCode::Char('h'))), Some(Action::PreviousTab));
+        assert_eq!(input.action_for(key(KeyCode::Char('l'))), Some(Action::NextTab));
+        assert_eq!(input.action_for(key(KeyCode::Char('G'))), Some(Action::Bottom));
+    }
+
+    #[test]
+    fn test_gg_sequence() {
+        let mut input = InputHandler::default();
+        assert_eq!(input.action_for(key(KeyCode::Char('g'))), None);
+        assert_eq!(input.action_for(key(KeyCode::Char('g'))), Some(Action::Top));
+
+        // Araya giren tuş diziyi iptal eder ama kendisi yine işlenir
+        assert_eq!(input.action_for(key(KeyCode::Char('g'))), None);
+        assert_eq!(input.action_for(key(KeyCode::Char('j'))), Some(Action::Down));
+        assert_eq!(input.action_for(key(KeyCode::Char('g'))), None);
+    }
+
+    #[test]
+    fn test_user_bindings_override_defaults() {
+        let bindings = HashMap::from([
+            (KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL), Action::Quit),
+            (key(KeyCode::Char('j')), Action::CycleTheme),
+        ]);
+        let mut input = InputHandler::new(bindings);
+        assert_eq!(
+            input.action_for(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+            Some(Action::Quit)
+        );
+        assert_eq!(input.action_for(key(KeyCode::Char('j'))), Some(Action::CycleTheme));
+        // Atanmamış 'q' güvenlik için hâlâ çıkış yapar
+        assert_eq!(input.action_for(key(KeyCode::Char('q'))), Some(Action::Quit));
+        // Büyük harf SHIFT ile gelse de eşleşir
+        assert_eq!(
+            input.action_for(KeyEvent::new(KeyCode::Char('G'), KeyModifiers::SHIFT)),
+            Some(Action::Bottom)
+        );
+    }
+}
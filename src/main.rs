@@ -1,90 +1,311 @@
 // Ana program dosyası - uygulamamızın giriş noktası
 // Bu dosya tıpkı bir evin kapısı gibi, tüm bileşenleri bir araya getirir
 
-use anyhow::Result;
-use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
-    execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-};
+use anyhow::{Context, Result};
+use clap::Parser;
+use crossterm::event::{self, Event, KeyEventKind};
 use ratatui::{
     backend::CrosstermBackend,
     Terminal,
 };
 use std::{
-    io,
+    io::{self, Write},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
     time::{Duration, Instant},
 };
+use tokio::net::TcpListener;
 
 // Kendi modüllerimizi import ediyoruz
+mod alerts;        // Eşik tabanlı uyarılar
 mod app;           // Uygulamanın ana mantığı burada olacak
+mod battery;       // Laptop bataryası (battery özelliği ile)
+mod cli;           // Komut satırı argümanları
+mod clipboard;     // Sistem panosuna kopyalama (clipboard özelliği ile)
+mod config;        // Kullanıcı ayarları (config.toml)
+mod connections;   // TCP bağlantı sayıları (/proc/net/tcp)
+mod container;     // Konteyner içinde çalışma tespiti
+mod cpu_times;     // CPU zamanı dağılımı (/proc/stat)
+mod demo;          // Sahte, salınan metrikler (--demo)
+mod diff;          // Referans snapshot ile şimdiki durumun farkı
+mod export;        // Process listesi ve grafik geçmişinin CSV dökümü
+mod fans;          // Fan hızları (hwmon)
+mod gpu;           // NVIDIA GPU kullanımı (nvidia özelliği ile)
+mod health;        // Tek bakışta sistem sağlığı (Good/Warning/Critical)
+mod hotplug;       // Çevrimiçi CPU çekirdekleri (cpu hotplug)
+mod influx;        // InfluxDB line protocol çıktısı
+mod input;         // Klavye girişi → uygulama eylemleri
+mod metrics;       // Prometheus /metrics endpoint'i
+mod notifications; // Kritik durumlar için masaüstü bildirimleri
+mod numa;          // NUMA düğümleri (/sys/devices/system/node)
+mod panels;        // Bölüm görünürlüğü
+mod remote;        // SSH üzerinden uzak makine izleme
+mod replay;        // Oturum kaydetme ve tekrar oynatma
+mod schedule;      // Kategori başına yenileme aralıkları ([refresh])
+mod snapshot;      // Serileştirilebilir metrik kopyası
+mod source;        // Metrik kaynağı soyutlaması (canlı sistem / kayıt)
+mod state;         // Oturumlar arası görünüm durumu (state.json)
+mod talkers;       // Process başına TCP bağlantı sayıları (net_talkers özelliği ile)
+mod terminal;      // Terminali arayüz moduna alma ve geri yükleme (sinyallerde de)
+mod theme;         // Renk temaları
+mod tree;          // Process ağacı (ebeveyn/çocuk görünümü)
 mod ui;            // Kullanıcı arayüzü komponetleri
-mod system_info;   // Sistem bilgilerini toplayan modül
+mod vmstat;        // Swap giriş/çıkış sayaçları (/proc/vmstat)
+mod zram;          // Sıkıştırılmış bellek istatistikleri (/sys/block/zram*)
+#[allow(dead_code)]
+mod system_info;   // Sistem bilgilerini toplayan modül - SystemInfoCollector henüz kullanılmıyor
 
 use app::App;
+use cli::Cli;
+use config::Config;
+use demo::DemoSource;
+use influx::InfluxExporter;
+use input::InputHandler;
+use remote::RemoteSource;
+use replay::{RecordingSource, ReplaySource};
+use snapshot::SharedSnapshot;
+use source::{LiveSource, MetricsSource};
+use state::ViewState;
 use ui::ui;
 
 // Ana async fonksiyon - Rust'ta async main için tokio macro kullanılır
 #[tokio::main]
 async fn main() -> Result<()> {
-    // Terminal'i ham moda alıyoruz - bu sayede karakterleri tek tek yakalayabiliriz
-    // Tıpkı bir piyanist gibi her tuşa ayrı ayrı tepki verebileceğiz
-    enable_raw_mode()?;
+    let cli = Cli::parse();
+    
+    // Uzak izlemenin karşı tarafı - config, terminal veya arayüz gerekmez
+    if cli.agent {
+        let interval = Duration::from_millis(cli.agent_interval_ms.max(100));
+        return remote::run_agent(&mut LiveSource::new(), interval).await;
+    }
+    
+    // Ayarları terminal ham moda geçmeden önce yüklüyoruz
+    // Böylece hatalı bir config dosyası terminali bozmadan okunabilir bir hata verir
+    let (config, config_warnings) = Config::load()?;
     
-    let mut stdout = io::stdout();
-    // Alternatif ekrana geçiyoruz - bu sayede mevcut terminal içeriğini bozmayız
-    // Uygulama kapandığında eski ekran geri gelecek
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    // Tuş atamaları App'e değil girdi katmanına ait - config App'e taşınmadan önce ayırıyoruz
+    let input = InputHandler::new(config.keybindings.clone());
     
+    // Metriklerin kaynağı - kayıt dosyası, uzak makine, demo verisi veya canlı sistem
+    // Kayıt isteniyorsa kaynak, her örneği dosyaya da yazan bir sarmalayıcıya alınır
+    let mut source: Box<dyn MetricsSource> = match (&cli.replay, &cli.remote) {
+        (Some(path), _) => Box::new(ReplaySource::open(path, cli.speed)?),
+        (None, Some(target)) => Box::new(RemoteSource::connect(target, &cli.remote_command)?),
+        (None, None) if cli.demo => Box::new(DemoSource::new()),
+        (None, None) => Box::new(LiveSource::new()),
+    };
+    if let Some(path) = &cli.record {
+        source = Box::new(RecordingSource::create(path, source)?);
+    }
+    
+    // Uygulamamızın ana durumunu tutacak struct'ı oluşturuyoruz
+    // Yenileme aralığı: komut satırı > ortam değişkeni > config dosyası > son oturum
+    let refresh_ms = cli.refresh_ms.or(config.refresh_ms);
+    let mut app = App::new(config, ViewState::load(), source).await?;
+    if let Some(refresh_ms) = refresh_ms {
+        app.set_refresh_ms(refresh_ms);
+    }
+    if cli.safe {
+        app.read_only = true;
+    }
+    app.key_labels = input.key_labels();
+    // Yok sayılan ortam değerleri - stderr'de çıkışta da görünür, TUI'de olay günlüğüne düşer
+    for warning in config_warnings {
+        eprintln!("Warning: {warning}");
+        app.set_status(warning, true);
+    }
+    
+    if cli.snapshot {
+        return print_snapshot(&mut app).await;
+    }
+    
+    // Her güncellemeden sonra yayınlanan metrik kopyası - metrics endpoint'i bunu okur
+    let snapshot: SharedSnapshot = Arc::new(Mutex::new(app.snapshot()));
+    
+    if let Some(port) = cli.metrics_port {
+        // Port'u burada bağlıyoruz ki kullanımdaysa hata TUI açılmadan görünsün
+        let listener = TcpListener::bind(("0.0.0.0", port))
+            .await
+            .with_context(|| format!("Failed to bind metrics port {port}"))?;
+        let shared = Arc::clone(&snapshot);
+        tokio::spawn(async move {
+            let _ = metrics::serve(listener, shared).await;
+        });
+    }
+    
+    // InfluxDB'ye gönderim ayrı bir task'te - host adı her satıra tag olarak eklenir
+    let influx = cli.influx_url.as_deref().map(|url| {
+        let host = app.sample.host.host_name.clone().unwrap_or_else(|| "unknown".to_string());
+        InfluxExporter::spawn(url, &cli.influx_db, host)
+    });
+    
+    if cli.no_tui || cli.stream_json {
+        run_headless(&mut app, &snapshot, influx, cli.stream_json).await
+    } else {
+        run_tui(&mut app, input, &snapshot, influx, !cli.no_mouse).await
+    }
+}
+
+// Arayüzün tek bir karesini düz metin olarak yazdır - CI çıktıları ve hata raporları için
+// CPU kullanımı ve hızlar iki ölçüm arasındaki farktan hesaplandığı için önce bir tur beklenir.
+// Çıktı bir dosyaya yönlendirildiyse terminal boyutu okunamaz, 120x40 kullanılır
+async fn print_snapshot(app: &mut App) -> Result<()> {
+    tokio::time::sleep(Duration::from_millis(app.refresh_ms)).await;
+    app.update().await?;
+    let (width, height) = crossterm::terminal::size().unwrap_or((120, 40));
+    let screen = ui::render_to_string(app, width, height);
+    // Çıktı `head` gibi bir komuta bağlıysa okuyucu erken kapanabilir - bu bir hata değil
+    match writeln!(io::stdout(), "{screen}") {
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        result => Ok(result?),
+    }
+}
+
+// Son toplanan metrikleri paylaşılan snapshot'a yaz
+fn publish_snapshot(app: &App, snapshot: &SharedSnapshot) {
+    if let Ok(mut shared) = snapshot.lock() {
+        *shared = app.snapshot();
+    }
+}
+
+// Snapshot'ı InfluxDB gönderim kuyruğuna ekle ve birikmiş uyarıları döndür
+fn export_influx(app: &App, influx: &mut Option<InfluxExporter>) -> Vec<String> {
+    let Some(influx) = influx else {
+        return Vec::new();
+    };
+    influx.push(app.snapshot());
+    std::iter::from_fn(|| influx.take_warning()).collect()
+}
+
+// Snapshot'ı tek satır JSON olarak stdout'a yaz - her satırdan sonra flush edilir ki
+// `jq` gibi tüketiciler tampon dolmasını beklemeden veriyi alsın
+fn write_json_line(app: &App) -> io::Result<()> {
+    let mut stdout = io::stdout().lock();
+    serde_json::to_writer(&mut stdout, &app.snapshot())?;
+    stdout.write_all(b"\n")?;
+    stdout.flush()
+}
+
+// Terminal arayüzü olmadan çalış - metrik toplar ve yayınlar, istenirse stdout'a JSON satırları yazar
+// Ctrl+C, SIGTERM ile veya okuyucu kapandığında (kırık boru) düzgün şekilde çıkılır
+async fn run_headless(
+    app: &mut App,
+    snapshot: &SharedSnapshot,
+    mut influx: Option<InfluxExporter>,
+    stream_json: bool,
+) -> Result<()> {
+    loop {
+        tokio::select! {
+            _ = tokio::time::sleep(Duration::from_millis(app.refresh_ms)) => {
+                app.update().await?;
+                publish_snapshot(app, snapshot);
+                if stream_json {
+                    match write_json_line(app) {
+                        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => return Ok(()),
+                        result => result?,
+                    }
+                }
+                for warning in export_influx(app, &mut influx) {
+                    eprintln!("Warning: {warning}");
+                }
+            }
+            _ = terminal::shutdown_signal() => return Ok(()),
+        }
+    }
+}
+
+// Terminal arayüzüyle çalış
+// mouse kapalıysa fare yakalanmaz, terminalin kendi metin seçimi çalışmaya devam eder
+// Döngü nasıl biterse bitsin ('q', sinyal veya hata) terminal eski haline döndürülür
+async fn run_tui(
+    app: &mut App,
+    input: InputHandler,
+    snapshot: &SharedSnapshot,
+    influx: Option<InfluxExporter>,
+    mouse: bool,
+) -> Result<()> {
+    terminal::install_panic_hook();
+    
+    // Terminal'i ham moda ve alternatif ekrana alıyoruz - bu sayede karakterleri tek tek
+    // yakalayabilir, mevcut terminal içeriğini bozmayız. Uygulama kapandığında eski ekran geri gelir
+    let shutdown = terminal::shutdown_flag();
+    let result = match terminal::enter(mouse) {
+        Ok(()) => run_event_loop(app, input, snapshot, influx, mouse, &shutdown).await,
+        Err(err) => Err(err.into()),
+    };
+    
+    // Temizlik işlemleri - uygulamadan çıkarken terminal'i eski haline döndür
+    terminal::restore();
+    result?;
+    
+    // Görünüm durumunu bir sonraki açılış için sakla
+    // Yazılamazsa (salt okunur home vs.) çıkışı engellemiyoruz, sadece haber veriyoruz
+    if let Err(err) = app.view_state().save() {
+        eprintln!("Warning: could not save view state: {err}");
+    }
+
+    Ok(())
+}
+
+// Çizim, girdi ve güncelleme döngüsü - 'q', sonlandırma sinyali veya hata ile biter
+async fn run_event_loop(
+    app: &mut App,
+    mut input: InputHandler,
+    snapshot: &SharedSnapshot,
+    mut influx: Option<InfluxExporter>,
+    mouse: bool,
+    shutdown: &AtomicBool,
+) -> Result<()> {
     // Terminal backend'ini kuruyoruz - ratatui'nin crossterm ile konuşması için köprü
-    let backend = CrosstermBackend::new(stdout);
+    let backend = CrosstermBackend::new(io::stdout());
     let mut terminal = Terminal::new(backend)?;
-
-    // Uygulamamızın ana durumunu tutacak struct'ı oluşturuyoruz
-    let mut app = App::new().await?;
     
     // Ana event loop - tüm modern GUI uygulamalarında böyle bir döngü vardır
     // Event gelir → İşlenir → UI güncellenir → Tekrar event beklenir
-    let tick_rate = Duration::from_millis(250); // 4 FPS - sistem bilgilerini güncellemek için
+    // Yenileme aralığı kullanıcı tarafından değiştirilebildiği için her turda app'ten okunur
+    // Sinyal poll beklerken gelirse en geç bir tick sonra çıkılır
     let mut last_tick = Instant::now();
-    let tick_delay = tokio::time::Duration::from_millis(500);
-    // Update network calculation in app.rs:
-    let time_delta = 0.5; // Instead of 0.25
-    loop {
+    while !shutdown.load(Ordering::SeqCst) {
+        let tick_rate = Duration::from_millis(app.refresh_ms);
+        
         // UI'yi çiziyoruz - her frame'de ekranı yeniden çizer
-        terminal.draw(|f| ui(f, &app))?;
+        terminal.draw(|f| ui(f, app))?;
 
         // Event handling - kullanıcı girişini kontrol ediyoruz
         let timeout = tick_rate.saturating_sub(last_tick.elapsed());
         
         if crossterm::event::poll(timeout)? {
-            if let Event::Key(key) = event::read()? {
+            match event::read()? {
                 // Sadece key press olaylarını işliyoruz (key release değil)
-                if key.kind == KeyEventKind::Press {
-                    match key.code {
-                        KeyCode::Char('q') => break, // 'q' tuşuna basınca çık
-                        KeyCode::Esc => break,       // Escape tuşuna basınca çık
-                        _ => {} // Diğer tuşları şimdilik görmezden gel
-                    }
+                Event::Key(key) if key.kind == KeyEventKind::Press && input.handle_key(app, key) => break,
+                Event::Mouse(mouse_event) if mouse => input.handle_mouse(app, mouse_event),
+                // Yeniden boyutlandırmada tick'i beklemeden yeni boyutta çiz - eski boyuttaki
+                // kare bir an bozuk görünmesin. Ardından kaydırmalar yeni düzene göre sınırlanır
+                Event::Resize(_, _) => {
+                    terminal.autoresize()?;
+                    terminal.draw(|f| ui(f, app))?;
+                    app.clamp_to_view();
                 }
+                _ => {}
             }
         }
 
-        // Belirli aralıklarla sistem bilgilerini güncelle
+        // Belirli aralıklarla sistem bilgilerini güncelle - donukken ekran olduğu gibi kalır
+        // ama tick yine ilerler, yoksa poll sıfır bekleme ile boşa döner
         if last_tick.elapsed() >= tick_rate {
-            app.update().await?;
+            if !app.paused {
+                app.update().await?;
+                publish_snapshot(app, snapshot);
+                // Gönderim hataları arayüzü durdurmaz, sadece alt bilgi çubuğunda görünür
+                for warning in export_influx(app, &mut influx) {
+                    app.set_status(warning, true);
+                }
+            }
             last_tick = Instant::now();
         }
     }
 
-    // Temizlik işlemleri - uygulamadan çıkarken terminal'i eski haline döndür
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
-
     Ok(())
 }
@@ -0,0 +1,133 @@
+// metrics.rs - Prometheus metrik endpoint'i
+// --metrics-port verilirse /metrics adresinde Prometheus text formatında metrik sunar
+// Veriler App'in her güncellemede paylaştığı snapshot'tan okunur - tekrar toplanmaz
+
+use anyhow::Result;
+use axum::{extract::State, http::header, response::IntoResponse, routing::get, Router};
+use std::fmt::Write;
+use tokio::net::TcpListener;
+
+use crate::snapshot::{SharedSnapshot, Snapshot};
+
+// HTTP sunucusunu çalıştır - listener önceden bağlanmış olmalı,
+// böylece port kullanımdaysa hata TUI açılmadan önce görülür
+pub async fn serve(listener: TcpListener, snapshot: SharedSnapshot) -> Result<()> {
+    let router = Router::new()
+        .route("/metrics", get(metrics_handler))
+        .with_state(snapshot);
+    axum::serve(listener, router).await?;
+    Ok(())
+}
+
+async fn metrics_handler(State(snapshot): State<SharedSnapshot>) -> impl IntoResponse {
+    // Kilidi sadece kopyalama süresince tutuyoruz
+    let snapshot = snapshot.lock().map(|s| s.clone());
+    match snapshot {
+        Ok(snapshot) => (
+            [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+            render_prometheus(&snapshot),
+        ),
+        Err(_) => (
+            [(header::CONTENT_TYPE, "text/plain")],
+            "metrics unavailable\n".to_string(),
+        ),
+    }
+}
+
+// Snapshot'ı Prometheus text exposition formatına çevir
+pub fn render_prometheus(snapshot: &Snapshot) -> String {
+    let mut out = String::new();
+
+    gauge(&mut out, "sysmon_cpu_usage_percent", "CPU usage per core");
+    for (index, usage) in snapshot.cpu_usage.iter().enumerate() {
+        let _ = writeln!(out, "sysmon_cpu_usage_percent{{core=\"{}\"}} {}", snapshot.cpu_id(index), usage);
+    }
+
+    gauge(&mut out, "sysmon_memory_used_bytes", "Used memory in bytes");
+    let _ = writeln!(out, "sysmon_memory_used_bytes {}", snapshot.memory_used);
+    gauge(&mut out, "sysmon_memory_total_bytes", "Total memory in bytes");
+    let _ = writeln!(out, "sysmon_memory_total_bytes {}", snapshot.memory_total);
+    gauge(&mut out, "sysmon_swap_used_bytes", "Used swap in bytes");
+    let _ = writeln!(out, "sysmon_swap_used_bytes {}", snapshot.swap_used);
+    gauge(&mut out, "sysmon_swap_total_bytes", "Total swap in bytes");
+    let _ = writeln!(out, "sysmon_swap_total_bytes {}", snapshot.swap_total);
+
+    gauge(&mut out, "sysmon_network_receive_bytes_per_second", "Download rate across all interfaces");
+    let _ = writeln!(out, "sysmon_network_receive_bytes_per_second {}", snapshot.network_rx_rate);
+    gauge(&mut out, "sysmon_network_transmit_bytes_per_second", "Upload rate across all interfaces");
+    let _ = writeln!(out, "sysmon_network_transmit_bytes_per_second {}", snapshot.network_tx_rate);
+
+    gauge(&mut out, "sysmon_disk_usage_percent", "Disk usage per mount point");
+    for disk in &snapshot.disks {
+        let _ = writeln!(
+            out,
+            "sysmon_disk_usage_percent{{mount=\"{}\"}} {}",
+            escape_label(&disk.mount_point),
+            disk.usage_percent
+        );
+    }
+
+    gauge(&mut out, "sysmon_temperature_celsius", "Component temperature");
+    for temp in &snapshot.temperatures {
+        let _ = writeln!(
+            out,
+            "sysmon_temperature_celsius{{component=\"{}\"}} {}",
+            escape_label(&temp.component_name),
+            temp.current_temp
+        );
+    }
+
+    out
+}
+
+// Metrik başlığı - HELP ve TYPE satırları
+fn gauge(out: &mut String, name: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} gauge", name);
+}
+
+// Label değerlerinde ters bölü, tırnak ve satır sonu kaçışlanmalı
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Local;
+
+    #[test]
+    fn test_render_prometheus() {
+        let snapshot = Snapshot {
+            timestamp: Local::now(),
+            cpu_usage: vec![10.0, 20.5],
+            cpu_ids: vec![0, 1],
+            cpu_average: 15.25,
+            memory_used: 1024,
+            memory_total: 4096,
+            swap_used: 0,
+            swap_total: 0,
+            network_rx_rate: 100,
+            network_tx_rate: 50,
+            network_received: 0,
+            network_transmitted: 0,
+            disk_read_rate: 0,
+            disk_write_rate: 0,
+            disks: Vec::new(),
+            temperatures: Vec::new(),
+            processes: Vec::new(),
+        };
+        let text = render_prometheus(&snapshot);
+        assert!(text.contains("sysmon_cpu_usage_percent{core=\"1\"} 20.5\n"));
+        assert!(text.contains("sysmon_memory_total_bytes 4096\n"));
+        assert!(text.contains("# TYPE sysmon_network_receive_bytes_per_second gauge\n"));
+    }
+
+    #[test]
+    fn test_escape_label() {
+        assert_eq!(escape_label("C:\\ \"x\""), "C:\\\\ \\\"x\\\"");
+    }
+}
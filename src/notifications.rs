@@ -0,0 +1,27 @@
+// notifications.rs - Masaüstü bildirimleri
+// Kritik durumlar (dolu disk, kritik sıcaklık) için işletim sisteminin bildirim
+// sistemini kullanır. Config'de notify_on_critical açık değilse hiç çağrılmaz.
+
+use notify_rust::Notification;
+
+use crate::alerts::CriticalEvent;
+
+const APP_NAME: &str = "rust-system-monitor";
+
+// Bir kritik durum olayı için bildirim gönder
+// Bildirim servisi (ör. D-Bus) yavaş yanıt verebilir veya hiç olmayabilir,
+// bu yüzden ayrı bir blocking task'te gönderip hataları yok sayıyoruz
+pub fn notify(event: &CriticalEvent) {
+    let (summary, body) = match event {
+        CriticalEvent::Entered { kind, message } => (format!("Critical: {}", kind.label()), message.clone()),
+        CriticalEvent::Cleared { kind, message } => (format!("Resolved: {}", kind.label()), message.clone()),
+    };
+    
+    tokio::task::spawn_blocking(move || {
+        let _ = Notification::new()
+            .appname(APP_NAME)
+            .summary(&summary)
+            .body(&body)
+            .show();
+    });
+}
@@ -0,0 +1,68 @@
+// numa.rs - NUMA düğümleri ve her düğüme ait çekirdekler
+// Çok soketli makinelerde 64 gauge'luk bir duvar yerine çekirdekler düğüm başlıkları altında
+// gruplanır. Linux'ta topoloji /sys/devices/system/node/nodeN/cpulist dosyalarından okunur.
+// Diğer platformlarda veya topoloji okunamazsa liste boş kalır ve çekirdekler düz listelenir
+
+use serde::{Deserialize, Serialize};
+
+// Bir NUMA düğümü ve üzerindeki mantıksal çekirdekler (sysinfo'daki çekirdek sırası)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NumaNode {
+    pub id: usize,
+    pub cpus: Vec<usize>,
+}
+
+// "0-3,8,10-11" biçimindeki çekirdek listesini aç - boş metin boş liste demek
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub fn parse_cpulist(text: &str) -> Option<Vec<usize>> {
+    let mut cpus = Vec::new();
+    for part in text.trim().split(',').filter(|part| !part.is_empty()) {
+        match part.split_once('-') {
+            Some((start, end)) => {
+                let (start, end): (usize, usize) = (start.parse().ok()?, end.parse().ok()?);
+                cpus.extend(start..=end);
+            }
+            None => cpus.push(part.parse().ok()?),
+        }
+    }
+    Some(cpus)
+}
+
+// Çekirdeği olan düğümler, numaraya göre sıralı
+// Sadece bellek içeren düğümler (CXL bellek vb.) gruplamada işe yaramaz, atlanır
+#[cfg(target_os = "linux")]
+pub fn read_numa_nodes() -> Vec<NumaNode> {
+    let Ok(entries) = std::fs::read_dir("/sys/devices/system/node") else {
+        return Vec::new();
+    };
+    let mut nodes: Vec<NumaNode> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let id = entry.file_name().to_str()?.strip_prefix("node")?.parse().ok()?;
+            let cpulist = std::fs::read_to_string(entry.path().join("cpulist")).ok()?;
+            let cpus = parse_cpulist(&cpulist)?;
+            (!cpus.is_empty()).then_some(NumaNode { id, cpus })
+        })
+        .collect();
+    nodes.sort_by_key(|node| node.id);
+    nodes
+}
+
+// /sys olmayan platformlarda çekirdekler düz listelenir
+#[cfg(not(target_os = "linux"))]
+pub fn read_numa_nodes() -> Vec<NumaNode> {
+    Vec::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cpulist() {
+        assert_eq!(parse_cpulist("0-3,8,10-11\n"), Some(vec![0, 1, 2, 3, 8, 10, 11]));
+        assert_eq!(parse_cpulist("5"), Some(vec![5]));
+        assert_eq!(parse_cpulist("\n"), Some(Vec::new()));
+        assert_eq!(parse_cpulist("0-x"), None);
+    }
+}
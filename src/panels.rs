@@ -0,0 +1,179 @@
+// panels.rs - Hangi bölümlerin ekranda gösterileceği
+// Config'deki [panels] tablosu başlangıç durumunu verir, 'V' ile açılan listeden çalışırken
+// değiştirilebilir. Gizlenen bölümün yeri boş kalmaz; düzen görünen bölümlerden yeniden kurulur
+
+use serde::Deserialize;
+
+use crate::app::Panel;
+
+// Tek tek gizlenebilen bölümler - bir grid paneli (örn. CPU) birden fazla bölüm içerebilir
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Section {
+    CpuGauges,
+    CpuChart,
+    MemoryInfo,
+    MemoryChart,
+    Processes,
+    Network,
+    DiskIo,
+    Alerts,
+    Disks,
+    Temperatures,
+}
+
+impl Section {
+    // Listedeki sıra - ekrandaki yerleşimle aynı, System sekmesindekiler sonda
+    pub const ALL: [Section; 10] = [
+        Section::CpuGauges,
+        Section::CpuChart,
+        Section::MemoryInfo,
+        Section::MemoryChart,
+        Section::Processes,
+        Section::Network,
+        Section::DiskIo,
+        Section::Alerts,
+        Section::Disks,
+        Section::Temperatures,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Section::CpuGauges => "CPU gauges",
+            Section::CpuChart => "CPU chart",
+            Section::MemoryInfo => "Memory info",
+            Section::MemoryChart => "Memory chart",
+            Section::Processes => "Processes",
+            Section::Network => "Network",
+            Section::DiskIo => "Disk I/O",
+            Section::Alerts => "Alerts",
+            Section::Disks => "Disks (System tab)",
+            Section::Temperatures => "Temperatures (System tab)",
+        }
+    }
+    
+    // [panels] tablosundaki anahtar - ortam değişkenlerinde de aynı adlar kullanılır
+    pub fn key(&self) -> &'static str {
+        match self {
+            Section::CpuGauges => "cpu_gauges",
+            Section::CpuChart => "cpu_chart",
+            Section::MemoryInfo => "memory_info",
+            Section::MemoryChart => "memory_chart",
+            Section::Processes => "processes",
+            Section::Network => "network",
+            Section::DiskIo => "disk_io",
+            Section::Alerts => "alerts",
+            Section::Disks => "disks",
+            Section::Temperatures => "temperatures",
+        }
+    }
+    
+    pub fn from_key(key: &str) -> Option<Section> {
+        Section::ALL.into_iter().find(|section| section.key() == key)
+    }
+}
+
+// Bölümlerin görünürlüğü - varsayılan olarak hepsi açık
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct PanelVisibility {
+    pub cpu_gauges: bool,
+    pub cpu_chart: bool,
+    pub memory_info: bool,
+    pub memory_chart: bool,
+    pub processes: bool,
+    pub network: bool,
+    pub disk_io: bool,
+    pub alerts: bool,
+    pub disks: bool,
+    pub temperatures: bool,
+}
+
+impl Default for PanelVisibility {
+    fn default() -> Self {
+        Self {
+            cpu_gauges: true,
+            cpu_chart: true,
+            memory_info: true,
+            memory_chart: true,
+            processes: true,
+            network: true,
+            disk_io: true,
+            alerts: true,
+            disks: true,
+            temperatures: true,
+        }
+    }
+}
+
+impl PanelVisibility {
+    fn flag_mut(&mut self, section: Section) -> &mut bool {
+        match section {
+            Section::CpuGauges => &mut self.cpu_gauges,
+            Section::CpuChart => &mut self.cpu_chart,
+            Section::MemoryInfo => &mut self.memory_info,
+            Section::MemoryChart => &mut self.memory_chart,
+            Section::Processes => &mut self.processes,
+            Section::Network => &mut self.network,
+            Section::DiskIo => &mut self.disk_io,
+            Section::Alerts => &mut self.alerts,
+            Section::Disks => &mut self.disks,
+            Section::Temperatures => &mut self.temperatures,
+        }
+    }
+
+    pub fn is_visible(&self, section: Section) -> bool {
+        match section {
+            Section::CpuGauges => self.cpu_gauges,
+            Section::CpuChart => self.cpu_chart,
+            Section::MemoryInfo => self.memory_info,
+            Section::MemoryChart => self.memory_chart,
+            Section::Processes => self.processes,
+            Section::Network => self.network,
+            Section::DiskIo => self.disk_io,
+            Section::Alerts => self.alerts,
+            Section::Disks => self.disks,
+            Section::Temperatures => self.temperatures,
+        }
+    }
+
+    pub fn toggle(&mut self, section: Section) {
+        let flag = self.flag_mut(section);
+        *flag = !*flag;
+    }
+    
+    pub fn set_visible(&mut self, section: Section, visible: bool) {
+        *self.flag_mut(section) = visible;
+    }
+
+    // Grid paneli görünür mü - içindeki bölümlerden en az biri açıksa
+    pub fn panel_visible(&self, panel: Panel) -> bool {
+        match panel {
+            Panel::Cpu => self.cpu_gauges || self.cpu_chart,
+            Panel::Memory => self.memory_info || self.memory_chart,
+            Panel::Processes => self.processes,
+            Panel::Network => self.network,
+            Panel::DiskIo => self.disk_io,
+            Panel::Alerts => self.alerts,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle_and_panel_visibility() {
+        let mut panels = PanelVisibility::default();
+        assert!(Section::ALL.iter().all(|&section| panels.is_visible(section)));
+
+        // CPU paneli iki bölümü de gizlenince kaybolur
+        panels.toggle(Section::CpuChart);
+        assert!(!panels.is_visible(Section::CpuChart));
+        assert!(panels.panel_visible(Panel::Cpu));
+        panels.toggle(Section::CpuGauges);
+        assert!(!panels.panel_visible(Panel::Cpu));
+        panels.toggle(Section::CpuChart);
+        assert!(panels.panel_visible(Panel::Cpu));
+    }
+}
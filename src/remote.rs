@@ -0,0 +1,307 @@
+// remote.rs - Uzak bir makineyi SSH üzerinden izleme
+// Uzak tarafta aynı program --agent ile arayüzsüz çalışır ve her ölçümü bir JSON satırı
+// olarak (kayıt dosyasıyla aynı format) stdout'a yazar. Yerelde RemoteSource `ssh` sürecini
+// başlatır, satırları ayrı bir thread'de okuyup Sample'a çevirir ve App'e verir - arayüz
+// verinin uzaktan geldiğini bilmez. Bağlantı koparsa artan aralıklarla yeniden bağlanılır
+
+use anyhow::{anyhow, bail, Context, Result};
+use std::io::{self, BufRead, BufReader, Write};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, TryRecvError};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::source::{MetricsSource, Sample};
+
+// İlk örnek bu süre içinde gelmezse bağlantı başarısız sayılır
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(15);
+
+// Yeniden bağlanma beklemesi: 1, 2, 4 ... saniye, en fazla 30 saniye
+const RETRY_MAX_SECS: u64 = 30;
+
+// Açık bir SSH süreci ve okuyucu thread'lerinin kanalları
+struct Connection {
+    child: Child,
+    samples: Receiver<Sample>,
+    // stderr'in (veya JSON olmayan stdout satırlarının) son satırı - kopma nedenini göstermek için
+    last_message: Arc<Mutex<Option<String>>>,
+    stderr_reader: JoinHandle<()>,
+}
+
+impl Connection {
+    fn spawn(argv: &[String]) -> Result<Self> {
+        let (program, args) = argv.split_first().ok_or_else(|| anyhow!("empty remote command"))?;
+        // stdin kapalı: ssh parola sorup terminali ele geçirmesin. stderr de ekrana değil
+        // buraya akar, aksi halde TUI'nin üstüne yazılırdı
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .with_context(|| format!("Failed to start {program}"))?;
+
+        let last_message = Arc::new(Mutex::new(None));
+        let (sender, samples) = mpsc::channel();
+
+        let stdout = child.stdout.take().expect("stdout is piped");
+        let message = Arc::clone(&last_message);
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                let Ok(line) = line else { break };
+                match serde_json::from_str::<Sample>(&line) {
+                    Ok(sample) => {
+                        if sender.send(sample).is_err() {
+                            break;
+                        }
+                    }
+                    // Giriş mesajı (motd) gibi JSON olmayan satırlar atlanır
+                    Err(_) => remember(&message, &line),
+                }
+            }
+            // sender burada düşer - alıcı tarafı bağlantının koptuğunu böyle anlar
+        });
+
+        let stderr = child.stderr.take().expect("stderr is piped");
+        let message = Arc::clone(&last_message);
+        let stderr_reader = thread::spawn(move || {
+            for line in BufReader::new(stderr).lines().map_while(|line| line.ok()) {
+                remember(&message, &line);
+            }
+        });
+
+        Ok(Self { child, samples, last_message, stderr_reader })
+    }
+
+    // Süreci sonlandır - zaten kapanmışsa hata önemsiz
+    // stderr okuyucusu da beklenir ki sürecin son mesajı kaçmasın
+    fn close(mut self) -> Option<String> {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        let _ = self.stderr_reader.join();
+        self.last_message.lock().ok().and_then(|message| message.clone())
+    }
+}
+
+fn remember(message: &Mutex<Option<String>>, line: &str) {
+    let line = line.trim();
+    if !line.is_empty() {
+        if let Ok(mut message) = message.lock() {
+            *message = Some(line.to_string());
+        }
+    }
+}
+
+// Bağlantı yokken tutulan yeniden deneme durumu
+struct Reconnect {
+    attempt: u32,
+    next_try: Instant,
+    reason: String,
+}
+
+// Uzak makineden örnek alan kaynak
+pub struct RemoteSource {
+    target: String,
+    argv: Vec<String>,
+    connection: Option<Connection>,
+    reconnect: Option<Reconnect>,
+    // Bağlanırken beklenen ilk örnek - App açılışta boş bir örnekle başlamasın
+    pending: Option<Sample>,
+}
+
+impl RemoteSource {
+    // `ssh` ile bağlan ve ilk örneği bekle - başarısızsa arayüz açılmadan hata verilir
+    pub fn connect(target: &str, remote_command: &str) -> Result<Self> {
+        Self::connect_with(target, ssh_argv(target, remote_command))
+    }
+
+    fn connect_with(target: &str, argv: Vec<String>) -> Result<Self> {
+        let connection = Connection::spawn(&argv)?;
+        let pending = match connection.samples.recv_timeout(CONNECT_TIMEOUT) {
+            Ok(sample) => sample,
+            Err(err) => {
+                let message = connection.close();
+                let reason = match err {
+                    RecvTimeoutError::Timeout => "no data received".to_string(),
+                    RecvTimeoutError::Disconnected => message.unwrap_or_else(|| "connection closed".to_string()),
+                };
+                bail!("Could not monitor {target}: {reason}");
+            }
+        };
+        Ok(Self {
+            target: target.to_string(),
+            argv,
+            connection: Some(connection),
+            reconnect: None,
+            pending: Some(pending),
+        })
+    }
+
+    // Bağlantı koptu - süreci kapat ve ilk denemeyi planla
+    fn disconnected(&mut self, connection: Connection) {
+        let reason = connection.close().unwrap_or_else(|| "connection closed".to_string());
+        self.reconnect = Some(Reconnect {
+            attempt: 0,
+            next_try: Instant::now() + retry_delay(0),
+            reason,
+        });
+    }
+
+    // Zamanı geldiyse yeniden bağlanmayı dene - başarısızsa bekleme süresi artar
+    fn try_reconnect(&mut self) {
+        let Some(reconnect) = &mut self.reconnect else {
+            return;
+        };
+        if Instant::now() < reconnect.next_try {
+            return;
+        }
+        match Connection::spawn(&self.argv) {
+            Ok(connection) => self.connection = Some(connection),
+            Err(err) => {
+                reconnect.attempt += 1;
+                reconnect.next_try = Instant::now() + retry_delay(reconnect.attempt);
+                reconnect.reason = err.to_string();
+            }
+        }
+    }
+}
+
+impl MetricsSource for RemoteSource {
+    // Birikmiş örneklerden en yenisi - arada kalanlar atlanır, hızlar kümülatif sayaçlardan
+    // hesaplandığı için kayıp olmaz (replay ile aynı mantık)
+    fn next_sample(&mut self) -> Result<Option<Sample>> {
+        let mut latest = self.pending.take();
+        if self.connection.is_none() {
+            self.try_reconnect();
+        }
+        if let Some(connection) = &self.connection {
+            loop {
+                match connection.samples.try_recv() {
+                    Ok(sample) => {
+                        // Yeniden bağlanma ancak gerçekten veri gelince tamamlanmış sayılır
+                        self.reconnect = None;
+                        latest = Some(sample);
+                    }
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => {
+                        let connection = self.connection.take().expect("connection checked above");
+                        match &mut self.reconnect {
+                            // Yeniden bağlanma denemesi de veri gelmeden kapandı
+                            Some(reconnect) => {
+                                reconnect.attempt += 1;
+                                reconnect.next_try = Instant::now() + retry_delay(reconnect.attempt);
+                                if let Some(reason) = connection.close() {
+                                    reconnect.reason = reason;
+                                }
+                            }
+                            None => self.disconnected(connection),
+                        }
+                        break;
+                    }
+                }
+            }
+        }
+        Ok(latest)
+    }
+
+    fn describe(&self) -> Option<String> {
+        Some(format!("Remote {}", self.target))
+    }
+
+    fn connection_warning(&self) -> Option<String> {
+        let reconnect = self.reconnect.as_ref()?;
+        let status = if self.connection.is_some() {
+            "reconnecting...".to_string()
+        } else {
+            let wait = reconnect.next_try.saturating_duration_since(Instant::now()).as_secs() + 1;
+            format!("reconnecting in {wait}s (attempt {})", reconnect.attempt + 1)
+        };
+        Some(format!("Connection to {} lost: {} - {}", self.target, reconnect.reason, status))
+    }
+}
+
+// Bağlantı kesilince ssh'in hemen fark etmesi için keepalive - aksi halde ölü bir TCP
+// bağlantısı dakikalarca açık görünür. BatchMode: parola sorulamaz, anahtar gerekir
+fn ssh_argv(target: &str, remote_command: &str) -> Vec<String> {
+    [
+        "ssh",
+        "-o", "BatchMode=yes",
+        "-o", "ServerAliveInterval=5",
+        "-o", "ServerAliveCountMax=3",
+        target,
+        remote_command,
+    ]
+    .iter()
+    .map(|arg| arg.to_string())
+    .collect()
+}
+
+fn retry_delay(attempt: u32) -> Duration {
+    Duration::from_secs(2u64.saturating_pow(attempt).min(RETRY_MAX_SECS))
+}
+
+// Uzak taraf: her aralıkta bir örneği JSON satırı olarak stdout'a yaz
+// ssh kapandığında stdout kırık boru hatası verir - bu normal bir çıkıştır
+pub async fn run_agent(source: &mut dyn MetricsSource, interval: Duration) -> Result<()> {
+    let stdout = io::stdout();
+    loop {
+        if let Some(sample) = source.next_sample()? {
+            let mut out = stdout.lock();
+            let result = serde_json::to_writer(&mut out, &sample)
+                .map_err(io::Error::from)
+                .and_then(|_| out.write_all(b"\n"))
+                .and_then(|_| out.flush());
+            match result {
+                Err(err) if err.kind() == io::ErrorKind::BrokenPipe => return Ok(()),
+                result => result?,
+            }
+        }
+        tokio::time::sleep(interval).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shell(script: &str) -> Vec<String> {
+        vec!["sh".to_string(), "-c".to_string(), script.to_string()]
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        assert_eq!(retry_delay(0), Duration::from_secs(1));
+        assert_eq!(retry_delay(3), Duration::from_secs(8));
+        assert_eq!(retry_delay(10), Duration::from_secs(RETRY_MAX_SECS));
+        assert_eq!(retry_delay(u32::MAX), Duration::from_secs(RETRY_MAX_SECS));
+    }
+
+    #[test]
+    fn test_remote_source_reports_lost_connection() {
+        let line = serde_json::to_string(&Sample { uptime: 42, ..Default::default() }).unwrap();
+        // Kısa bekleme: süreç ilk örnek okunmadan kapanırsa kopma ilk çağrıda görünürdü
+        let script = format!("echo 'Welcome'; echo '{line}'; sleep 0.5; echo 'Connection reset' >&2");
+        let mut source = RemoteSource::connect_with("host", shell(&script)).unwrap();
+
+        // Bağlanırken alınan ilk örnek
+        assert_eq!(source.next_sample().unwrap().unwrap().uptime, 42);
+        assert!(source.connection_warning().is_none());
+
+        // Süreç çıkınca kopma fark edilir ve sebep gösterilir
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while source.connection_warning().is_none() && Instant::now() < deadline {
+            assert!(source.next_sample().unwrap().is_none());
+            thread::sleep(Duration::from_millis(20));
+        }
+        let warning = source.connection_warning().unwrap();
+        assert!(warning.starts_with("Connection to host lost: Connection reset"), "{warning}");
+        assert!(warning.contains("reconnecting"));
+    }
+
+    #[test]
+    fn test_remote_source_connect_failure() {
+        let error = RemoteSource::connect_with("host", shell("echo 'Permission denied' >&2")).err().unwrap();
+        assert_eq!(error.to_string(), "Could not monitor host: Permission denied");
+    }
+}
@@ -0,0 +1,158 @@
+// replay.rs - Bir izleme oturumunu kaydetme ve tekrar oynatma
+// Kayıt dosyası her satırı bir Sample olan JSON Lines formatındadır.
+// --record canlı kaynağı sarıp her örneği dosyaya yazar, --replay ise dosyadaki
+// örnekleri orijinal zamanlamayla (veya bir hız çarpanıyla) App'e geri verir
+
+use anyhow::{bail, Context, Result};
+use std::collections::VecDeque;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use sysinfo::Signal;
+
+use crate::schedule::Due;
+use crate::source::{MetricsSource, Sample};
+
+// Başka bir kaynağın örneklerini dosyaya yazan sarmalayıcı
+// Her örnekten sonra flush ediyoruz - uygulama beklenmedik kapansa bile kayıt kullanılabilir kalır
+pub struct RecordingSource {
+    inner: Box<dyn MetricsSource>,
+    writer: BufWriter<File>,
+}
+
+impl RecordingSource {
+    pub fn create(path: &Path, inner: Box<dyn MetricsSource>) -> Result<Self> {
+        let file = File::create(path)
+            .with_context(|| format!("Failed to create recording {}", path.display()))?;
+        Ok(Self {
+            inner,
+            writer: BufWriter::new(file),
+        })
+    }
+}
+
+impl MetricsSource for RecordingSource {
+    fn next_sample(&mut self) -> Result<Option<Sample>> {
+        self.next_sample_for(Due::ALL)
+    }
+
+    // Kaydedilen örnekte zamanı gelmeyen kategoriler kaynağın son değerleridir
+    fn next_sample_for(&mut self, due: Due) -> Result<Option<Sample>> {
+        let sample = self.inner.next_sample_for(due)?;
+        if let Some(sample) = &sample {
+            serde_json::to_writer(&mut self.writer, sample)?;
+            self.writer.write_all(b"\n")?;
+            self.writer.flush()?;
+        }
+        Ok(sample)
+    }
+
+    fn kill(&mut self, pid: u32, signal: Signal) -> Result<(), &'static str> {
+        self.inner.kill(pid, signal)
+    }
+
+    fn describe(&self) -> Option<String> {
+        Some(match self.inner.describe() {
+            Some(inner) => format!("{} | REC", inner),
+            None => "REC".to_string(),
+        })
+    }
+
+    fn connection_warning(&self) -> Option<String> {
+        self.inner.connection_warning()
+    }
+
+    fn observes_self(&self) -> bool {
+        self.inner.observes_self()
+    }
+
+    fn min_cpu_interval(&self) -> Option<Duration> {
+        self.inner.min_cpu_interval()
+    }
+}
+
+// Kayıt dosyasındaki örnekleri oynatan kaynak
+// Her örnek, ilk örneğe göre zaman farkı hız çarpanına bölündüğünde geldiği anda verilir.
+// App kayıttan daha seyrek güncelleniyorsa aradaki örnekler atlanır ve en güncel olan verilir -
+// hızlar kümülatif sayaçlardan hesaplandığı için bu bir veri kaybına yol açmaz
+pub struct ReplaySource {
+    samples: VecDeque<Sample>,
+    speed: f64,
+    started: Instant,
+    first: Option<chrono::DateTime<chrono::Local>>,
+}
+
+impl ReplaySource {
+    // Kaydı baştan sona okuyup doğrula - bozuk satır varsa arayüz açılmadan hata verilir
+    pub fn open(path: &Path, speed: f64) -> Result<Self> {
+        if !(speed > 0.0 && speed.is_finite()) {
+            bail!("Replay speed must be a positive number");
+        }
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read recording {}", path.display()))?;
+        let samples = parse_recording(&contents)
+            .with_context(|| format!("Invalid recording {}", path.display()))?;
+        Ok(Self {
+            first: samples.front().map(|sample| sample.timestamp),
+            samples,
+            speed,
+            started: Instant::now(),
+        })
+    }
+}
+
+// JSON Lines içeriğini örneklere çevir - boş satırlar atlanır
+fn parse_recording(contents: &str) -> Result<VecDeque<Sample>> {
+    let samples = contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line).with_context(|| format!("line {}", index + 1))
+        })
+        .collect::<Result<VecDeque<Sample>>>()?;
+    if samples.is_empty() {
+        bail!("recording contains no samples");
+    }
+    Ok(samples)
+}
+
+impl MetricsSource for ReplaySource {
+    fn next_sample(&mut self) -> Result<Option<Sample>> {
+        let Some(first) = self.first else {
+            return Ok(None);
+        };
+        let elapsed_ms = self.started.elapsed().as_secs_f64() * 1000.0 * self.speed;
+        let mut due = None;
+        while let Some(next) = self.samples.front() {
+            let offset_ms = (next.timestamp - first).num_milliseconds() as f64;
+            if offset_ms > elapsed_ms {
+                break;
+            }
+            due = self.samples.pop_front();
+        }
+        Ok(due)
+    }
+
+    fn describe(&self) -> Option<String> {
+        let status = if self.samples.is_empty() { " (ended)" } else { "" };
+        Some(format!("Replay {}x{}", self.speed, status))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_recording() {
+        let line = serde_json::to_string(&Sample::default()).unwrap();
+        let samples = parse_recording(&format!("{line}\n\n{line}\n")).unwrap();
+        assert_eq!(samples.len(), 2);
+
+        assert!(parse_recording("").is_err());
+        let error = parse_recording(&format!("{line}\nnot json\n")).unwrap_err();
+        assert!(format!("{error:#}").contains("line 2"));
+    }
+}
@@ -0,0 +1,162 @@
+// schedule.rs - Veri kategorilerinin kendi yenileme aralıkları
+// Her şeyi aynı hızda okumak gerekmez: CPU'yu 200 ms'de bir izleyen biri process listesini
+// 2 saniyede bir tazelemekle yetinebilir - process taraması en pahalı okumadır.
+// Config'deki [refresh] tablosu kategori başına aralık verir; 0 ana yenileme aralığını izler.
+// Zamanı gelmeyen kategoriler kaynakta tazelenmez, App önceki değerleri taşır
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Local};
+use serde::Deserialize;
+use std::time::{Duration, Instant};
+use sysinfo::{System, SystemExt};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    Cpu,
+    Memory,      // RAM, swap ve swap sayaçları
+    Network,     // Ağ sayaçları, arayüzler ve bağlantılar
+    Disk,        // Disk doluluğu, disk I/O ve yeniden başlatma işareti
+    Temperature, // Sensörler, fanlar, piller ve GPU'lar
+    Processes,
+}
+
+impl Category {
+    pub const ALL: [Category; 6] = [
+        Category::Cpu,
+        Category::Memory,
+        Category::Network,
+        Category::Disk,
+        Category::Temperature,
+        Category::Processes,
+    ];
+}
+
+// [refresh] tablosu - kategori başına yenileme aralığı (ms), 0 = her yenilemede
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct RefreshIntervals {
+    pub cpu_ms: u64,
+    pub mem_ms: u64,
+    pub net_ms: u64,
+    pub disk_ms: u64,
+    pub temp_ms: u64,
+    pub proc_ms: u64,
+}
+
+impl RefreshIntervals {
+    pub fn get(&self, category: Category) -> u64 {
+        match category {
+            Category::Cpu => self.cpu_ms,
+            Category::Memory => self.mem_ms,
+            Category::Network => self.net_ms,
+            Category::Disk => self.disk_ms,
+            Category::Temperature => self.temp_ms,
+            Category::Processes => self.proc_ms,
+        }
+    }
+
+    // CPU yüzdeleri iki okuma arasındaki farktan hesaplanır; sysinfo'nun alt sınırından
+    // sık okunursa değerler 0 veya gürültü olur - böyle bir ayar sessizce yükseltilmez, hatadır
+    pub fn validate(&self) -> Result<()> {
+        let minimum = System::MINIMUM_CPU_UPDATE_INTERVAL.as_millis() as u64;
+        if self.cpu_ms != 0 && self.cpu_ms < minimum {
+            bail!("refresh.cpu_ms must be 0 or at least {minimum} ms, got {}", self.cpu_ms);
+        }
+        Ok(())
+    }
+}
+
+// Bu turda tazelenecek kategoriler
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Due([bool; 6]);
+
+impl Due {
+    pub const ALL: Due = Due([true; 6]);
+
+    pub fn contains(&self, category: Category) -> bool {
+        self.0[category as usize]
+    }
+}
+
+// Her kategorinin en son ne zaman tazelendiğini tutar
+// Karar duvar saatiyle (Instant) verilir; hız hesabı ise örneklerin kendi zaman damgalarıyla
+// yapılır, çünkü oynatmada kaydın zamanı geçerlidir
+pub struct RefreshSchedule {
+    intervals: RefreshIntervals,
+    last: [Option<Instant>; 6],
+    stamps: [Option<DateTime<Local>>; 6],
+}
+
+impl RefreshSchedule {
+    pub fn new(intervals: RefreshIntervals) -> Self {
+        Self {
+            intervals,
+            last: [None; 6],
+            stamps: [None; 6],
+        }
+    }
+
+    // Aralığı dolan (veya hiç okunmamış) kategoriler
+    // Ana yenilemeden kısa aralıklar her turda dolar - kategori ana hızdan sık okunamaz
+    pub fn due(&self, now: Instant) -> Due {
+        Due(Category::ALL.map(|category| {
+            let interval = Duration::from_millis(self.intervals.get(category));
+            self.last[category as usize].is_none_or(|last| now.duration_since(last) >= interval)
+        }))
+    }
+
+    // Örnek alındıktan sonra tazelenen kategorilerin zamanını kaydet
+    pub fn mark(&mut self, due: Due, now: Instant, timestamp: DateTime<Local>) {
+        for category in Category::ALL {
+            if due.contains(category) {
+                self.last[category as usize] = Some(now);
+                self.stamps[category as usize] = Some(timestamp);
+            }
+        }
+    }
+
+    // Kategorinin önceki tazelenmesinden bu örneğe kadar geçen süre (saniye)
+    // Tick gecikebilir veya kullanıcı girdisi yüzünden kayabilir; sayaç farkı bu süreye bölünür
+    pub fn elapsed_secs(&self, category: Category, timestamp: DateTime<Local>) -> f64 {
+        self.stamps[category as usize]
+            .map_or(0.0, |previous| (timestamp - previous).num_milliseconds().max(0) as f64 / 1000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_categories_fire_on_their_own_timers() {
+        let intervals = RefreshIntervals { proc_ms: 2_000, net_ms: 500, ..Default::default() };
+        let mut schedule = RefreshSchedule::new(intervals);
+        let start = Instant::now();
+        let stamp = Local::now();
+        assert_eq!(schedule.due(start), Due::ALL);
+        schedule.mark(Due::ALL, start, stamp);
+
+        let tick = start + Duration::from_millis(100);
+        let due = schedule.due(tick);
+        assert!(due.contains(Category::Cpu) && due.contains(Category::Memory));
+        assert!(!due.contains(Category::Network) && !due.contains(Category::Processes));
+        schedule.mark(due, tick, stamp + chrono::Duration::milliseconds(100));
+
+        // Ağ kendi aralığı dolunca tazelenir; hız tüm aralık üzerinden hesaplanır
+        let later = start + Duration::from_millis(600);
+        let due = schedule.due(later);
+        assert!(due.contains(Category::Network) && !due.contains(Category::Processes));
+        assert_eq!(schedule.elapsed_secs(Category::Network, stamp + chrono::Duration::milliseconds(600)), 0.6);
+        assert_eq!(schedule.elapsed_secs(Category::Cpu, stamp + chrono::Duration::milliseconds(600)), 0.5);
+    }
+
+    #[test]
+    fn test_cpu_interval_below_sysinfo_minimum_rejected() {
+        assert!(RefreshIntervals::default().validate().is_ok());
+        assert!(RefreshIntervals { cpu_ms: 1_000, ..Default::default() }.validate().is_ok());
+        let minimum = System::MINIMUM_CPU_UPDATE_INTERVAL.as_millis() as u64;
+        if minimum > 1 {
+            assert!(RefreshIntervals { cpu_ms: minimum - 1, ..Default::default() }.validate().is_err());
+        }
+    }
+}
@@ -0,0 +1,51 @@
+// snapshot.rs - Bir güncellemede toplanan metriklerin serileştirilebilir kopyası
+// App her güncellemeden sonra bir Snapshot üretir. UI dışındaki tüketiciler
+// (Prometheus endpoint'i gibi) sysinfo'yu ikinci kez sorgulamak yerine bunu okur.
+// Referans olarak saklanan bir snapshot, sonradan şimdiki durumla karşılaştırılabilir (diff.rs)
+
+use chrono::{DateTime, Local};
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+
+use crate::system_info::{DiskInfo, TemperatureInfo};
+
+// Tek bir andaki tüm metrikler
+#[derive(Debug, Clone, Serialize)]
+pub struct Snapshot {
+    pub timestamp: DateTime<Local>,
+    pub cpu_usage: Vec<f32>, // Çekirdek başına kullanım (%)
+    pub cpu_ids: Vec<usize>, // cpu_usage ile aynı sırada gerçek CPU numaraları
+    pub cpu_average: f32,
+    pub memory_used: u64,    // byte
+    pub memory_total: u64,
+    pub swap_used: u64,
+    pub swap_total: u64,
+    pub network_rx_rate: u64, // byte/s
+    pub network_tx_rate: u64,
+    pub network_received: u64, // Kümülatif, tüm arayüzlerin toplamı (byte)
+    pub network_transmitted: u64,
+    pub disk_read_rate: u64,  // byte/s
+    pub disk_write_rate: u64,
+    pub disks: Vec<DiskInfo>,
+    pub temperatures: Vec<TemperatureInfo>,
+    pub processes: Vec<ProcessSnapshot>,
+}
+
+// Process'in karşılaştırma için gereken özeti
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProcessSnapshot {
+    pub pid: u32,
+    pub name: String,
+    pub cpu_usage: f32,
+    pub memory: u64, // byte
+}
+
+// Thread'ler ve task'ler arasında paylaşılan son snapshot
+pub type SharedSnapshot = Arc<Mutex<Snapshot>>;
+
+impl Snapshot {
+    // Sıradaki çekirdeğin etiketlerde kullanılan CPU numarası
+    pub fn cpu_id(&self, index: usize) -> usize {
+        self.cpu_ids.get(index).copied().unwrap_or(index)
+    }
+}
@@ -0,0 +1,469 @@
+// source.rs - App'in metrikleri nereden aldığını soyutlayan katman
+// App sysinfo'yu doğrudan sorgulamak yerine bir MetricsSource'tan Sample ister.
+// Canlı sistem (LiveSource), kayıttan oynatma (replay.rs) ve uzak makine (remote.rs) aynı arayüzü uygular,
+// böylece arayüz ve hesaplama mantığı veri nereden gelirse gelsin aynı çalışır
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+use sysinfo::{CpuExt, NetworkExt, Pid, PidExt, ProcessExt, ProcessRefreshKind, Signal, System, SystemExt};
+
+use crate::battery::{BatteryCollector, BatteryInfo};
+use crate::connections::{self, ConnectionCounts};
+use crate::container;
+use crate::cpu_times::{CpuBreakdown, CpuTimesCollector};
+use crate::fans::{self, FanInfo};
+use crate::gpu::{GpuCollector, GpuInfo};
+use crate::hotplug;
+use crate::numa::{self, NumaNode};
+use crate::schedule::{Category, Due};
+use crate::system_info::{self, DiskInfo, TemperatureInfo};
+use crate::talkers::{self, ProcessConnections};
+use crate::vmstat::{self, SwapCounters};
+use crate::zram::{self, ZramStats};
+
+// Makine hakkındaki, çalışma boyunca pek değişmeyen bilgiler
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HostInfo {
+    pub host_name: Option<String>,
+    pub os_name: Option<String>,
+    pub os_version: Option<String>,
+    pub kernel_version: Option<String>,
+    pub boot_time: u64, // Epoch saniyesi
+    pub physical_core_count: Option<usize>,
+    pub cpu_brand: String, // Kısaltılmış işlemci model adı
+    // Güncelleme sonrası yeniden başlatma bekleniyor mu - eski kayıtlarda alan yok
+    #[serde(default)]
+    pub reboot_pending: bool,
+    // NUMA düğümleri - tek düğümlü makinelerde ve Linux dışında boş
+    #[serde(default)]
+    pub numa_nodes: Vec<NumaNode>,
+    // Konteyner içinde çalışılıyorsa türü (Docker, Kubernetes...) - boş sensör/disk listelerini açıklar
+    #[serde(default)]
+    pub container: Option<String>,
+}
+
+// Tek bir process'in ölçüm anındaki durumu
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProcessSample {
+    pub pid: u32,
+    pub parent_pid: Option<u32>,
+    pub name: String,
+    pub status: String,
+    pub cpu_usage: f32,
+    pub memory: u64,         // byte
+    pub virtual_memory: u64, // byte
+    pub start_time: u64,     // Epoch saniyesi
+    pub exe: String,
+    pub cmd: Vec<String>,
+    // Process başladığından beri diskten okunan/diske yazılan toplam byte
+    #[serde(default)]
+    pub disk_read: u64,
+    #[serde(default)]
+    pub disk_written: u64,
+}
+
+// Tek bir ağ arayüzünün kümülatif sayaçları - arayüz açıldığından beri, `ip -s link` ile aynı
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InterfaceCounters {
+    pub name: String,
+    pub received: u64,    // byte
+    pub transmitted: u64, // byte
+}
+
+// Bir güncellemede toplanan ham ölçümler - App'in okuduğu her şey burada
+// Sayaçlar (ağ, disk I/O) kümülatiftir; hızları App iki örnek arasındaki farktan hesaplar
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Sample {
+    pub timestamp: DateTime<Local>,
+    pub uptime: u64, // saniye
+    pub host: HostInfo,
+    pub cpu_usage: Vec<f32>,       // Çekirdek başına (%)
+    pub cpu_frequencies: Vec<u64>, // Çekirdek başına (MHz)
+    // cpu_usage ile aynı sırada gerçek CPU numaraları - kapalı çekirdekler sırayı kaydırabilir
+    // Boşsa (eski kayıtlar) sıra numarası CPU numarası kabul edilir
+    #[serde(default)]
+    pub cpu_ids: Vec<usize>,
+    pub cpu_breakdown: Option<CpuBreakdown>,
+    pub memory_used: u64,
+    pub memory_total: u64,
+    // Geri kazanılabilir önbellek dahil, yeni işlere verilebilecek bellek - eski kayıtlarda alan yok
+    #[serde(default)]
+    pub memory_available: u64,
+    pub swap_used: u64,
+    pub swap_total: u64,
+    pub network_received: u64,    // Tüm arayüzlerin toplamı (byte)
+    pub network_transmitted: u64,
+    // Arayüz başına sayaçlar, ada göre sıralı - eski kayıtlarda alan yok
+    #[serde(default)]
+    pub interfaces: Vec<InterfaceCounters>,
+    pub disk_read: u64,           // Tüm process'lerin toplamı (byte)
+    pub disk_written: u64,
+    pub processes: Vec<ProcessSample>,
+    pub disks: Vec<DiskInfo>,
+    pub temperatures: Vec<TemperatureInfo>,
+    // hwmon fan sensörleri - sadece Linux'ta, eski kayıtlarda alan yok
+    #[serde(default)]
+    pub fans: Vec<FanInfo>,
+    pub batteries: Vec<BatteryInfo>,
+    pub gpus: Vec<GpuInfo>,
+    // Sadece Linux'ta - yoksa bağlantı paneli gizlenir
+    #[serde(default)]
+    pub connections: Option<ConnectionCounts>,
+    // Sadece Linux'ta - yoksa swap hızları gösterilmez
+    #[serde(default)]
+    pub swap_counters: Option<SwapCounters>,
+    // Sadece Linux'ta ve zram kuruluysa - yoksa bellek panelinde zram satırı gösterilmez
+    #[serde(default)]
+    pub zram: Option<ZramStats>,
+    // Sadece Linux'ta ve net_talkers özelliği açıkken - yoksa liste gizlenir
+    #[serde(default)]
+    pub process_connections: Option<Vec<ProcessConnections>>,
+}
+
+// Metrik kaynağı - App her güncellemede buradan yeni bir örnek ister
+pub trait MetricsSource {
+    // Yeni örnek - henüz yeni veri yoksa (veya kayıt bittiyse) None
+    fn next_sample(&mut self) -> Result<Option<Sample>>;
+
+    // Sadece zamanı gelen kategorileri tazeleyen örnek - kaynak pahalı okumaları atlayabilir
+    // Diğer kategoriler için ne döndüğü önemli değildir, App önceki değerleri taşır
+    fn next_sample_for(&mut self, _due: Due) -> Result<Option<Sample>> {
+        self.next_sample()
+    }
+
+    // Process'e sinyal gönder - sadece canlı kaynak gerçek process'lere ulaşabilir
+    fn kill(&mut self, _pid: u32, _signal: Signal) -> Result<(), &'static str> {
+        Err("not available for this data source")
+    }
+
+    // Başlıkta gösterilecek kısa açıklama (örn. "Replay 2x") - canlı kaynakta yok
+    fn describe(&self) -> Option<String> {
+        None
+    }
+
+    // Kaynağa ulaşılamıyorsa başlıkta gösterilecek uyarı (örn. uzak bağlantı koptu)
+    fn connection_warning(&self) -> Option<String> {
+        None
+    }
+
+    // Örnekler bu process'in çalıştığı makineden mi geliyor - kendi PID'imiz sadece o zaman anlamlı
+    fn observes_self(&self) -> bool {
+        false
+    }
+
+    // CPU yüzdelerinin anlamlı olması için iki CPU okuması arasında gereken en kısa süre
+    // Yenileme bundan sık ise kaynak CPU'yu arada bir okur, diğer metrikler her turda tazelenir
+    fn min_cpu_interval(&self) -> Option<Duration> {
+        None
+    }
+}
+
+// Canlı sistemden okuyan kaynak - sysinfo ve platforma özel collector'ları sarar
+pub struct LiveSource {
+    system: System,
+    cpu_times: CpuTimesCollector,
+    battery: BatteryCollector,
+    gpu: GpuCollector,
+    numa_nodes: Vec<NumaNode>, // Topoloji çalışma boyunca değişmez, bir kez okunur
+    container: Option<String>, // Çalışma ortamı da değişmez
+    last_cpu_refresh: Option<Instant>,
+    last_process_cpu_refresh: Option<Instant>, // Process'lerin CPU payıyla birlikte son taranması
+    // Her process'in fd dizinini gezmek pahalıdır - ağ zamanı gelmediyse önceki liste kullanılır
+    process_connections: Option<Vec<ProcessConnections>>,
+}
+
+impl LiveSource {
+    // System::new_all() tüm bilgileri ilk kez doldurur
+    pub fn new() -> Self {
+        Self {
+            system: System::new_all(),
+            cpu_times: CpuTimesCollector::new(),
+            battery: BatteryCollector::new(),
+            gpu: GpuCollector::new(),
+            numa_nodes: numa::read_numa_nodes(),
+            container: container::detect_container(),
+            last_cpu_refresh: None,
+            last_process_cpu_refresh: None,
+            process_connections: None,
+        }
+    }
+
+    // Yeniden başlatma işareti diskteki bir dosyadır - disk zamanı gelmediyse okunmaz
+    fn host_info(&self, due: Due) -> HostInfo {
+        HostInfo {
+            host_name: self.system.host_name(),
+            os_name: self.system.name(),
+            os_version: self.system.os_version(),
+            kernel_version: self.system.kernel_version(),
+            boot_time: self.system.boot_time(),
+            physical_core_count: self.system.physical_core_count(),
+            // İşlemci model adı - ilk çekirdekten okunur, tüm çekirdekler aynıdır
+            cpu_brand: system_info::shorten_cpu_brand(
+                self.system.cpus().first().map(|cpu| cpu.brand()).unwrap_or_default(),
+            ),
+            reboot_pending: due.contains(Category::Disk) && system_info::reboot_pending(),
+            numa_nodes: self.numa_nodes.clone(),
+            container: self.container.clone(),
+        }
+    }
+}
+
+impl MetricsSource for LiveSource {
+    fn next_sample(&mut self) -> Result<Option<Sample>> {
+        self.next_sample_for(Due::ALL)
+    }
+
+    fn next_sample_for(&mut self, due: Due) -> Result<Option<Sample>> {
+        // Sistem verilerini yenile - sadece zamanı gelen kategoriler, sysinfo diğerlerinin
+        // son değerlerini tutar. Bazı kısıtlı ortamlarda sysinfo yenileme sırasında panic edebilir -
+        // uygulamayı düşürmek yerine hata döndürülür, App son iyi değerleri göstermeye devam eder
+        // sysinfo CPU kullanımını iki okuma arasındaki farktan hesaplar; okumalar
+        // MINIMUM_CPU_UPDATE_INTERVAL'dan sık olursa yüzdeler 0 veya gürültü olur. O turlarda CPU
+        // (process'lerin CPU payı dahil) atlanır ve önceki değerler gösterilmeye devam eder
+        let now = Instant::now();
+        let cpu_due = due.contains(Category::Cpu)
+            && self
+                .last_cpu_refresh
+                .is_none_or(|last| now.duration_since(last) >= System::MINIMUM_CPU_UPDATE_INTERVAL);
+        // Disk I/O process sayaçlarının toplamıdır, o yüzden disk zamanı da process taraması ister
+        let processes_due = due.contains(Category::Processes) || due.contains(Category::Disk);
+        // sysinfo process CPU payını son CPU okumasından bu yana geçen toplam CPU zamanına böler.
+        // Process'ler CPU'dan seyrek taranıyorsa process'in süresi daha uzun bir aralığı kapsar -
+        // pay, iki aralığın oranıyla düzeltilir
+        let process_cpu_scale = match (self.last_cpu_refresh, self.last_process_cpu_refresh) {
+            (Some(cpu), Some(process)) if cpu_due && processes_due => {
+                now.duration_since(cpu).as_secs_f32() / now.duration_since(process).as_secs_f32().max(f32::EPSILON)
+            }
+            _ => 1.0,
+        };
+        let system = &mut self.system;
+        panic::catch_unwind(AssertUnwindSafe(|| {
+            if cpu_due {
+                system.refresh_cpu();
+            }
+            if due.contains(Category::Memory) {
+                system.refresh_memory();
+            }
+            if due.contains(Category::Temperature) {
+                system.refresh_components();
+            }
+            if processes_due {
+                let kind = ProcessRefreshKind::everything();
+                system.refresh_processes_specifics(if cpu_due { kind } else { kind.without_cpu() });
+            }
+            if due.contains(Category::Disk) {
+                system.refresh_disks();
+            }
+            if due.contains(Category::Network) {
+                system.refresh_networks();
+            }
+        }))
+        .map_err(|_| anyhow!("system refresh failed"))?;
+        if cpu_due {
+            self.last_cpu_refresh = Some(now);
+        }
+        if cpu_due && processes_due {
+            self.last_process_cpu_refresh = Some(now);
+        }
+        if due.contains(Category::Network) {
+            self.process_connections = talkers::read_process_connections();
+        }
+        let system = &self.system;
+
+        let interfaces = network_interfaces(system);
+        let network_received = interfaces.iter().map(|interface| interface.received).sum();
+        let network_transmitted = interfaces.iter().map(|interface| interface.transmitted).sum();
+
+        // sysinfo sistem geneli disk I/O vermez, bu yüzden process'lerin kümülatif
+        // sayaçlarını topluyoruz. Process'ler kapandığında toplam düşebilir - bu durumu
+        // App'teki hız hesabı sıfır hız olarak ele alır
+        let (disk_read, disk_written) = system
+            .processes()
+            .values()
+            .map(|p| p.disk_usage())
+            .fold((0u64, 0u64), |(read, written), usage| {
+                (read + usage.total_read_bytes, written + usage.total_written_bytes)
+            });
+
+        let processes = system
+            .processes()
+            .iter()
+            .map(|(pid, process)| ProcessSample {
+                pid: pid.as_u32(),
+                parent_pid: process.parent().map(|parent| parent.as_u32()),
+                name: process.name().to_string(),
+                status: process.status().to_string(),
+                cpu_usage: process.cpu_usage() * process_cpu_scale,
+                memory: process_memory_bytes(process.memory()),
+                virtual_memory: process_memory_bytes(process.virtual_memory()),
+                start_time: process.start_time(),
+                exe: process.exe().to_string_lossy().to_string(),
+                cmd: process.cmd().to_vec(),
+                disk_read: process.disk_usage().total_read_bytes,
+                disk_written: process.disk_usage().total_written_bytes,
+            })
+            .collect();
+
+        let (cpu_ids, cpu_usage, cpu_frequencies) = online_cpus(system);
+        // sysinfo dışındaki okumalar da sadece zamanı geldiğinde yapılır - boş kalan alanları
+        // App'teki carry_over önceki örnekten doldurur
+        let sensors_due = due.contains(Category::Temperature);
+        let memory_due = due.contains(Category::Memory);
+        let network_due = due.contains(Category::Network);
+
+        Ok(Some(Sample {
+            timestamp: Local::now(),
+            uptime: system.uptime(),
+            host: self.host_info(due),
+            cpu_usage,
+            cpu_frequencies,
+            cpu_ids,
+            cpu_breakdown: self.cpu_times.sample(),
+            memory_used: system.used_memory(),
+            memory_total: system.total_memory(),
+            memory_available: system.available_memory(),
+            swap_used: system.used_swap(),
+            swap_total: system.total_swap(),
+            network_received,
+            network_transmitted,
+            interfaces,
+            disk_read,
+            disk_written,
+            processes,
+            disks: system_info::collect_disk_info(system),
+            temperatures: system_info::collect_temperature_info(system),
+            fans: if sensors_due { fans::read_fans() } else { Vec::new() },
+            batteries: if sensors_due { self.battery.collect() } else { Vec::new() },
+            gpus: if sensors_due { self.gpu.get_gpu_info() } else { Vec::new() },
+            connections: if network_due { connections::read_connections() } else { None },
+            swap_counters: if memory_due { vmstat::read_swap_counters() } else { None },
+            zram: if memory_due { zram::read_zram_stats() } else { None },
+            process_connections: self.process_connections.clone(),
+        }))
+    }
+
+    // Başka kullanıcının process'ine sinyal göndermek yetki hatasıyla başarısız olabilir
+    fn kill(&mut self, pid: u32, signal: Signal) -> Result<(), &'static str> {
+        match self.system.process(Pid::from_u32(pid)) {
+            Some(process) => match process.kill_with(signal) {
+                Some(true) => Ok(()),
+                Some(false) => Err("permission denied or process exited"),
+                None => Err("signal not supported on this platform"),
+            },
+            None => Err("process no longer exists"),
+        }
+    }
+
+    fn observes_self(&self) -> bool {
+        true
+    }
+
+    fn min_cpu_interval(&self) -> Option<Duration> {
+        Some(System::MINIMUM_CPU_UPDATE_INTERVAL)
+    }
+}
+
+// Önceden hazırlanmış örnekleri sırayla veren sahte kaynak - testler için
+// Donanımdan bağımsız olduğu için App'in hesaplama mantığı deterministik test edilebilir
+#[cfg(test)]
+pub struct MockSource {
+    samples: std::collections::VecDeque<Sample>,
+}
+
+#[cfg(test)]
+impl MockSource {
+    pub fn new(samples: Vec<Sample>) -> Self {
+        Self { samples: samples.into() }
+    }
+}
+
+#[cfg(test)]
+impl MetricsSource for MockSource {
+    // Örnekler bitince None - App son değerlerle kalır
+    fn next_sample(&mut self) -> Result<Option<Sample>> {
+        Ok(self.samples.pop_front())
+    }
+}
+
+// sysinfo'nun process bellek birimi (byte başına) - 0.26'dan önceki sürümler KiB döndürürdü,
+// sabitlenen 0.29 byte döndürür. Sürüm yükseltmesinde birim değişirse aşağıdaki test yakalar
+const SYSINFO_PROCESS_MEMORY_UNIT: u64 = 1;
+
+// sysinfo'nun process bellek değerini byte'a çevir - Sample'a her zaman byte girer,
+// format_bytes ve kayıtlar da byte bekler
+fn process_memory_bytes(raw: u64) -> u64 {
+    raw.saturating_mul(SYSINFO_PROCESS_MEMORY_UNIT)
+}
+
+// Çevrimiçi çekirdeklerin numaraları, kullanımları ve frekansları
+// sysinfo çekirdek listesini açılışta kurar; sonradan kapatılan bir çekirdek listede kalır.
+// Numara sysinfo'nun verdiği addan ("cpu3") okunur ve kapalı çekirdekler ayıklanır.
+// Ad okunamazsa numara listesi boş bırakılır, App sıra numarasını kullanır
+fn online_cpus(system: &System) -> (Vec<usize>, Vec<f32>, Vec<u64>) {
+    let ids: Option<Vec<usize>> = system
+        .cpus()
+        .iter()
+        .map(|cpu| cpu.name().strip_prefix("cpu")?.parse().ok())
+        .collect();
+    let Some(ids) = ids else {
+        return (
+            Vec::new(),
+            system.cpus().iter().map(|cpu| cpu.cpu_usage()).collect(),
+            system.cpus().iter().map(|cpu| cpu.frequency()).collect(),
+        );
+    };
+    let online = hotplug::read_online_cpus();
+    let mut result = (Vec::new(), Vec::new(), Vec::new());
+    for (cpu, id) in system.cpus().iter().zip(ids) {
+        if online.is_empty() || online.contains(&id) {
+            result.0.push(id);
+            result.1.push(cpu.cpu_usage());
+            result.2.push(cpu.frequency());
+        }
+    }
+    result
+}
+
+// Ağ arayüzlerinin kümülatif sayaçları - toplamları hız hesabında kullanılır
+// Modern sysinfo API'sinde networks() artık System üzerinde direkt method
+// system.networks() tüm ağ arayüzlerini döndürür (eth0, wlan0, vs.)
+fn network_interfaces(system: &System) -> Vec<InterfaceCounters> {
+    let mut interfaces: Vec<InterfaceCounters> = system
+        .networks()
+        .into_iter()
+        .map(|(name, network)| InterfaceCounters {
+            name: name.clone(),
+            received: network.total_received(),
+            transmitted: network.total_transmitted(),
+        })
+        .collect();
+    interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+    interfaces
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    // Kendi RSS'imiz çekirdeğin /proc/self/status'ta kB olarak verdiği değerle aynı büyüklükte olmalı
+    // Birim KiB'ye dönerse değer 1024 kat küçük çıkar
+    #[test]
+    fn test_process_memory_is_bytes() {
+        let pid = sysinfo::get_current_pid().unwrap();
+        let mut system = System::new();
+        system.refresh_process(pid);
+        let memory = process_memory_bytes(system.process(pid).unwrap().memory());
+
+        let status = std::fs::read_to_string("/proc/self/status").unwrap();
+        let rss_kib: u64 = status
+            .lines()
+            .find_map(|line| line.strip_prefix("VmRSS:"))
+            .and_then(|value| value.trim().trim_end_matches("kB").trim().parse().ok())
+            .unwrap();
+        let rss = rss_kib * 1024;
+        assert!(memory > rss / 2 && memory < rss * 2, "sysinfo {} byte, VmRSS {} byte", memory, rss);
+    }
+}
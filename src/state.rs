@@ -0,0 +1,100 @@
+// state.rs - Oturumlar arasında saklanan görünüm durumu
+// Uygulama düzgün kapanırken aktif sekme, sıralama, tema ve yenileme hızı
+// ~/.local/state/rust-system-monitor/state.json dosyasına yazılır ve
+// bir sonraki açılışta geri yüklenir
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::{env, fs, path::PathBuf};
+
+use crate::app::{ProcessSortKey, Tab, DEFAULT_REFRESH_MS};
+use crate::theme::ThemeName;
+
+// Saklanan görünüm durumu
+// Eksik alanlar varsayılan değerleri alır - eski state dosyaları da okunabilir
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ViewState {
+    pub active_tab: Tab,
+    pub process_sort: ProcessSortKey,
+    pub theme: ThemeName,
+    pub refresh_ms: u64,
+}
+
+impl Default for ViewState {
+    fn default() -> Self {
+        Self {
+            active_tab: Tab::default(),
+            process_sort: ProcessSortKey::default(),
+            theme: ThemeName::default(),
+            refresh_ms: DEFAULT_REFRESH_MS,
+        }
+    }
+}
+
+impl ViewState {
+    // State dosyasını oku
+    // Dosya yoksa veya bozuksa sessizce varsayılanlara döneriz - bu sadece bir kolaylık
+    pub fn load() -> Self {
+        state_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|contents| Self::from_json(&contents))
+            .unwrap_or_default()
+    }
+
+    // JSON metninden state oluştur - parse edilemezse varsayılan
+    pub fn from_json(contents: &str) -> Self {
+        serde_json::from_str(contents).unwrap_or_default()
+    }
+
+    // State'i diske yaz - klasör yoksa oluşturulur
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = state_path() else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+// State dosyasının yolu - XDG standardına uyuyoruz
+// Önce $XDG_STATE_HOME, yoksa ~/.local/state kullanılır
+pub fn state_path() -> Option<PathBuf> {
+    let base = env::var_os("XDG_STATE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".local").join("state")))?;
+    Some(base.join("rust-system-monitor").join("state.json"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let state = ViewState {
+            active_tab: Tab::System,
+            process_sort: ProcessSortKey::Memory,
+            theme: ThemeName::Ocean,
+            refresh_ms: 1000,
+        };
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(ViewState::from_json(&json), state);
+    }
+
+    #[test]
+    fn test_corrupt_state_falls_back_to_default() {
+        assert_eq!(ViewState::from_json("{ not json"), ViewState::default());
+        assert_eq!(ViewState::from_json("{\"theme\": \"no_such_theme\"}"), ViewState::default());
+    }
+
+    #[test]
+    fn test_missing_fields_use_defaults() {
+        let state = ViewState::from_json("{\"process_sort\": \"run_time\"}");
+        assert_eq!(state.process_sort, ProcessSortKey::RunTime);
+        assert_eq!(state.refresh_ms, DEFAULT_REFRESH_MS);
+    }
+}
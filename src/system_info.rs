@@ -2,10 +2,12 @@
 // Bu modül gelecekteki genişlemeler için hazırlanmış bir temel sağlar
 // Örneğin: sıcaklık sensörleri, disk bilgileri, GPU kullanımı gibi
 
-use sysinfo::{System, SystemExt, DiskExt, ComponentExt};
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use sysinfo::{System, SystemExt, DiskExt, ComponentExt, ProcessExt};
 
 // Disk kullanım bilgilerini tutan struct
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DiskInfo {
     pub name: String,           // Disk adı (örn: "/dev/sda1")
     pub mount_point: String,    // Bağlanma noktası (örn: "/", "/home")
@@ -17,7 +19,7 @@ pub struct DiskInfo {
 }
 
 // Sistem sıcaklık bilgilerini tutan struct
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TemperatureInfo {
     pub component_name: String, // Bileşen adı (CPU, GPU, vs.)
     pub current_temp: f32,      // Şu anki sıcaklık (Celsius)
@@ -25,6 +27,38 @@ pub struct TemperatureInfo {
     pub critical_temp: Option<f32>, // Kritik sıcaklık (varsa)
 }
 
+// Process'lerin durumlara göre dağılımı
+// Windows'ta sysinfo her process'i "Runnable" raporlar - o zaman sadece toplam anlamlıdır
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ProcessStates {
+    pub total: usize,
+    pub running: usize,
+    pub sleeping: usize, // Idle ve kesintisiz disk beklemesi dahil
+    pub stopped: usize,  // Tracing dahil
+    pub zombie: usize,
+    pub detailed: bool,  // Platform gerçek durum bilgisi veriyor mu
+}
+
+impl ProcessStates {
+    // sysinfo'nun ProcessStatus metinlerinden say - tanınmayanlar sadece toplama girer
+    pub fn from_statuses<'a>(statuses: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut states = ProcessStates::default();
+        for status in statuses {
+            states.total += 1;
+            match status {
+                "Runnable" | "Waking" => states.running += 1,
+                "Sleeping" | "Idle" | "UninterruptibleDiskSleep" | "Parked" | "LockBlocked" => states.sleeping += 1,
+                "Stopped" | "Tracing" => states.stopped += 1,
+                "Zombie" => states.zombie += 1,
+                _ => {}
+            }
+        }
+        // Her sistemde uyuyan process vardır; hiç yoksa durumlar gerçek değildir
+        states.detailed = states.sleeping + states.stopped + states.zombie > 0;
+        states
+    }
+}
+
 // Gelişmiş sistem bilgileri için ana struct
 pub struct SystemInfoCollector {
     system: System,
@@ -47,52 +81,18 @@ impl SystemInfoCollector {
     // Bu fonksiyon sistem üzerindeki tüm bağlı diskleri tarar
     // Modern sysinfo API'sinde disks() artık System'da instance method
     pub fn get_disk_info(&self) -> Vec<DiskInfo> {
-        self.system
-            .disks()
-            .iter()
-            .map(|disk| {
-                let total = disk.total_space();
-                let available = disk.available_space();
-                let used = total - available;
-                
-                // Kullanım yüzdesini hesapla - sıfıra bölme kontrolü önemli
-                let usage_percent = if total > 0 {
-                    (used as f64 / total as f64 * 100.0) as f32
-                } else {
-                    0.0
-                };
-                
-                DiskInfo {
-                    name: disk.name().to_string_lossy().to_string(),
-                    mount_point: disk.mount_point().to_string_lossy().to_string(),
-                    total_space: total,
-                    available_space: available,
-                    used_space: used,
-                    usage_percent,
-                    file_system: String::from_utf8_lossy(disk.file_system()).to_string(),
-                }
-            })
-            .collect()
+        collect_disk_info(&self.system)
+    }
+    
+    // Sadece gerçek depolama disklerini döndür - tmpfs, overlay gibi sahte dosya sistemleri elenir
+    pub fn get_physical_disks(&self, filter: &DiskFilter) -> Vec<DiskInfo> {
+        filter.physical(&self.get_disk_info())
     }
     
     // Sistem sıcaklık bilgilerini topla
     // Bu özellik her sistemde mevcut olmayabilir - özellikle sanal makinelerde
-    // Modern API'de components() method'u da değişmemiş
     pub fn get_temperature_info(&self) -> Vec<TemperatureInfo> {
-        self.system
-            .components()
-            .iter()
-            .map(|component| {
-                let max = component.max();
-                let critical = component.critical();
-                TemperatureInfo {
-                    component_name: component.label().to_string(),
-                    current_temp: component.temperature(),
-                    max_temp: (max > 0.0).then(|| max),
-                    critical_temp: critical.filter(|&c| c > 0.0),
-                }
-            })
-            .collect()
+        collect_temperature_info(&self.system)
     }
     
     // Sistem boot zamanını al
@@ -106,6 +106,12 @@ impl SystemInfoCollector {
         self.system.processes().len()
     }
     
+    // Process sayısı durumlara göre (çalışan, uyuyan, durdurulmuş, zombi)
+    pub fn get_process_states(&self) -> ProcessStates {
+        let statuses: Vec<String> = self.system.processes().values().map(|process| process.status().to_string()).collect();
+        ProcessStates::from_statuses(statuses.iter().map(String::as_str))
+    }
+    
     // Sistem hostname'ini al
     // Modern API'de artık instance method
     pub fn get_hostname(&self) -> Option<String> {
@@ -152,14 +158,163 @@ impl SystemInfoCollector {
 
 // Yardımcı fonksiyonlar - UI tarafından kullanılabilir
 
+// Sahte (pseudo) dosya sistemi filtresi - config'deki [disks] tablosu
+// tmpfs, overlay, squashfs gibi bağlama noktaları gerçek depolama değildir ve disk listesini kalabalıklaştırır
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct DiskFilter {
+    // Gizlenecek dosya sistemi türleri
+    pub hidden_filesystems: Vec<String>,
+    // Toplam alanı 0 görünen bağlama noktalarını da gizle
+    pub hide_empty: bool,
+}
+
+impl Default for DiskFilter {
+    fn default() -> Self {
+        let hidden = [
+            "tmpfs", "devtmpfs", "overlay", "squashfs", "proc", "sysfs", "devpts",
+            "cgroup", "cgroup2", "efivarfs", "ramfs", "autofs", "fuse.snapfuse",
+        ];
+        Self {
+            hidden_filesystems: hidden.iter().map(|fs| fs.to_string()).collect(),
+            hide_empty: true,
+        }
+    }
+}
+
+impl DiskFilter {
+    // Disk gerçek bir depolama birimi mi?
+    pub fn is_physical(&self, disk: &DiskInfo) -> bool {
+        if self.hide_empty && disk.total_space == 0 {
+            return false;
+        }
+        !self.hidden_filesystems.iter().any(|fs| fs.eq_ignore_ascii_case(&disk.file_system))
+    }
+    
+    // Listeden sadece gerçek diskleri seç
+    pub fn physical(&self, disks: &[DiskInfo]) -> Vec<DiskInfo> {
+        disks.iter().filter(|disk| self.is_physical(disk)).cloned().collect()
+    }
+}
+
+// Verilen System'daki disk bilgilerini topla
+// App kendi System'ını tuttuğu için collector'dan bağımsız bir fonksiyon olarak da sunuyoruz
+// Böylece aynı veriyi iki kez refresh etmek zorunda kalmıyoruz
+pub fn collect_disk_info(system: &System) -> Vec<DiskInfo> {
+    system
+        .disks()
+        .iter()
+        .map(|disk| {
+            let total = disk.total_space();
+            let available = disk.available_space();
+            let used = total.saturating_sub(available);
+            
+            // Kullanım yüzdesini hesapla - sıfıra bölme kontrolü önemli
+            let usage_percent = if total > 0 {
+                (used as f64 / total as f64 * 100.0) as f32
+            } else {
+                0.0
+            };
+            
+            DiskInfo {
+                name: disk.name().to_string_lossy().to_string(),
+                mount_point: disk.mount_point().to_string_lossy().to_string(),
+                total_space: total,
+                available_space: available,
+                used_space: used,
+                usage_percent,
+                file_system: String::from_utf8_lossy(disk.file_system()).to_string(),
+            }
+        })
+        .collect()
+}
+
+// Verilen System'daki sıcaklık sensörlerini topla
+pub fn collect_temperature_info(system: &System) -> Vec<TemperatureInfo> {
+    system
+        .components()
+        .iter()
+        .map(|component| {
+            let max = component.max();
+            let critical = component.critical();
+            TemperatureInfo {
+                component_name: component.label().to_string(),
+                current_temp: component.temperature(),
+                max_temp: (max > 0.0).then_some(max),
+                critical_temp: critical.filter(|&c| c > 0.0),
+            }
+        })
+        .collect()
+}
+
+// Renk eşikleri - config'deki [thresholds] tablosu
+// Her dizi bir sonraki renge geçilen sınırlardır ve artan sırada olmalıdır.
+// Değerin tam sayı kısmı eşiği aşınca bir üst seviyeye geçilir (%50.9 hâlâ yeşil)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ColorThresholds {
+    pub usage: [f32; 2],       // CPU/RAM/GPU gauge'ları: yeşil / sarı / kırmızı (%)
+    pub temperature: [f32; 4], // soğuk / normal / ılık / sıcak / kritik (°C)
+    pub disk: [f32; 3],        // normal / uyarı / kritik / dolu (%)
+}
+
+impl Default for ColorThresholds {
+    fn default() -> Self {
+        Self {
+            usage: [50.0, 80.0],
+            temperature: [40.0, 60.0, 75.0, 85.0],
+            disk: [70.0, 85.0, 95.0],
+        }
+    }
+}
+
+impl ColorThresholds {
+    // Eşikler kesin artan sırada değilse bazı renkler hiç görünmez - config hatası sayılır
+    pub fn validate(&self) -> Result<()> {
+        let lists: [(&str, &[f32]); 3] = [
+            ("usage", &self.usage),
+            ("temperature", &self.temperature),
+            ("disk", &self.disk),
+        ];
+        for (name, values) in lists {
+            if !values.windows(2).all(|pair| pair[0] < pair[1]) {
+                bail!("thresholds.{name} must be strictly increasing, got {values:?}");
+            }
+        }
+        Ok(())
+    }
+}
+
+// Değer kaç eşiği aşıyor - 0 en düşük seviye
+fn threshold_level(value: f32, thresholds: &[f32]) -> usize {
+    let value = value.trunc();
+    thresholds.iter().filter(|&&threshold| value > threshold).count()
+}
+
+// Kullanım yüzdesini kategorize et - gauge renkleri için
+pub fn categorize_usage(usage: f32, thresholds: &ColorThresholds) -> UsageLevel {
+    match threshold_level(usage, &thresholds.usage) {
+        0 => UsageLevel::Low,    // Düşük kullanım - yeşil
+        1 => UsageLevel::Medium, // Orta kullanım - sarı
+        _ => UsageLevel::High,   // Yüksek kullanım - kırmızı
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UsageLevel {
+    Low,
+    Medium,
+    High,
+}
+
 // Sıcaklık verilerini kategorize et - kritik sıcaklıkları belirle
-pub fn categorize_temperature(temp: f32) -> TemperatureCategory {
-    match temp as u32 {
-        0..=40 => TemperatureCategory::Cool,      // Soğuk - yeşil
-        41..=60 => TemperatureCategory::Normal,   // Normal - mavi
-        61..=75 => TemperatureCategory::Warm,     // Ilık - sarı
-        76..=85 => TemperatureCategory::Hot,      // Sıcak - turuncu
-        86.. => TemperatureCategory::Critical,    // Kritik - kırmızı
+pub fn categorize_temperature(temp: f32, thresholds: &ColorThresholds) -> TemperatureCategory {
+    match threshold_level(temp, &thresholds.temperature) {
+        0 => TemperatureCategory::Cool,      // Soğuk - yeşil
+        1 => TemperatureCategory::Normal,    // Normal - mavi
+        2 => TemperatureCategory::Warm,      // Ilık - sarı
+        3 => TemperatureCategory::Hot,       // Sıcak - turuncu
+        _ => TemperatureCategory::Critical,  // Kritik - kırmızı
     }
 }
 
@@ -173,15 +328,51 @@ pub enum TemperatureCategory {
 }
 
 // Disk kullanımını kategorize et - renk kodlaması için
-pub fn categorize_disk_usage(usage_percent: f32) -> DiskUsageCategory {
-    match usage_percent as u32 {
-        0..=70 => DiskUsageCategory::Normal,      // Normal kullanım - yeşil
-        71..=85 => DiskUsageCategory::Warning,    // Uyarı - sarı
-        86..=95 => DiskUsageCategory::Critical,   // Kritik - turuncu
-        96.. => DiskUsageCategory::Full,          // Dolu - kırmızı
+pub fn categorize_disk_usage(usage_percent: f32, thresholds: &ColorThresholds) -> DiskUsageCategory {
+    match threshold_level(usage_percent, &thresholds.disk) {
+        0 => DiskUsageCategory::Normal,      // Normal kullanım - yeşil
+        1 => DiskUsageCategory::Warning,     // Uyarı - sarı
+        2 => DiskUsageCategory::Critical,    // Kritik - turuncu
+        _ => DiskUsageCategory::Full,        // Dolu - kırmızı
     }
 }
 
+// Açık kalma süresini kategorize et - eşik gün cinsinden, 0 uyarıyı kapatır
+// Eşiğin iki katını aşan sistem kırmızı gösterilir
+pub fn categorize_uptime(uptime_secs: u64, warning_days: u64) -> UptimeCategory {
+    let days = uptime_secs / 86_400;
+    match warning_days {
+        0 => UptimeCategory::Normal,
+        _ if days >= warning_days.saturating_mul(2) => UptimeCategory::VeryLong,
+        _ if days >= warning_days => UptimeCategory::Long,
+        _ => UptimeCategory::Normal,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum UptimeCategory {
+    Normal,
+    Long,     // Sarı
+    VeryLong, // Kırmızı
+}
+
+// Paket yöneticisinin yeniden başlatma istediğini bildiren işaret dosyaları
+// Debian/Ubuntu güncellemeden sonra bu dosyayı oluşturur, yeniden başlatınca silinir
+#[cfg(target_os = "linux")]
+const REBOOT_REQUIRED_MARKERS: &[&str] = &["/run/reboot-required", "/var/run/reboot-required"];
+
+// Yeniden başlatma bekleyen bir güncelleme var mı - dosya okunamazsa sessizce false
+#[cfg(target_os = "linux")]
+pub fn reboot_pending() -> bool {
+    REBOOT_REQUIRED_MARKERS.iter().any(|path| std::path::Path::new(path).exists())
+}
+
+// Diğer platformlarda bilinen bir işaret yok
+#[cfg(not(target_os = "linux"))]
+pub fn reboot_pending() -> bool {
+    false
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum DiskUsageCategory {
     Normal,
@@ -190,26 +381,73 @@ pub enum DiskUsageCategory {
     Full,
 }
 
-// Byte'ları insan tarafından okunabilir formata çevir
-// Bu fonksiyon App struct'ındaki ile aynı - gelecekte tek yerde toplanabilir
-pub fn format_bytes_detailed(bytes: u64) -> String {
-    const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB", "PB"];
-    let mut size = bytes as f64;
-    let mut unit_index = 0;
-    
-    while size >= 1024.0 && unit_index < UNITS.len() - 1 {
-        size /= 1024.0;
-        unit_index += 1;
+// Byte birimlerinin tabanı
+// Binary: 1024 tabanlı, KiB/MiB/GiB etiketleri (işletim sistemlerinin çoğu böyle hesaplar)
+// Decimal: 1000 tabanlı, KB/MB/GB etiketleri (disk üreticileri böyle hesaplar)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ByteUnits {
+    #[default]
+    Binary,
+    Decimal,
+}
+
+impl ByteUnits {
+    // Byte değerini uygun birime böl - (değer, birim etiketi) döner
+    // App::format_bytes ve format_bytes_detailed aynı ölçeklemeyi kullanır
+    pub fn scale(&self, bytes: u64) -> (f64, &'static str) {
+        let (base, units): (f64, &[&'static str]) = match self {
+            ByteUnits::Binary => (1024.0, &["B", "KiB", "MiB", "GiB", "TiB", "PiB"]),
+            ByteUnits::Decimal => (1000.0, &["B", "KB", "MB", "GB", "TB", "PB"]),
+        };
+        let mut size = bytes as f64;
+        let mut unit_index = 0;
+        
+        while size >= base && unit_index < units.len() - 1 {
+            size /= base;
+            unit_index += 1;
+        }
+        
+        (size, units[unit_index])
     }
+}
+
+// Byte'ları insan tarafından okunabilir formata çevir
+// App::format_bytes'tan farkı birime göre değişen hassasiyet
+pub fn format_bytes_detailed(bytes: u64, units: ByteUnits) -> String {
+    let (size, unit) = units.scale(bytes);
     
-    // Hassasiyet - büyük dosyalar için daha az ondalık
-    let precision = match unit_index {
-        0..=1 => 0,  // Byte ve KB için tam sayı
-        2 => 1,      // MB için 1 ondalık
-        _ => 2,      // GB ve üzeri için 2 ondalık
+    // Hassasiyet - büyük dosyalar için daha fazla ondalık
+    let precision = match unit {
+        "B" => 0,                                        // Byte için tam sayı
+        "KiB" | "KB" if size.fract() == 0.0 => 0,        // Tam KB değerleri için ondalık gereksiz
+        "KiB" | "KB" | "MiB" | "MB" => 1,                // KB ve MB için 1 ondalık
+        _ => 2,                                          // GB ve üzeri için 2 ondalık
     };
     
-    format!("{:.precision$} {}", size, UNITS[unit_index], precision = precision)
+    format!("{:.precision$} {}", size, unit, precision = precision)
+}
+
+// İşlemci model adını kısalt - "Intel(R) Core(TM) i7-8700K CPU @ 3.70GHz" gibi
+// uzun adlardan marka işaretlerini, "CPU" kelimesini ve nominal frekansı atar
+pub fn shorten_cpu_brand(brand: &str) -> String {
+    let brand = brand.split(" @ ").next().unwrap_or(brand);
+    brand
+        .replace("(R)", "")
+        .replace("(TM)", "")
+        .split_whitespace()
+        .filter(|word| !matches!(*word, "CPU" | "Processor"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// CPU frekansını formatla - MHz cinsinden gelir, 1 GHz üstünde GHz gösterilir
+pub fn format_frequency(mhz: u64) -> String {
+    if mhz >= 1000 {
+        format!("{:.2} GHz", mhz as f64 / 1000.0)
+    } else {
+        format!("{} MHz", mhz)
+    }
 }
 
 // Uptime'ı detaylı formata çevir
@@ -237,26 +475,106 @@ mod tests {
     
     #[test]
     fn test_temperature_categorization() {
-        assert_eq!(categorize_temperature(30.0), TemperatureCategory::Cool);
-        assert_eq!(categorize_temperature(50.0), TemperatureCategory::Normal);
-        assert_eq!(categorize_temperature(70.0), TemperatureCategory::Warm);
-        assert_eq!(categorize_temperature(80.0), TemperatureCategory::Hot);
-        assert_eq!(categorize_temperature(90.0), TemperatureCategory::Critical);
+        let thresholds = ColorThresholds::default();
+        assert_eq!(categorize_temperature(30.0, &thresholds), TemperatureCategory::Cool);
+        assert_eq!(categorize_temperature(50.0, &thresholds), TemperatureCategory::Normal);
+        assert_eq!(categorize_temperature(70.0, &thresholds), TemperatureCategory::Warm);
+        assert_eq!(categorize_temperature(80.0, &thresholds), TemperatureCategory::Hot);
+        assert_eq!(categorize_temperature(90.0, &thresholds), TemperatureCategory::Critical);
     }
     
     #[test]
     fn test_disk_usage_categorization() {
-        assert_eq!(categorize_disk_usage(50.0), DiskUsageCategory::Normal);
-        assert_eq!(categorize_disk_usage(80.0), DiskUsageCategory::Warning);
-        assert_eq!(categorize_disk_usage(90.0), DiskUsageCategory::Critical);
-        assert_eq!(categorize_disk_usage(98.0), DiskUsageCategory::Full);
+        let thresholds = ColorThresholds::default();
+        assert_eq!(categorize_disk_usage(50.0, &thresholds), DiskUsageCategory::Normal);
+        assert_eq!(categorize_disk_usage(80.0, &thresholds), DiskUsageCategory::Warning);
+        assert_eq!(categorize_disk_usage(90.0, &thresholds), DiskUsageCategory::Critical);
+        assert_eq!(categorize_disk_usage(98.0, &thresholds), DiskUsageCategory::Full);
+    }
+    
+    #[test]
+    fn test_usage_thresholds() {
+        // Varsayılanlar eski sabit sınırlarla aynı: 0-50 yeşil, 51-80 sarı, 81+ kırmızı
+        let thresholds = ColorThresholds::default();
+        assert_eq!(categorize_usage(50.9, &thresholds), UsageLevel::Low);
+        assert_eq!(categorize_usage(51.0, &thresholds), UsageLevel::Medium);
+        assert_eq!(categorize_usage(80.5, &thresholds), UsageLevel::Medium);
+        assert_eq!(categorize_usage(81.0, &thresholds), UsageLevel::High);
+        
+        let custom = ColorThresholds { usage: [60.0, 85.0], ..Default::default() };
+        assert_eq!(categorize_usage(55.0, &custom), UsageLevel::Low);
+        assert!(custom.validate().is_ok());
+        assert!(ColorThresholds { usage: [85.0, 60.0], ..Default::default() }.validate().is_err());
+        assert!(ColorThresholds { disk: [70.0, 70.0, 95.0], ..Default::default() }.validate().is_err());
+    }
+    
+    #[test]
+    fn test_uptime_categorization() {
+        let day = 86_400;
+        assert_eq!(categorize_uptime(29 * day, 30), UptimeCategory::Normal);
+        assert_eq!(categorize_uptime(30 * day, 30), UptimeCategory::Long);
+        assert_eq!(categorize_uptime(60 * day, 30), UptimeCategory::VeryLong);
+        assert_eq!(categorize_uptime(400 * day, 0), UptimeCategory::Normal);
     }
     
     #[test]
     fn test_byte_formatting() {
-        assert_eq!(format_bytes_detailed(1024), "1 KB");
-        assert_eq!(format_bytes_detailed(1536), "1.5 KB");
-        assert_eq!(format_bytes_detailed(1073741824), "1.00 GB");
+        assert_eq!(format_bytes_detailed(1024, ByteUnits::Binary), "1 KiB");
+        assert_eq!(format_bytes_detailed(1536, ByteUnits::Binary), "1.5 KiB");
+        assert_eq!(format_bytes_detailed(1073741824, ByteUnits::Binary), "1.00 GiB");
+        
+        assert_eq!(format_bytes_detailed(1000, ByteUnits::Decimal), "1 KB");
+        assert_eq!(format_bytes_detailed(1500, ByteUnits::Decimal), "1.5 KB");
+        assert_eq!(format_bytes_detailed(1_000_000_000, ByteUnits::Decimal), "1.00 GB");
+        assert_eq!(format_bytes_detailed(1024, ByteUnits::Decimal), "1.0 KB");
+        assert_eq!(format_bytes_detailed(999, ByteUnits::Decimal), "999 B");
+    }
+    
+    #[test]
+    fn test_disk_filter_hides_pseudo_filesystems() {
+        let disk = |file_system: &str, total_space: u64| DiskInfo {
+            name: String::new(),
+            mount_point: String::new(),
+            total_space,
+            available_space: 0,
+            used_space: 0,
+            usage_percent: 0.0,
+            file_system: file_system.to_string(),
+        };
+        let filter = DiskFilter::default();
+        assert!(filter.is_physical(&disk("ext4", 100)));
+        assert!(!filter.is_physical(&disk("tmpfs", 100)));
+        assert!(!filter.is_physical(&disk("ext4", 0)));
+        
+        let keep_empty = DiskFilter { hidden_filesystems: Vec::new(), hide_empty: false };
+        assert!(keep_empty.is_physical(&disk("tmpfs", 0)));
+    }
+    
+    #[test]
+    fn test_shorten_cpu_brand() {
+        assert_eq!(shorten_cpu_brand("Intel(R) Core(TM) i7-8700K CPU @ 3.70GHz"), "Intel Core i7-8700K");
+        assert_eq!(shorten_cpu_brand("AMD Ryzen 7 5800X 8-Core Processor"), "AMD Ryzen 7 5800X 8-Core");
+        assert_eq!(shorten_cpu_brand(""), "");
+    }
+    
+    #[test]
+    fn test_frequency_formatting() {
+        assert_eq!(format_frequency(800), "800 MHz");
+        assert_eq!(format_frequency(2400), "2.40 GHz");
+    }
+    
+    #[test]
+    fn test_process_states_breakdown() {
+        let states = ProcessStates::from_statuses(["Runnable", "Sleeping", "Idle", "Zombie", "Stopped", "Tracing", "Unknown"]);
+        assert_eq!(
+            states,
+            ProcessStates { total: 7, running: 1, sleeping: 2, stopped: 2, zombie: 1, detailed: true }
+        );
+        
+        // Windows: hepsi "Runnable" - sadece toplam
+        let states = ProcessStates::from_statuses(["Runnable"; 3]);
+        assert_eq!(states.total, 3);
+        assert!(!states.detailed);
     }
     
     #[test]
@@ -0,0 +1,135 @@
+// talkers.rs - Process başına TCP bağlantı sayıları (Linux, net_talkers özelliği ile)
+// Çekirdek soketleri process'lere göre raporlamaz. /proc/net/tcp{,6} her soketin inode
+// numarasını verir; /proc/<pid>/fd altındaki "socket:[inode]" bağlantıları da hangi
+// process'in o sokete sahip olduğunu gösterir. İkisi eşleştirilerek sayılır.
+// Her turda tüm fd dizinlerini gezmek pahalı olduğu için özellik varsayılan olarak kapalıdır.
+// Root değilken başka kullanıcıların fd dizinleri okunamaz - onların bağlantıları sayılmaz.
+// Bayt hızı yoktur; sadece bağlantı sayısı
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+// Bir process'in açık TCP bağlantı sayısı
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProcessConnections {
+    pub pid: u32,
+    pub connections: u32,
+}
+
+// /proc/net/tcp{,6} içeriğinden bağlantı soketlerinin inode'ları
+// Dinleyen soketler (0A) bağlantı değildir; TIME_WAIT gibi sahipsiz soketlerin inode'u 0'dır
+#[cfg_attr(not(all(target_os = "linux", feature = "net_talkers")), allow(dead_code))]
+fn connection_inodes(contents: &str, inodes: &mut HashSet<u64>) {
+    for line in contents.lines().skip(1) {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let (Some(state), Some(inode)) = (fields.get(3), fields.get(9)) else {
+            continue;
+        };
+        match inode.parse::<u64>() {
+            Ok(inode) if inode != 0 && *state != "0A" => {
+                inodes.insert(inode);
+            }
+            _ => {}
+        }
+    }
+}
+
+// `proc_root` altındaki process'lerin bağlantı sayıları, çoktan aza
+// Tablolar okunamazsa None; hiç bağlantı yoksa boş liste
+#[cfg_attr(not(all(target_os = "linux", feature = "net_talkers")), allow(dead_code))]
+pub fn read_process_connections_from(proc_root: &Path) -> Option<Vec<ProcessConnections>> {
+    let mut inodes = HashSet::new();
+    let mut found = false;
+    for table in ["net/tcp", "net/tcp6"] {
+        if let Ok(contents) = fs::read_to_string(proc_root.join(table)) {
+            connection_inodes(&contents, &mut inodes);
+            found = true;
+        }
+    }
+    if !found {
+        return None;
+    }
+
+    let mut counts: HashMap<u32, u32> = HashMap::new();
+    for entry in fs::read_dir(proc_root).ok()?.flatten() {
+        let Some(pid) = entry.file_name().to_str().and_then(|name| name.parse::<u32>().ok()) else {
+            continue;
+        };
+        // İzin yoksa veya process bu arada kapandıysa sessizce atlanır
+        let Ok(fds) = fs::read_dir(entry.path().join("fd")) else {
+            continue;
+        };
+        let owned = fds
+            .flatten()
+            .filter_map(|fd| fs::read_link(fd.path()).ok())
+            .filter_map(|target| {
+                let target = target.to_str()?;
+                target.strip_prefix("socket:[")?.strip_suffix(']')?.parse::<u64>().ok()
+            })
+            .filter(|inode| inodes.contains(inode))
+            .count() as u32;
+        if owned > 0 {
+            counts.insert(pid, owned);
+        }
+    }
+
+    let mut talkers: Vec<ProcessConnections> = counts
+        .into_iter()
+        .map(|(pid, connections)| ProcessConnections { pid, connections })
+        .collect();
+    talkers.sort_by(|a, b| b.connections.cmp(&a.connections).then(a.pid.cmp(&b.pid)));
+    Some(talkers)
+}
+
+#[cfg(all(target_os = "linux", feature = "net_talkers"))]
+pub fn read_process_connections() -> Option<Vec<ProcessConnections>> {
+    read_process_connections_from(Path::new("/proc"))
+}
+
+// Özellik kapalıyken veya Linux dışında liste yok - panel gizlenir
+#[cfg(not(all(target_os = "linux", feature = "net_talkers")))]
+pub fn read_process_connections() -> Option<Vec<ProcessConnections>> {
+    None
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::symlink;
+
+    #[test]
+    fn test_process_connections_from_proc_tree() {
+        let root = std::env::temp_dir().join(format!("sysmon-talkers-{}", std::process::id()));
+        fs::create_dir_all(root.join("net")).unwrap();
+        let header = "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode\n";
+        let socket = |slot: u32, state: &str, inode: u64| {
+            format!("   {slot}: 0100007F:1F90 0100007F:C350 {state} 00000000:00000000 00:00000000 00000000  1000        0 {inode} 1 0 20 4 30 10 -1\n")
+        };
+        // 100 ve 101 bağlantı, 102 dinleyen soket, TIME_WAIT'in sahibi yok
+        let tcp = [header.to_string(), socket(0, "01", 100), socket(1, "01", 101), socket(2, "0A", 102), socket(3, "06", 0)].concat();
+        fs::write(root.join("net/tcp"), tcp).unwrap();
+        fs::write(root.join("net/tcp6"), [header.to_string(), socket(0, "08", 103)].concat()).unwrap();
+
+        for (pid, targets) in [("10", vec!["socket:[100]", "socket:[102]", "/dev/null"]), ("20", vec!["socket:[101]", "socket:[103]"])] {
+            fs::create_dir_all(root.join(pid).join("fd")).unwrap();
+            for (fd, target) in targets.iter().enumerate() {
+                symlink(target, root.join(pid).join("fd").join(fd.to_string())).unwrap();
+            }
+        }
+        // fd dizini okunamayan (veya olmayan) process atlanır
+        fs::create_dir_all(root.join("30")).unwrap();
+
+        let talkers = read_process_connections_from(&root);
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(
+            talkers,
+            Some(vec![
+                ProcessConnections { pid: 20, connections: 2 },
+                ProcessConnections { pid: 10, connections: 1 },
+            ])
+        );
+        assert_eq!(read_process_connections_from(Path::new("/nonexistent")), None);
+    }
+}
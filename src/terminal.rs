@@ -0,0 +1,98 @@
+// terminal.rs - Terminali arayüz moduna alma ve her çıkış yolunda eski haline döndürme
+// Ham mod ve alternatif ekran açık kalırsa kullanıcının kabuğu bozuk görünür: yazılanlar
+// görünmez, Enter satır atlamaz. Normal çıkış ('q'), sinyaller (SIGTERM/SIGINT/SIGHUP), hata
+// yolları ve panikler aynı geri yüklemeden geçer. Geri yükleme sadece ilk çağrıda iş yapar,
+// ikinci kez çağrılması zararsızdır
+
+use crossterm::{
+    cursor::Show,
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+// Terminal şu an arayüz modunda mı ve fare yakalanıyor mu
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+static MOUSE: AtomicBool = AtomicBool::new(false);
+
+// Ham moda ve alternatif ekrana geç - mouse kapalıysa terminalin kendi metin seçimi çalışır
+pub fn enter(mouse: bool) -> io::Result<()> {
+    enable_raw_mode()?;
+    ACTIVE.store(true, Ordering::SeqCst);
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    if mouse {
+        MOUSE.store(true, Ordering::SeqCst);
+        execute!(stdout, EnableMouseCapture)?;
+    }
+    Ok(())
+}
+
+// Terminali eski haline döndür - adımlardan biri başarısız olsa da diğerleri denenir
+pub fn restore() {
+    if !ACTIVE.swap(false, Ordering::SeqCst) {
+        return;
+    }
+    let mut stdout = io::stdout();
+    if MOUSE.swap(false, Ordering::SeqCst) {
+        let _ = execute!(stdout, DisableMouseCapture);
+    }
+    let _ = disable_raw_mode();
+    let _ = execute!(stdout, LeaveAlternateScreen, Show);
+}
+
+// Panik olursa önce terminali geri yükle, sonra varsayılan çıktıyı (mesaj, backtrace) yazdır
+// Yoksa mesaj alternatif ekrana ham modda yazılır ve çıkışla birlikte kaybolur.
+// Arayüz döngüsü ana thread'de çalışır; arka plan görevlerindeki (metrics, InfluxDB) panikleri
+// tokio yakalar ve arayüz çalışmaya devam eder - onlarda terminal geri alınmaz
+pub fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if std::thread::current().name() == Some("main") {
+            restore();
+        }
+        default_hook(info);
+    }));
+}
+
+// Sonlandırma sinyali gelince true olan bayrak - arayüz döngüsü bunu her turda kontrol eder
+// Döngü crossterm'in poll'unda beklerken sinyal bu görevde yakalanır
+pub fn shutdown_flag() -> Arc<AtomicBool> {
+    let flag = Arc::new(AtomicBool::new(false));
+    let shared = Arc::clone(&flag);
+    tokio::spawn(async move {
+        shutdown_signal().await;
+        shared.store(true, Ordering::SeqCst);
+    });
+    flag
+}
+
+// SIGTERM (kill, systemd), SIGINT (kill -INT - ham modda Ctrl+C bir tuştur, sinyal değil)
+// veya SIGHUP (terminal penceresi kapandı) gelene kadar bekle
+// Sinyaller dinlenemezse hiç dönmez - çıkış yine 'q' ile mümkündür
+#[cfg(unix)]
+pub async fn shutdown_signal() {
+    use tokio::signal::unix::{signal, SignalKind};
+    let (Ok(mut terminate), Ok(mut interrupt), Ok(mut hangup)) = (
+        signal(SignalKind::terminate()),
+        signal(SignalKind::interrupt()),
+        signal(SignalKind::hangup()),
+    ) else {
+        return std::future::pending().await;
+    };
+    tokio::select! {
+        _ = terminate.recv() => {}
+        _ = interrupt.recv() => {}
+        _ = hangup.recv() => {}
+    }
+}
+
+#[cfg(not(unix))]
+pub async fn shutdown_signal() {
+    if tokio::signal::ctrl_c().await.is_err() {
+        std::future::pending::<()>().await;
+    }
+}
@@ -0,0 +1,73 @@
+// theme.rs - Renk temaları
+// Panellerin çerçeve, başlık ve metin renkleri temadan gelir
+// Kullanım seviyesini anlatan renkler (yeşil/sarı/kırmızı) temadan bağımsızdır,
+// çünkü onlar bir anlam taşır - süs değildir
+
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+// Seçilebilir temalar - state dosyasına isimleriyle yazılır
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemeName {
+    #[default]
+    Default,
+    Ocean,
+    Monochrome,
+}
+
+impl ThemeName {
+    // 't' tuşu ile dolaşılan sıra
+    pub fn next(&self) -> ThemeName {
+        match self {
+            ThemeName::Default => ThemeName::Ocean,
+            ThemeName::Ocean => ThemeName::Monochrome,
+            ThemeName::Monochrome => ThemeName::Default,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ThemeName::Default => "default",
+            ThemeName::Ocean => "ocean",
+            ThemeName::Monochrome => "monochrome",
+        }
+    }
+
+    // Temanın renk paleti
+    pub fn theme(&self) -> Theme {
+        match self {
+            ThemeName::Default => Theme {
+                border: Color::Blue,
+                title: Color::Cyan,
+                text: Color::White,
+                accent: Color::Yellow,
+                muted: Color::Gray,
+            },
+            ThemeName::Ocean => Theme {
+                border: Color::Cyan,
+                title: Color::LightBlue,
+                text: Color::White,
+                accent: Color::LightCyan,
+                muted: Color::DarkGray,
+            },
+            ThemeName::Monochrome => Theme {
+                border: Color::Gray,
+                title: Color::White,
+                text: Color::White,
+                accent: Color::White,
+                muted: Color::DarkGray,
+            },
+        }
+    }
+}
+
+// Bir temanın renk paleti
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub border: Color, // Panel çerçeveleri
+    pub title: Color,  // Başlık çubuğu metni
+    pub text: Color,   // Normal metin
+    pub accent: Color, // Tablo başlıkları, popup çerçeveleri
+    pub muted: Color,  // Eksen etiketleri, ikincil bilgiler
+}
@@ -0,0 +1,184 @@
+// tree.rs - Process'leri ebeveyn/çocuk ilişkisine göre iç içe dizen ağaç görünümü
+// Düz liste kimin kimi başlattığını gizler; ağaçta her process ebeveyninin altında görünür.
+// Ebeveyni listede olmayanlar (yetim kalanlar, gizlenen bir ebeveynin çocukları) ve hiç
+// ebeveyni olmayanlar (init) görünmeyen ortak bir kökün altında en üst seviyede durur.
+// PID'ler yeniden kullanıldığı için iki örnek arasında ebeveyn zinciri döngüye girebilir -
+// döngü bir yerinden kesilir ki hiçbir process kaybolmasın
+
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+use crate::app::ProcessRow;
+
+// Satırın ağaçtaki yeri
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TreeNode {
+    pub depth: usize,
+    pub children: usize, // Doğrudan çocuk sayısı
+    pub collapsed: bool, // Çocukları gizli mi
+}
+
+// Satırları ağaç sırasına diz - kardeşler `compare` ile sıralanır, kapalı düğümlerin
+// alt ağacı atlanır. `totals` açıksa CPU, bellek ve disk değerleri alt ağacın toplamıdır
+// (sıralama da toplamlara göre yapılır)
+pub fn build_tree(
+    mut rows: Vec<ProcessRow>,
+    collapsed: &HashSet<u32>,
+    totals: bool,
+    compare: impl Fn(&ProcessRow, &ProcessRow) -> Ordering,
+) -> Vec<ProcessRow> {
+    let index: HashMap<u32, usize> = rows.iter().enumerate().map(|(i, row)| (row.pid, i)).collect();
+    let mut parent: Vec<Option<usize>> = rows
+        .iter()
+        .map(|row| row.parent_pid.filter(|&pid| pid != row.pid).and_then(|pid| index.get(&pid).copied()))
+        .collect();
+
+    // Kökten ulaşılamayan düğümler bir döngünün parçasıdır (veya ona bağlıdır) - döngüyü
+    // ilk ulaşılamayan düğümde kesip onu köke bağla
+    let mut children = vec![Vec::new(); rows.len()];
+    for (i, p) in parent.iter().enumerate() {
+        if let Some(p) = p {
+            children[*p].push(i);
+        }
+    }
+    let mut reachable = vec![false; rows.len()];
+    for i in (0..rows.len()).filter(|&i| parent[i].is_none()) {
+        mark_reachable(i, &children, &mut reachable);
+    }
+    while let Some(i) = reachable.iter().position(|&seen| !seen) {
+        if let Some(p) = parent[i].take() {
+            children[p].retain(|&child| child != i);
+        }
+        mark_reachable(i, &children, &mut reachable);
+    }
+
+    if totals {
+        // Ön-sıranın tersinde her düğüm ebeveyninden önce gelir - toplamlar yukarı taşınır
+        let mut order = Vec::with_capacity(rows.len());
+        for i in (0..rows.len()).filter(|&i| parent[i].is_none()) {
+            pre_order(i, &children, &mut order);
+        }
+        for &i in order.iter().rev() {
+            if let Some(p) = parent[i] {
+                let child = rows[i].clone();
+                let row = &mut rows[p];
+                row.cpu_usage += child.cpu_usage;
+                row.memory += child.memory;
+                row.memory_percent += child.memory_percent;
+                row.disk_read_rate += child.disk_read_rate;
+                row.disk_write_rate += child.disk_write_rate;
+            }
+        }
+    }
+
+    let mut roots: Vec<usize> = (0..rows.len()).filter(|&i| parent[i].is_none()).collect();
+    roots.sort_by(|&a, &b| compare(&rows[a], &rows[b]));
+    for list in &mut children {
+        list.sort_by(|&a, &b| compare(&rows[a], &rows[b]));
+    }
+
+    let mut flat = Vec::with_capacity(rows.len());
+    for root in roots {
+        flatten(root, 0, &children, collapsed, &rows, &mut flat);
+    }
+    flat
+}
+
+fn mark_reachable(i: usize, children: &[Vec<usize>], reachable: &mut [bool]) {
+    if reachable[i] {
+        return;
+    }
+    reachable[i] = true;
+    for &child in &children[i] {
+        mark_reachable(child, children, reachable);
+    }
+}
+
+fn pre_order(i: usize, children: &[Vec<usize>], order: &mut Vec<usize>) {
+    order.push(i);
+    for &child in &children[i] {
+        pre_order(child, children, order);
+    }
+}
+
+fn flatten(
+    i: usize,
+    depth: usize,
+    children: &[Vec<usize>],
+    collapsed: &HashSet<u32>,
+    rows: &[ProcessRow],
+    flat: &mut Vec<ProcessRow>,
+) {
+    let is_collapsed = collapsed.contains(&rows[i].pid);
+    let mut row = rows[i].clone();
+    row.tree = Some(TreeNode {
+        depth,
+        children: children[i].len(),
+        collapsed: is_collapsed,
+    });
+    flat.push(row);
+    if !is_collapsed {
+        for &child in &children[i] {
+            flatten(child, depth + 1, children, collapsed, rows, flat);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(pid: u32, parent_pid: Option<u32>, cpu_usage: f32) -> ProcessRow {
+        ProcessRow {
+            pid,
+            parent_pid,
+            name: format!("p{pid}"),
+            cpu_usage,
+            memory: 100,
+            memory_percent: 1.0,
+            exe: String::new(),
+            run_time: 0,
+            disk_read_rate: 0,
+            disk_write_rate: 0,
+            tree: None,
+        }
+    }
+
+    fn by_cpu(a: &ProcessRow, b: &ProcessRow) -> Ordering {
+        b.cpu_usage.total_cmp(&a.cpu_usage).then(a.pid.cmp(&b.pid))
+    }
+
+    fn layout(rows: &[ProcessRow]) -> Vec<(u32, usize)> {
+        rows.iter().map(|row| (row.pid, row.tree.unwrap().depth)).collect()
+    }
+
+    #[test]
+    fn test_children_nest_under_parents() {
+        // 1 → {2 → {4}, 3}; 9'un ebeveyni listede yok, 5 ↔ 6 döngüde
+        let rows = vec![
+            row(4, Some(2), 1.0),
+            row(3, Some(1), 5.0),
+            row(2, Some(1), 2.0),
+            row(1, None, 0.0),
+            row(9, Some(77), 3.0),
+            row(5, Some(6), 0.0),
+            row(6, Some(5), 0.0),
+        ];
+        let tree = build_tree(rows.clone(), &HashSet::new(), false, by_cpu);
+        assert_eq!(layout(&tree), vec![(9, 0), (1, 0), (3, 1), (2, 1), (4, 2), (5, 0), (6, 1)]);
+        assert_eq!(tree[1].tree.unwrap().children, 2);
+
+        // Kapalı düğümün alt ağacı gizlenir
+        let collapsed = HashSet::from([1]);
+        let tree = build_tree(rows.clone(), &collapsed, false, by_cpu);
+        assert_eq!(layout(&tree), vec![(9, 0), (1, 0), (5, 0), (6, 1)]);
+        assert!(tree[1].tree.unwrap().collapsed);
+
+        // Toplamlarla ebeveyn alt ağacının yükünü taşır ve sıralamada öne geçer
+        let tree = build_tree(rows, &HashSet::new(), true, by_cpu);
+        assert_eq!(layout(&tree)[..3], [(1, 0), (3, 1), (2, 1)]);
+        assert_eq!(tree[0].cpu_usage, 8.0);
+        assert_eq!(tree[0].memory, 400);
+        assert_eq!(tree[2].cpu_usage, 3.0);
+    }
+}
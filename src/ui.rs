@@ -1,19 +1,34 @@
 // ui.rs - Terminal kullanıcı arayüzünü çizen modül
 // Bu modül tıpkı bir grafik tasarımcı gibi, verileri görsel öğelere dönüştürür
-use sysinfo::SystemExt;
 use ratatui::{
-    backend::Backend,
-    layout::{Constraint, Direction, Layout, Rect},
+    backend::TestBackend,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     symbols,
-    text::Line,
+    text::{Line, Span},
     widgets::{
-        Block, Borders, Chart, Dataset, Gauge, List, ListItem, 
-        Paragraph, Sparkline, Table, Row, Cell
+        block::{Position, Title}, Block, BorderType, Borders, Chart, Clear, Dataset, Gauge, List, ListItem, ListState,
+        Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Sparkline, Table, TableState,
+        Tabs, Row, Cell, Wrap
     },
-    Frame,
+    Frame, Terminal,
 };
-use crate::app::App;
+use chrono::Local;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::time::Duration;
+use crate::alerts::AlertKind;
+use crate::health::{Health, HealthLevel};
+use crate::connections::ConnectionCounts;
+use crate::cpu_times::CpuBreakdown;
+use crate::battery::{categorize_battery, BatteryLevel, ChargeState};
+use crate::app::{App, BulkKillPopup, CpuRow, HitAreas, IdleCores, KillPopup, Panel, ProcessDetails, ProcessRow, ProcessSortKey, RateStats, Severity, Tab, KILL_SIGNALS, OFFENDER_TOP_N};
+use crate::system_info::{categorize_disk_usage, categorize_temperature, categorize_uptime, categorize_usage, ColorThresholds, UsageLevel, format_bytes_detailed, format_frequency, format_uptime, DiskInfo, DiskUsageCategory, TemperatureCategory, TemperatureInfo, UptimeCategory};
+use crate::panels::Section;
+use crate::theme::Theme;
+use crate::input::{Action, KeyLabels};
 
 // Ana UI çizim fonksiyonu - her frame'de çağrılır
 // Frame, ratatui'nin çizim yüzeyi - tıpkı ressamın tuvali gibi
@@ -22,119 +37,610 @@ pub fn ui(f: &mut Frame, app: &App) {
     // Terminal boyutunu al - responsive tasarım için gerekli
     let size = f.size();
     
+    // Tıklanabilir alanlar bu çizimde yeniden kaydedilir
+    app.hit_areas.set(HitAreas::default());
+    
+    // Sabit yükseklikli başlık/alt bilgi bile sığmıyorsa düzen hesaplamaları anlamsız
+    // dikdörtgenler üretir - hiçbir paneli çizmeden sadece uyarı gösteriyoruz
+    if size.width < MIN_WIDTH || size.height < MIN_HEIGHT {
+        draw_too_small(f, size);
+        return;
+    }
+    
     // Ana layout'u oluştur - tıpkı web tasarımında grid system gibi
     // Constraint::Percentage ile yüzdelik oranlar belirliyoruz
     let main_layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3),     // Üst başlık - 3 satır sabit
+            Constraint::Length(1),     // Sekme çubuğu
             Constraint::Min(10),       // Ana içerik - kalan alan
             Constraint::Length(3),     // Alt bilgi - 3 satır sabit
         ])
         .split(size);
     
-    // Başlık bölümünü çiz
+    // Başlık bölümünü ve sekmeleri çiz
     draw_header(f, main_layout[0], app);
+    draw_tabs(f, main_layout[1], app);
     
-    // Ana içerik alanını yatay olarak böl
-    let content_layout = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage(60), // Sol panel - CPU ve RAM
-            Constraint::Percentage(40), // Sağ panel - Process listesi ve ağ
-        ])
-        .split(main_layout[1]);
+    match app.active_tab {
+        // Zoom açıksa odaklanılan panel tüm içerik alanını kaplar, diğerleri gizlenir
+        Tab::Overview if app.zoomed => draw_panel(f, main_layout[2], app, app.focused_panel),
+        // Küçük terminalde grafikler sığmaz - sadece temel sayıları gösteren sade düzen
+        Tab::Overview if use_compact_layout(size, app.compact) => draw_compact(f, main_layout[2], app),
+        Tab::Overview => draw_grid(f, main_layout[2], app),
+        Tab::System => draw_system_tab(f, main_layout[2], app),
+        Tab::Processes => draw_process_tab(f, main_layout[2], app),
+    }
     
-    // Sol paneli dikey olarak böl
-    let left_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage(50), // CPU bölümü
-            Constraint::Percentage(50), // RAM bölümü
-        ])
-        .split(content_layout[0]);
+    // Alt bilgi çubuğunu çiz
+    draw_footer(f, main_layout[3], app);
+    
+    // Popup'lar en son çizilir - diğer her şeyin üstünde görünmeleri için
+    if let Some(popup) = &app.kill_popup {
+        draw_kill_popup(f, size, popup, app.theme());
+    }
+    if let Some(popup) = &app.bulk_kill_popup {
+        draw_bulk_kill_popup(f, size, popup, app);
+    }
+    if let Some(details) = &app.detail_popup {
+        draw_detail_popup(f, size, details, app);
+    }
+    if app.memory_popup {
+        draw_memory_popup(f, size, app);
+    }
+    if app.offenders_popup {
+        draw_offenders_popup(f, size, app);
+    }
+    if app.health_popup {
+        draw_health_popup(f, size, app);
+    }
+    if app.diff_popup {
+        draw_diff_popup(f, size, app);
+    }
+    if let Some(offset) = app.events_popup {
+        draw_events_popup(f, size, app, offset);
+    }
+    if let Some(selected) = app.panels_popup {
+        draw_panels_popup(f, size, app, selected);
+    }
+    if app.quit_prompt {
+        draw_quit_popup(f, size, app.theme());
+    }
+}
+
+// Tek bir kareyi bellekteki bir terminale çizip düz metin olarak döndür
+// Testlerde ekran çıktısını karşılaştırmak ve --snapshot modu için - düzen kodu tekrarlanmaz.
+// Çift genişlikli karakterlerin (emoji) kapattığı hücreler atlanır, satır sonu boşlukları kırpılır
+pub fn render_to_string(app: &App, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).expect("test backend cannot fail");
+    terminal.draw(|f| ui(f, app)).expect("test backend cannot fail");
     
-    // CPU ve RAM bölümlerini çiz
-    draw_cpu_section(f, left_layout[0], app);
-    draw_memory_section(f, left_layout[1], app);
+    let buffer = terminal.backend().buffer();
+    let mut lines = Vec::with_capacity(height as usize);
+    for y in 0..height {
+        let mut line = String::new();
+        let mut hidden = 0;
+        for x in 0..width {
+            if hidden > 0 {
+                hidden -= 1;
+                continue;
+            }
+            let symbol = buffer.get(x, y).symbol();
+            line.push_str(symbol);
+            hidden = symbol.width().saturating_sub(1);
+        }
+        lines.push(line.trim_end().to_string());
+    }
+    lines.join("\n")
+}
+
+// Arayüzün çizilebileceği en küçük terminal boyutu
+// Yükseklik: başlık (3) + sekmeler (1) + alt bilgi (3) + en az birkaç satır içerik
+const MIN_WIDTH: u16 = 40;
+const MIN_HEIGHT: u16 = 12;
+
+// Terminal çok küçükken gösterilen mesaj - alan ne kadar küçük olursa olsun güvenle çizilir
+fn draw_too_small(f: &mut Frame, area: Rect) {
+    let message = Paragraph::new(format!(
+        "Terminal too small\n{}x{} (need {}x{})",
+        area.width, area.height, MIN_WIDTH, MIN_HEIGHT
+    ))
+    .alignment(Alignment::Center)
+    .style(Style::default().fg(Color::Red))
+    .wrap(Wrap { trim: true });
+    f.render_widget(message, area);
+}
+
+// Bu boyutun altında grid düzeni iç içe bölmelerle ezilir, gauge'lar kırpılır
+const COMPACT_WIDTH: u16 = 100;
+const COMPACT_HEIGHT: u16 = 30;
+
+// Sade düzen kullanılsın mı - kullanıcı açtıysa ya da terminal çok küçükse
+fn use_compact_layout(size: Rect, forced: bool) -> bool {
+    forced || size.width < COMPACT_WIDTH || size.height < COMPACT_HEIGHT
+}
+
+// Sade düzen - grafik yok; CPU%, RAM%, ağ hızı ve en yoğun 3 process
+fn draw_compact(f: &mut Frame, area: Rect, app: &App) {
+    let theme = app.theme();
+    let block = Block::default()
+        .title(panel_title("Overview (compact)".to_string(), app.data_gaps.cpu || app.data_gaps.memory))
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.border));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
     
-    // Sağ paneli dikey olarak böl
-    let right_layout = Layout::default()
+    let rows = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Percentage(60), // Process listesi
-            Constraint::Percentage(40), // Ağ trafiği
+            Constraint::Length(1), // CPU
+            Constraint::Length(1), // RAM
+            Constraint::Length(1), // Ağ
+            Constraint::Length(1), // Boşluk
+            Constraint::Min(0),    // Process'ler
         ])
-        .split(content_layout[1]);
+        .split(inner);
+    
+    let memory_percent = app.memory_usage_percent();
+    let gauges = [
+        (format!("CPU {:.1}%", app.cpu_average), app.cpu_average),
+        (format!("RAM {:.1}%", memory_percent), memory_percent),
+    ];
+    for (row, (label, percent)) in gauges.into_iter().enumerate() {
+        draw_gauge(f, rows[row], app, percent, usage_color(percent, &app.color_thresholds), label);
+    }
     
-    // Process ve ağ bölümlerini çiz
-    draw_process_section(f, right_layout[0], app);
-    draw_network_section(f, right_layout[1], app);
+    let (download_speed, upload_speed) = app.network_history.back().copied().unwrap_or((0, 0));
+    let glyphs = glyphs(app);
+    let network = Paragraph::new(format!(
+        "{} {}  {} {}",
+        glyphs.download,
+        app.format_network_rate(download_speed),
+        glyphs.upload,
+        app.format_network_rate(upload_speed)
+    ))
+    .style(Style::default().fg(theme.text));
+    f.render_widget(network, rows[2]);
     
-    // Alt bilgi çubuğunu çiz
-    draw_footer(f, main_layout[2]);
+    let items: Vec<ListItem> = app
+        .top_processes()
+        .into_iter()
+        .take(3)
+        .map(|process| {
+            ListItem::new(format!(
+                "{:>5.1}% {:>9}  {}",
+                process.cpu_usage,
+                app.format_bytes(process.memory),
+                process.name
+            ))
+        })
+        .collect();
+    let processes = List::new(items).style(Style::default().fg(theme.text));
+    f.render_widget(processes, rows[4]);
+}
+
+// Normal grid düzeni - solda CPU ve RAM, sağda process, ağ, disk I/O ve uyarılar
+// Constraint'ler görünen panellerden kurulur: gizlenen panelin yeri aynı sütundakilere
+// dağıtılır, bir sütun tamamen boşsa diğeri tüm genişliği alır
+fn draw_grid(f: &mut Frame, area: Rect, app: &App) {
+    // (panel, sütun içindeki pay) - sağ sütunda process listesi diğerlerinin iki katı
+    let visible = |panels: &[(Panel, u16)]| -> Vec<(Panel, u16)> {
+        panels.iter().copied().filter(|(panel, _)| app.panels.panel_visible(*panel)).collect()
+    };
+    let left = visible(&[(Panel::Cpu, 1), (Panel::Memory, 1)]);
+    let right = visible(&[(Panel::Processes, 2), (Panel::Network, 1), (Panel::DiskIo, 1), (Panel::Alerts, 1)]);
+    
+    // Sütunların genişlik payı 60/40
+    let columns: Vec<(Vec<(Panel, u16)>, u16)> = [(left, 60), (right, 40)]
+        .into_iter()
+        .filter(|(panels, _)| !panels.is_empty())
+        .collect();
+    if columns.is_empty() {
+        draw_all_hidden(f, area, app);
+        return;
+    }
+    
+    let column_areas = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(columns.iter().map(|(_, weight)| Constraint::Fill(*weight)))
+        .split(area);
+    
+    for ((panels, _), column_area) in columns.iter().zip(column_areas.iter()) {
+        let panel_areas = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(panels.iter().map(|(_, weight)| Constraint::Fill(*weight)))
+            .split(*column_area);
+        for ((panel, _), panel_area) in panels.iter().zip(panel_areas.iter()) {
+            draw_panel(f, *panel_area, app, *panel);
+        }
+    }
+}
+
+// Bütün paneller gizlendiyse boş ekran yerine nasıl geri açılacağını göster
+fn draw_all_hidden(f: &mut Frame, area: Rect, app: &App) {
+    let message = Paragraph::new("All panels are hidden - press V to choose panels")
+        .alignment(Alignment::Center)
+        .style(Style::default().fg(app.theme().muted))
+        .block(Block::default().borders(Borders::ALL).style(Style::default().fg(app.theme().border)));
+    f.render_widget(message, area);
+}
+
+// Tek bir paneli verilen alana çizer - grid ve zoom modu aynı fonksiyonları kullanır
+// Böylece büyütülen panel de kendi layout'unu alanın boyutuna göre yeniden hesaplar
+fn draw_panel(f: &mut Frame, area: Rect, app: &App, panel: Panel) {
+    let focused = app.focused_panel == panel;
+    match panel {
+        Panel::Cpu => draw_cpu_section(f, area, app, focused),
+        Panel::Memory => draw_memory_section(f, area, app, focused),
+        Panel::Processes => draw_process_section(f, area, app, focused),
+        Panel::Network => draw_network_section(f, area, app, focused),
+        Panel::DiskIo => draw_disk_io_section(f, area, app, focused),
+        Panel::Alerts => draw_alerts_section(f, area, app, focused),
+    }
+}
+
+// Panel çerçevesinin stili - ilgili uyarı aktifse çerçeve kırmızı yanıp söner
+// Yanıp sönme fazını saatin milisaniyesinden alıyoruz, ekstra state tutmaya gerek yok
+// Panel başlığı - veri eksik geldiyse panelin son iyi değerleri gösterdiği belirtilir
+fn panel_title(title: String, unavailable: bool) -> Line<'static> {
+    let mut spans = vec![Span::raw(title)];
+    if unavailable {
+        spans.push(Span::styled(
+            " [data unavailable]",
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        ));
+    }
+    Line::from(spans)
+}
+
+// Emoji ve özel karakterler - ascii_only açıkken sade karşılıkları kullanılır
+// Emoji çoğu fontta çift genişlikte, eski konsollarda ise kutu (tofu) olarak görünür
+struct Glyphs {
+    logo: &'static str,
+    download: &'static str,
+    upload: &'static str,
+    warning: &'static str,
+    sorted: &'static str,
+    block: &'static str,
+    shade: &'static str, // Yığılmış bellek çubuğunda geri kazanılabilir kısım
+    tree_open: &'static str,   // Ağaçta çocukları görünen düğüm
+    tree_closed: &'static str, // Ağaçta çocukları gizli düğüm
+    heartbeat: &'static [&'static str], // Başlıktaki nabız göstergesinin kareleri
+    increase: &'static str, // Referansa göre artış / azalış
+    decrease: &'static str,
+    marker: symbols::Marker,
+}
+
+const UNICODE_GLYPHS: Glyphs = Glyphs {
+    logo: "🖥️  ",
+    download: "⬇️",
+    upload: "⬆️",
+    warning: "⚠",
+    sorted: "▼",
+    block: "█",
+    shade: "░",
+    tree_open: "▾ ",
+    tree_closed: "▸ ",
+    heartbeat: &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+    increase: "↑",
+    decrease: "↓",
+    marker: symbols::Marker::Braille, // Braille karakterler ile yumuşak çizgi
+};
+
+const ASCII_GLYPHS: Glyphs = Glyphs {
+    logo: "",
+    download: "RX",
+    upload: "TX",
+    warning: "!",
+    sorted: "v",
+    block: "#",
+    shade: ":",
+    tree_open: "- ",
+    tree_closed: "+ ",
+    heartbeat: &["|", "/", "-", "\\"],
+    increase: "+",
+    decrease: "-",
+    marker: symbols::Marker::Dot,
+};
+
+fn glyphs(app: &App) -> &'static Glyphs {
+    if app.ascii_only { &ASCII_GLYPHS } else { &UNICODE_GLYPHS }
+}
+
+// Yüzde çubuğu - Gauge dolu kısmı blok karakterle çizdiği için ASCII modunda
+// aynı çubuk glyph'lerle düz metin olarak üretilir
+fn draw_gauge(f: &mut Frame, area: Rect, app: &App, percent: f32, color: Color, label: String) {
+    let style = Style::default().fg(color);
+    if app.ascii_only {
+        let bar = ascii_gauge(percent, area.width as usize, &label, glyphs(app).block);
+        f.render_widget(Paragraph::new(bar).style(style), area);
+    } else {
+        let gauge = Gauge::default()
+            .gauge_style(style)
+            .ratio((percent as f64 / 100.0).clamp(0.0, 1.0))
+            .label(label);
+        f.render_widget(gauge, area);
+    }
+}
+
+// Etiket Gauge'daki gibi ortalanır, çubuk etiketin altında kalan yerlerde görünür
+fn ascii_gauge(percent: f32, width: usize, label: &str, cell: &str) -> String {
+    let filled = ((percent.clamp(0.0, 100.0) / 100.0) * width as f32).round() as usize;
+    let label: Vec<char> = label.chars().take(width).collect();
+    let start = (width - label.len()) / 2;
+    (0..width)
+        .map(|x| match label.get(x.wrapping_sub(start)) {
+            Some(c) if x >= start => c.to_string(),
+            _ if x < filled => cell.to_string(),
+            _ => " ".to_string(),
+        })
+        .collect()
+}
+
+// Yığılmış bellek çubuğunda kullanılan ve kullanılabilir kısımların hücre sayısı
+// İkisi ayrı yuvarlanınca toplam genişliği aşabilir - kullanılabilir kısım kalanla sınırlanır
+fn stacked_bar_cells(used: u64, available: u64, total: u64, width: usize) -> (usize, usize) {
+    if total == 0 {
+        return (0, 0);
+    }
+    let cells = |bytes: u64| ((bytes.min(total) as f64 / total as f64) * width as f64).round() as usize;
+    let used_cells = cells(used);
+    (used_cells, cells(available).min(width - used_cells))
+}
+
+// ASCII modunda ok tuşları yazıyla
+fn ascii_hints(hints: String) -> String {
+    hints.replace("↑/↓", "Up/Down").replace("←/→", "Left/Right")
+}
+
+// Ana ekrandaki panellerin çerçevesi
+// Odaklanılan panel (Tab ile değişir) kalın ve vurgu renginde çizilir - oklar ve Enter ona gider
+// İlgili uyarı aktifse çerçeve kırmızı yanıp söner; yanıp sönme odak renginin de önüne geçer
+fn panel_block(app: &App, focused: bool, alert: Option<AlertKind>) -> Block<'static> {
+    let theme = app.theme();
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.border));
+    if focused {
+        block = block
+            .border_type(BorderType::Thick)
+            .border_style(Style::default().fg(theme.accent));
+    }
+    let blink_on = Local::now().timestamp_subsec_millis() < 500;
+    if alert.is_some_and(|kind| app.alerts.is_active(kind)) && blink_on {
+        block = block.border_style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD));
+    }
+    block
 }
 
 // Üst başlık bölümünü çizen fonksiyon
 fn draw_header(f: &mut Frame, area: Rect, app: &App) {
+    let theme = app.theme();
     // Sistem uptime'ını formatla - saniyeden okunabilir formata
     // Modern API'de uptime() artık instance method
-    let uptime = app.system.uptime();
+    let uptime = app.sample.uptime;
     let hours = uptime / 3600;
     let minutes = (uptime % 3600) / 60;
     let seconds = uptime % 60;
     
+    // Uzun süredir yeniden başlatılmamış sistem kaçırılmış çekirdek güncellemesi demek olabilir
+    let uptime_style = match categorize_uptime(uptime, app.uptime_warning_days) {
+        UptimeCategory::Normal => Style::default(),
+        UptimeCategory::Long => Style::default().fg(Color::Yellow),
+        UptimeCategory::VeryLong => Style::default().fg(Color::Red),
+    };
+    
     // Başlık metnini oluştur - uygulamanın kimliği
-    let header_text = format!(
-        "🖥️  Rust System Monitor | Uptime: {:02}:{:02}:{:02} | CPU Cores: {} | Avg Usage: {:.1}%",
-        hours, minutes, seconds,
-        app.cpu_count(),
-        app.cpu_average
-    );
+    let mut spans = vec![
+        Span::raw(format!("{}Rust System Monitor | Uptime: ", glyphs(app).logo)),
+        Span::styled(format!("{:02}:{:02}:{:02}", hours, minutes, seconds), uptime_style),
+    ];
+    if app.sample.host.reboot_pending {
+        spans.push(Span::styled(" [reboot pending]", Style::default().fg(Color::Yellow)));
+    }
+    spans.push(Span::raw(format!(
+        " | {} | Avg Usage: {:.1}% | Focus: {}{}",
+        core_count_text(app.cpu_count(), app.sample.host.physical_core_count),
+        app.cpu_average,
+        app.focused_panel.title(),
+        if app.zoomed { " (zoom)" } else { "" }
+    )));
+    // Dolmak üzere olan disk - disk paneline bakmadan fark edilsin
+    if let Some(disk) = app.worst_disk() {
+        spans.push(Span::raw(" | "));
+        spans.push(Span::styled(
+            format!("{} {} at {:.0}%", glyphs(app).warning, disk.mount_point, disk.usage_percent),
+            Style::default().fg(disk_usage_color(categorize_disk_usage(disk.usage_percent, &app.color_thresholds))),
+        ));
+    }
+    // Kayıt veya oynatma sürüyorsa canlı veriyle karıştırılmasın diye belirt
+    if let Some(source) = app.source_description() {
+        spans.push(Span::raw(format!(" | {}", source)));
+    }
+    // Uzak bağlantı koptuysa ekrandaki değerler donmuştur - gözden kaçmasın
+    if let Some(warning) = app.source_warning() {
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(
+            format!(" {} {} ", glyphs(app).warning, warning),
+            Style::default().fg(Color::Black).bg(Color::Yellow),
+        ));
+    }
     
     // Paragraph widget'ı - metin göstermek için temel bileşen
     // Style ile renk ve formatı belirliyoruz
-    let header = Paragraph::new(header_text)
-        .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+    let header = Paragraph::new(Line::from(spans))
+        .style(Style::default().fg(theme.title).add_modifier(Modifier::BOLD))
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .style(Style::default().fg(Color::Blue))
+                .style(Style::default().fg(theme.border))
+                // Duvar saati her çizimde okunur - veri donsa bile saat ilerler
+                // Yanındaki nabız ise sadece yeni örnek geldikçe döner; duran nabız donmuş veriyi gösterir
+                .title(Title::from(format!(" {}{} ", heartbeat_frame(app), Local::now().format("%H:%M:%S"))).alignment(Alignment::Right))
         );
     
     f.render_widget(header, area);
 }
 
+// Yenileme sayacına göre nabız karesi (ardından boşluk) - kapalıysa boş
+fn heartbeat_frame(app: &App) -> String {
+    if !app.heartbeat {
+        return String::new();
+    }
+    let frames = glyphs(app).heartbeat;
+    format!("{} ", frames[(app.refresh_ticks % frames.len() as u64) as usize])
+}
+
+// "CPU: 16 threads / 8 cores" - hyperthreading'li makinede CPU0..CPU15'in nedeni anlaşılsın
+// Fiziksel çekirdek sayısı okunamayan platformlarda sadece mantıksal sayı gösterilir
+fn core_count_text(logical: usize, physical: Option<usize>) -> String {
+    match physical {
+        Some(physical) => format!("CPU: {} threads / {} cores", logical, physical),
+        None => format!("CPU Cores: {}", logical),
+    }
+}
+
+// Sekme çubuğu - aktif sekme vurgulanır
+fn draw_tabs(f: &mut Frame, area: Rect, app: &App) {
+    let theme = app.theme();
+    let titles: Vec<String> = Tab::ALL
+        .iter()
+        .enumerate()
+        .map(|(i, tab)| format!("{} {}", i + 1, tab.title()))
+        .collect();
+    
+    // Sekme başlıkları + ayraçlar ("│" ve iki yanındaki boşluklar) + baştaki/sondaki boşluk
+    let tabs_width = titles.iter().map(|title| title.chars().count() as u16 + 3).sum::<u16>() + 1;
+    let tabs = Tabs::new(titles)
+        .select(app.active_tab.index())
+        .style(Style::default().fg(theme.muted))
+        .highlight_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+    
+    f.render_widget(tabs, area);
+    
+    // Sekmelerin sağında, sağa yaslı renkli sağlık şeridi - kalan yere sığdığı kadar
+    // Ekran donukken yerini donma nedenine bırakır; donuk bir sağlık durumu yanıltıcı olurdu
+    let width = area.width.saturating_sub(tabs_width) as usize;
+    let (text, color) = if app.paused {
+        let color = if app.pause_reason.is_some() { Color::Red } else { Color::Yellow };
+        (pause_banner_text(app.pause_reason.as_deref(), width), color)
+    } else {
+        let health = app.health();
+        (health_banner_text(&health, width), health_color(health.level))
+    };
+    let text_width = text.chars().count() as u16;
+    let banner = Paragraph::new(text).style(
+        Style::default()
+            .fg(Color::Black)
+            .bg(color)
+            .add_modifier(Modifier::BOLD),
+    );
+    f.render_widget(banner, Rect { x: area.right() - text_width, width: text_width, ..area });
+}
+
+fn health_color(level: HealthLevel) -> Color {
+    match level {
+        HealthLevel::Good => Color::Green,
+        HealthLevel::Warning => Color::Yellow,
+        HealthLevel::Critical => Color::Red,
+    }
+}
+
+// " Health: Warning - memory 87%, /data 90% full (H: details) " - nedenler sığdığı kadar eklenir
+fn health_banner_text(health: &Health, width: usize) -> String {
+    let mut text = format!(" Health: {}", health.level.label());
+    let mut shown = 0;
+    for factor in &health.factors {
+        let separator = if shown == 0 { " - " } else { ", " };
+        // Sona gelecek "+N" ve kapanış boşluğu için yer bırak
+        if text.chars().count() + separator.len() + factor.reason.chars().count() + 6 > width {
+            break;
+        }
+        text.push_str(separator);
+        text.push_str(&factor.reason);
+        shown += 1;
+    }
+    if shown < health.factors.len() {
+        text.push_str(&format!(" +{}", health.factors.len() - shown));
+    }
+    text.push(' ');
+    text.chars().take(width).collect()
+}
+
+// " PAUSED: CPU usage above 90% for 5s (p: resume) " - neden sığmazsa kısaltılır, tuş ipucu kalır
+fn pause_banner_text(reason: Option<&str>, width: usize) -> String {
+    let hint = " (p: resume) ";
+    let Some(reason) = reason else {
+        return format!(" PAUSED{hint}").chars().take(width).collect();
+    };
+    let room = width.saturating_sub(" PAUSED: ".len() + hint.len());
+    let reason = if reason.chars().count() > room {
+        let kept: String = reason.chars().take(room.saturating_sub(1)).collect();
+        format!("{kept}…")
+    } else {
+        reason.to_string()
+    };
+    format!(" PAUSED: {reason}{hint}").chars().take(width).collect()
+}
+
 // CPU bölümünü çizen fonksiyon - en karmaşık kısım
-fn draw_cpu_section(f: &mut Frame, area: Rect, app: &App) {
-    // CPU alanını yatay olarak böl
+fn draw_cpu_section(f: &mut Frame, area: Rect, app: &App, focused: bool) {
+    let show_gauges = app.panels.is_visible(Section::CpuGauges);
+    let show_chart = app.panels.is_visible(Section::CpuChart);
+    
+    // CPU alanını yatay olarak böl - biri gizliyse diğeri tüm alanı alır
     let cpu_layout = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
-            Constraint::Percentage(30), // CPU gauge'lar
-            Constraint::Percentage(70), // CPU grafiği
+            Constraint::Fill(if show_gauges { 30 } else { 0 }), // CPU gauge'lar
+            Constraint::Fill(if show_chart { 70 } else { 0 }),  // CPU grafiği
         ])
         .split(area);
     
-    // Sol taraf: Her çekirdek için gauge çiz
-    draw_cpu_gauges(f, cpu_layout[0], app);
+    // Sol taraf: Her çekirdek için gauge çiz, altında (varsa) user/system dağılımı
+    match &app.sample.cpu_breakdown {
+        _ if !show_gauges => {}
+        Some(breakdown) => {
+            let left = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(3), Constraint::Length(4)])
+                .split(cpu_layout[0]);
+            draw_cpu_gauges(f, left[0], app, focused);
+            draw_cpu_breakdown(f, left[1], app, breakdown, focused);
+        }
+        None => draw_cpu_gauges(f, cpu_layout[0], app, focused),
+    }
     
     // Sağ taraf: CPU kullanım grafiği
-    draw_cpu_chart(f, cpu_layout[1], app);
+    if show_chart {
+        draw_cpu_chart(f, cpu_layout[1], app, focused);
+    }
 }
 
 // CPU gauge'larını çizen fonksiyon
-fn draw_cpu_gauges(f: &mut Frame, area: Rect, app: &App) {
+fn draw_cpu_gauges(f: &mut Frame, area: Rect, app: &App, focused: bool) {
+    if app.core_heatmap {
+        draw_core_heatmap(f, area, app, focused);
+        return;
+    }
+    
     let current_usage = app.current_cpu_usage();
-    let cpu_count = current_usage.len();
+    // NUMA düğümü birden fazlaysa düğüm başlıkları da birer satır kaplar
+    let rows = app.cpu_rows();
+    let row_count = rows.len();
     
-    // Her çekirdek için bir satır ayırıyoruz
-    // min(cpu_count, area_height - 2) ile sınırları kontrol ediyoruz
+    // Her satır için bir satır ayırıyoruz
+    // min(row_count, area_height - 2) ile sınırları kontrol ediyoruz
     let available_height = area.height.saturating_sub(2) as usize; // Border için 2 çıkar
-    let visible_cpus = cpu_count.min(available_height);
+    let visible_rows = row_count.min(available_height);
+    
+    // Kaydırma miktarını son sayfanın ötesine geçmeyecek şekilde sınırla
+    // Zoom ile alan büyüdüğünde daha fazla çekirdek sığar, başlangıç noktası da geri çekilir
+    let first_row = app.cpu_scroll.min(row_count - visible_rows);
     
-    // Dinamik constraint'ler oluştur - çekirdek sayısına göre
-    let constraints: Vec<Constraint> = (0..visible_cpus)
+    // Dinamik constraint'ler oluştur - satır sayısına göre
+    let constraints: Vec<Constraint> = (0..visible_rows)
         .map(|_| Constraint::Length(1))
         .collect();
     
@@ -152,167 +658,437 @@ fn draw_cpu_gauges(f: &mut Frame, area: Rect, app: &App) {
                 }
             );
         
-        // Her çekirdek için gauge çiz
-        for (i, &usage) in current_usage.iter().take(visible_cpus).enumerate() {
-            // Kullanım yüzdesine göre renk belirleme - görsel feedback
-            let color = match usage as u8 {
-                0..=50 => Color::Green,    // Düşük kullanım - yeşil
-                51..=80 => Color::Yellow,  // Orta kullanım - sarı  
-                81..=100 => Color::Red,    // Yüksek kullanım - kırmızı
-                _ => Color::White,
-            };
-            
-            // Gauge widget - progress bar benzeri
-            let gauge = Gauge::default()
-                .block(Block::default())
-                .gauge_style(Style::default().fg(color))
-                .percent(usage as u16)
-                .label(format!("CPU{}: {:.1}%", i, usage));
-            
-            f.render_widget(gauge, gauge_layout[i]);
+        // Her çekirdek için gauge, her düğüm için ortalamalı bir başlık çiz
+        for (line, row) in rows.iter().skip(first_row).take(visible_rows).enumerate() {
+            match row {
+                CpuRow::Node { id, cores } => {
+                    let average = cores.iter().filter_map(|&i| current_usage.get(i)).sum::<f32>()
+                        / cores.len() as f32;
+                    let header = Paragraph::new(format!(
+                        "Node {} ({} cores, avg {:.1}%)",
+                        id,
+                        cores.len(),
+                        average
+                    ))
+                    .style(Style::default().fg(app.theme().accent).add_modifier(Modifier::BOLD));
+                    f.render_widget(header, gauge_layout[line]);
+                }
+                CpuRow::Core(i) => {
+                    let usage = current_usage.get(*i).copied().unwrap_or(0.0);
+                    let color = if app.idle_cores == IdleCores::Dim && usage < app.idle_core_threshold {
+                        app.theme().muted
+                    } else {
+                        usage_color(usage, &app.color_thresholds)
+                    };
+                    draw_gauge(f, gauge_layout[line], app, usage, color, cpu_gauge_label(app, *i, usage));
+                }
+                CpuRow::Idle { count } => {
+                    let summary = Paragraph::new(format!(
+                        "{} {} < {}%",
+                        count,
+                        if *count == 1 { "core" } else { "cores" },
+                        app.idle_core_threshold
+                    ))
+                    .style(Style::default().fg(app.theme().muted));
+                    f.render_widget(summary, gauge_layout[line]);
+                }
+            }
         }
     }
     
+    let mut hits = app.hit_areas.get();
+    hits.cpu_gauges = Some(area);
+    app.hit_areas.set(hits);
+    
     // Ana border'ı çiz
-    let block = Block::default()
-        .title("CPU Cores")
-        .borders(Borders::ALL)
-        .style(Style::default().fg(Color::Blue));
+    let block = panel_block(app, focused, Some(AlertKind::HighCpu))
+        .title(panel_title("CPU Cores".to_string(), app.data_gaps.cpu));
+    
+    f.render_widget(block, area);
+}
+
+// Tüm çekirdekler tek bakışta - her hücre bir çekirdek, renk gauge'larla aynı eşiklerden
+// Çekirdek sayısı genişliği aşarsa alt satırlara devam eder
+fn draw_core_heatmap(f: &mut Frame, area: Rect, app: &App, focused: bool) {
+    let block = panel_block(app, focused, Some(AlertKind::HighCpu))
+        .title(panel_title("CPU Cores (heatmap)".to_string(), app.data_gaps.cpu));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
     
+    let usage = app.current_cpu_usage();
+    f.render_widget(Paragraph::new(heatmap_lines(&usage, inner.width, &app.color_thresholds, glyphs(app).block)), inner);
+}
+
+// Çekirdek kullanımlarını genişliğe göre satırlara bölünmüş renkli bloklara çevir
+fn heatmap_lines(usage: &[f32], width: u16, thresholds: &ColorThresholds, cell: &'static str) -> Vec<Line<'static>> {
+    if width == 0 {
+        return Vec::new();
+    }
+    usage
+        .chunks(width as usize)
+        .map(|row| {
+            Line::from(
+                row.iter()
+                    .map(|&core| Span::styled(cell, Style::default().fg(usage_color(core, thresholds))))
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect()
+}
+
+// CPU zamanı dağılımı - yığılmış tek satırlık çubuk ve altında yüzdeler
+fn draw_cpu_breakdown(f: &mut Frame, area: Rect, app: &App, breakdown: &CpuBreakdown, focused: bool) {
+    let theme = app.theme();
+    let block = panel_block(app, focused, None).title("CPU Time");
+    let inner = block.inner(area);
     f.render_widget(block, area);
+    
+    let parts = [
+        ("usr", breakdown.user, Color::Green),
+        ("sys", breakdown.system, Color::Red),
+        ("io", breakdown.iowait, Color::Yellow),
+        ("steal", breakdown.steal, Color::Magenta),
+    ];
+    
+    // Her parçanın genişliği yüzdesiyle orantılı; kalan kısım boşta geçen zaman
+    let width = inner.width as f32;
+    let bar: Vec<Span> = parts
+        .iter()
+        .map(|(_, percent, color)| {
+            let cells = (percent / 100.0 * width).round() as usize;
+            Span::styled(glyphs(app).block.repeat(cells), Style::default().fg(*color))
+        })
+        .collect();
+    let legend: Vec<Span> = parts
+        .iter()
+        .map(|(label, percent, color)| Span::styled(format!("{} {:.0}% ", label, percent), Style::default().fg(*color)))
+        .chain(std::iter::once(Span::styled(
+            format!("idle {:.0}%", breakdown.idle),
+            Style::default().fg(theme.muted),
+        )))
+        .collect();
+    
+    f.render_widget(Paragraph::new(vec![Line::from(bar), Line::from(legend)]), inner);
+}
+
+// Kullanım yüzdesine göre renk belirleme - görsel feedback
+// Sınırlar config'deki [thresholds] tablosundan gelir
+fn usage_color(usage: f32, thresholds: &ColorThresholds) -> Color {
+    match categorize_usage(usage, thresholds) {
+        UsageLevel::Low => Color::Green,     // Düşük kullanım - yeşil
+        UsageLevel::Medium => Color::Yellow, // Orta kullanım - sarı
+        UsageLevel::High => Color::Red,      // Yüksek kullanım - kırmızı
+    }
+}
+
+// Çekirdek gauge'unun etiketi - frekans biliniyorsa anlık/en yüksek frekans da eklenir
+// Bazı platformlar (sanal makineler, bazı ARM kartlar) frekansı 0 bildirir, o zaman gizliyoruz
+// Diğerleri boştayken tek başına dolu kalan çekirdek etikette işaretlenir
+fn cpu_gauge_label(app: &App, core: usize, usage: f32) -> String {
+    let current = app.sample.cpu_frequencies.get(core).copied().unwrap_or(0);
+    let max = app.cpu_max_frequencies.get(core).copied().unwrap_or(0);
+    let id = app.cpu_id(core);
+    let pegged = if app.alerts.is_core_pegged(id) { " [pegged]" } else { "" };
+    if current == 0 {
+        format!("CPU{}: {:.1}%{}", id, usage, pegged)
+    } else {
+        format!("CPU{}: {:.1}% {}/{}{}", id, usage, format_frequency(current), format_frequency(max), pegged)
+    }
+}
+
+// Grafiklerin sağ üstünde "updated 2s ago" - veri eskidikçe sayı büyür ve sarıya döner
+// Donukken veya kaynak takıldığında ekrandaki değerlerin canlı olmadığı hemen anlaşılır
+fn sample_age_title(app: &App) -> Title<'static> {
+    let style = if app.is_stale() {
+        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(app.theme().muted)
+    };
+    Title::from(Span::styled(format!(" updated {} ago ", format_age(app.sample_age())), style)).alignment(Alignment::Right)
+}
+
+// "4s", "2m 05s", "1h 03m" - saniye hassasiyeti sadece ilk dakikada anlamlı
+fn format_age(age: Duration) -> String {
+    let secs = age.as_secs();
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m {:02}s", secs / 60, secs % 60),
+        _ => format!("{}h {:02}m", secs / 3600, (secs % 3600) / 60),
+    }
 }
 
 // CPU kullanım grafiğini çizen fonksiyon
-fn draw_cpu_chart(f: &mut Frame, area: Rect, app: &App) {
+fn draw_cpu_chart(f: &mut Frame, area: Rect, app: &App, focused: bool) {
+    let theme = app.theme();
     // Grafik için veri hazırlığı - zaman serisini koordinatlara dönüştür
     if app.cpu_history.is_empty() {
         // Veri yoksa boş grafik göster
-        let block = Block::default()
-            .title("CPU Usage History")
-            .borders(Borders::ALL)
-            .style(Style::default().fg(Color::Blue));
+        let block = panel_block(app, focused, None).title("CPU Usage History");
         f.render_widget(block, area);
         return;
     }
     
-    // Ortalama CPU kullanımı için dataset oluştur
-    let cpu_data: Vec<(f64, f64)> = app.cpu_history
+    // Her zaman noktasında tüm çekirdeklerin ortalamasını al
+    let averages: Vec<f64> = app.cpu_history
         .iter()
+        .map(|cpu_values| (cpu_values.iter().sum::<f32>() / cpu_values.len() as f32) as f64)
+        .collect();
+    
+    // Ortalama CPU kullanımı için dataset oluştur - sadece çizgi yumuşatılır, gauge'lar anlık kalır
+    let cpu_data: Vec<(f64, f64)> = moving_average(&averages, app.cpu_smoothing)
+        .into_iter()
         .enumerate()
-        .map(|(i, cpu_values)| {
-            // Her zaman noktasında tüm çekirdeklerin ortalamasını al
-            let avg = cpu_values.iter().sum::<f32>() / cpu_values.len() as f32;
-            (i as f64, avg as f64)
-        })
+        .map(|(i, avg)| (i as f64, avg))
         .collect();
+    let sample_count = cpu_data.len();
+    let cpu_data = downsample_min_max(cpu_data, area.width);
     
     // Grafik için x ve y eksen sınırlarını belirle
     let max_y = 100.0; // CPU yüzdesi max 100
-    let max_x = app.cpu_history_len as f64;
+    // X ekseni tutulan örnek sayısına göre - geçmiş henüz dolmadıysa çizgi köşeye sıkışmasın
+    let max_x = history_x_bound(sample_count);
     
     // Dataset oluştur - çizgiyi tanımlar
     // Modern ratatui'de marker için symbols modülünü kullanıyoruz
     let dataset = Dataset::default()
         .name("Avg CPU")
-        .marker(symbols::Marker::Braille) // Braille karakterler ile yumuşak çizgi
+        .marker(glyphs(app).marker)
         .style(Style::default().fg(Color::Cyan))
         .data(&cpu_data);
     
     // Chart widget'ı oluştur
     let chart = Chart::new(vec![dataset])
         .block(
-            Block::default()
-                .title("CPU Usage History")
-                .borders(Borders::ALL)
-                .style(Style::default().fg(Color::Blue))
+            panel_block(app, focused, None)
+                .title(format!("CPU Usage History (peak {:.0}%)", app.peaks.cpu_average))
+                .title(sample_age_title(app))
         )
         .x_axis(
             ratatui::widgets::Axis::default()
-                .title("Time")
-                .style(Style::default().fg(Color::Gray))
+                .style(Style::default().fg(theme.muted))
                 .bounds([0.0, max_x])
+                .labels(time_axis_labels(sample_count, app.refresh_ms, area.width))
         )
         .y_axis(
             ratatui::widgets::Axis::default()
                 .title("Usage %")
-                .style(Style::default().fg(Color::Gray))
+                .style(Style::default().fg(theme.muted))
                 .bounds([0.0, max_y])
         );
     
     f.render_widget(chart, area);
 }
 
+// Geçmiş grafiklerinin x ekseni etiketleri: "-60s … now"
+// Ratatui etiketleri eksene eşit aralıkla dağıtır; örnekler de eşit aralıklı olduğu için her
+// etiket gerçekten o kadar önceyi gösterir. Dar panellerde sadece iki uç yazılır
+fn time_axis_labels(sample_count: usize, refresh_ms: u64, width: u16) -> Vec<Span<'static>> {
+    let span_secs = sample_count.saturating_sub(1) as u64 * refresh_ms / 1000;
+    // Henüz bir saniyelik geçmiş yok - etiketlerin hepsi "now" olurdu
+    if span_secs == 0 {
+        return Vec::new();
+    }
+    let count: u64 = if width >= 40 { 4 } else if width >= 24 { 3 } else { 2 };
+    (0..count)
+        .rev()
+        .map(|step| match span_secs * step / (count - 1) {
+            0 => Span::raw("now"),
+            ago => Span::raw(format!("-{}", format_ago(ago))),
+        })
+        .collect()
+}
+
+// Kısa süre yazımı: 45s, 90s, 2m, 2m30s, 1h - iki dakikaya kadar saniye okunması daha kolay
+fn format_ago(secs: u64) -> String {
+    if secs < 120 {
+        return format!("{secs}s");
+    }
+    match (secs / 3600, secs % 3600 / 60, secs % 60) {
+        (0, m, 0) => format!("{m}m"),
+        (0, m, s) => format!("{m}m{s}s"),
+        (h, 0, _) => format!("{h}h"),
+        (h, m, _) => format!("{h}h{m}m"),
+    }
+}
+
+// Kayan ortalama - her nokta kendisi dahil önceki `window` örneğin ortalaması olur
+// Başta yeterli örnek yokken eldekilerin ortalaması alınır; window 0 veya 1 ise veri aynen döner
+fn moving_average(values: &[f64], window: usize) -> Vec<f64> {
+    let window = window.max(1);
+    let mut sum = 0.0;
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| {
+            sum += value;
+            if i >= window {
+                sum -= values[i - window];
+            }
+            sum / (i + 1).min(window) as f64
+        })
+        .collect()
+}
+
+// Uzun geçmişi grafiğin genişliğine indir - sütun başına bir kova, her kovadan en küçük ve en
+// büyük nokta (zaman sırasıyla) kalır. Ortalama almak kısa zirveleri yok ederdi; böylece
+// sıçramalar görünür kalır ve çizilen nokta sayısı geçmişin uzunluğundan bağımsız olur.
+// x değerleri değişmez, eksenler aynı kalır
+fn downsample_min_max(points: Vec<(f64, f64)>, columns: u16) -> Vec<(f64, f64)> {
+    let buckets = columns as usize;
+    if buckets == 0 || points.len() <= buckets * 2 {
+        return points;
+    }
+    let mut result = Vec::with_capacity(buckets * 2);
+    for bucket in 0..buckets {
+        let slice = &points[bucket * points.len() / buckets..(bucket + 1) * points.len() / buckets];
+        let by_y = |a: &&(f64, f64), b: &&(f64, f64)| a.1.total_cmp(&b.1);
+        let (Some(min), Some(max)) = (slice.iter().min_by(by_y), slice.iter().max_by(by_y)) else {
+            continue;
+        };
+        let (first, second) = if min.0 <= max.0 { (min, max) } else { (max, min) };
+        result.push(*first);
+        if second.0 != first.0 {
+            result.push(*second);
+        }
+    }
+    result
+}
+
+// Geçmiş grafiklerinin x ekseni üst sınırı - son örneğin indeksi
+// Tek örnek varken eksen sıfır genişlikte olmasın diye en az 1
+fn history_x_bound(sample_count: usize) -> f64 {
+    sample_count.saturating_sub(1).max(1) as f64
+}
+
 // RAM bölümünü çizen fonksiyon
-fn draw_memory_section(f: &mut Frame, area: Rect, app: &App) {
-    // RAM alanını yatay olarak böl
+fn draw_memory_section(f: &mut Frame, area: Rect, app: &App, focused: bool) {
+    let show_info = app.panels.is_visible(Section::MemoryInfo);
+    let show_chart = app.panels.is_visible(Section::MemoryChart);
+    
+    // RAM alanını yatay olarak böl - biri gizliyse diğeri tüm alanı alır
     let memory_layout = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
-            Constraint::Percentage(50), // RAM bilgileri
-            Constraint::Percentage(50), // RAM grafiği
+            Constraint::Fill(u16::from(show_info)),  // RAM bilgileri
+            Constraint::Fill(u16::from(show_chart)), // RAM grafiği
         ])
         .split(area);
     
     // Sol taraf: RAM bilgileri
-    draw_memory_info(f, memory_layout[0], app);
+    if show_info {
+        draw_memory_info(f, memory_layout[0], app, focused);
+    }
     
     // Sağ taraf: RAM kullanım geçmişi
-    draw_memory_chart(f, memory_layout[1], app);
+    if show_chart {
+        draw_memory_chart(f, memory_layout[1], app, focused);
+    }
 }
 
 // RAM bilgilerini gösteren fonksiyon
-fn draw_memory_info(f: &mut Frame, area: Rect, app: &App) {
-    let used_memory = app.system.used_memory();
-    let total_memory = app.system.total_memory();
+fn draw_memory_info(f: &mut Frame, area: Rect, app: &App, focused: bool) {
+    let theme = app.theme();
+    let used_memory = app.sample.memory_used;
+    let total_memory = app.sample.memory_total;
     let memory_percent = app.memory_usage_percent();
     
     // Swap bilgileri
-    let used_swap = app.system.used_swap();
-    let total_swap = app.system.total_swap();
-    let swap_percent = if total_swap > 0 {
-        (used_swap as f64 / total_swap as f64 * 100.0) as f32
-    } else {
-        0.0
-    };
+    let used_swap = app.sample.swap_used;
+    let total_swap = app.sample.swap_total;
+    let swap_percent = app.swap_usage_percent();
     
-    // RAM bilgilerini formatla
-    let memory_text = format!(
-        "RAM Usage: {:.1}%\n\
-         Used: {}\n\
-         Total: {}\n\
+    // RAM bilgilerini formatla - yüzde ve kullanılan miktar gauge'larda
+    let mut memory_text = format!(
+        "Total: {}\n\
          Free: {}\n\
          \n\
          Swap Usage: {:.1}%\n\
          Used: {}\n\
          Total: {}",
-        memory_percent,
-        App::format_bytes(used_memory),
-        App::format_bytes(total_memory),
-        App::format_bytes(total_memory - used_memory),
+        app.format_bytes(total_memory),
+        app.format_bytes(total_memory - used_memory),
         swap_percent,
-        App::format_bytes(used_swap),
-        App::format_bytes(total_swap)
+        app.format_bytes(used_swap),
+        app.format_bytes(total_swap)
     );
+    // Swap'ten okuma/yazma hızı - dolu ama sessiz bir swap zararsızdır, sürekli trafik değildir
+    if let Some((swap_in, swap_out)) = app.swap_rates {
+        memory_text.push_str(&format!(
+            "\nIn: {}/s  Out: {}/s",
+            app.format_bytes(swap_in),
+            app.format_bytes(swap_out)
+        ));
+    }
+    // zram: swap'e giden verinin sıkıştırılmış hali ve oranı - swap satırları bunu göstermez
+    if let Some(zram) = app.sample.zram {
+        memory_text.push_str(&format!(
+            "\nZram: {} in {}",
+            app.format_bytes(zram.original),
+            app.format_bytes(zram.compressed)
+        ));
+        if let Some(ratio) = zram.ratio() {
+            memory_text.push_str(&format!(" ({ratio:.1}x)"));
+        }
+    }
     
-    let memory_info = Paragraph::new(memory_text)
-        .block(
-            Block::default()
-                .title("Memory Info")
-                .borders(Borders::ALL)
-                .style(Style::default().fg(Color::Blue))
-        )
-        .style(Style::default().fg(Color::White));
+    // Swap sıkışması da bellek sorunudur - iki uyarı da aynı paneli yakar
+    let alert = if app.alerts.is_active(AlertKind::SwapThrashing) {
+        AlertKind::SwapThrashing
+    } else {
+        AlertKind::HighMemory
+    };
+    let block = panel_block(app, focused, Some(alert))
+        .title(panel_title(
+            format!("Memory Info (peak {:.0}%)", app.peaks.memory_percent),
+            app.data_gaps.memory,
+        ));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    
+    // Üstte kullanılan ve kullanılabilir belleği bir arada gösteren çubuk
+    // "Used" önbelleği de sayabilir; gerçek baskıyı "Available" gösterir
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Length(1), Constraint::Min(0)])
+        .split(inner);
+    
+    let available_memory = app.sample.memory_available;
+    let used_color = usage_color(memory_percent, &app.color_thresholds);
+    if available_memory == 0 {
+        // Platform kullanılabilir belleği raporlamıyorsa yığılacak bir şey yok - düz gauge
+        let label = format!("Used {:.1}% ({})", memory_percent, app.format_bytes(used_memory));
+        draw_gauge(f, layout[0], app, memory_percent, used_color, label);
+    } else {
+        // Tek çubukta iki katman: dolu kısım gerçekten ayrılmış bellek, açık gölgeli kısım
+        // önbellek gibi geri kazanılabilir (kullanılabilir) bellek. Altındaki satır açıklamadır
+        let glyphs = glyphs(app);
+        let (used_cells, available_cells) =
+            stacked_bar_cells(used_memory, available_memory, total_memory, layout[0].width as usize);
+        let bar = Line::from(vec![
+            Span::styled(glyphs.block.repeat(used_cells), Style::default().fg(used_color)),
+            Span::styled(glyphs.shade.repeat(available_cells), Style::default().fg(theme.muted)),
+        ]);
+        f.render_widget(Paragraph::new(bar), layout[0]);
+        // Dar panelde de sığsın diye kısa: yüzde ve kullanılabilir miktar
+        let legend = Line::from(vec![
+            Span::styled(format!("{} Used {:.1}%", glyphs.block, memory_percent), Style::default().fg(used_color)),
+            Span::styled(
+                format!("  {} Avail {}", glyphs.shade, app.format_bytes(available_memory)),
+                Style::default().fg(theme.muted),
+            ),
+        ]);
+        f.render_widget(Paragraph::new(legend), layout[1]);
+    }
     
-    f.render_widget(memory_info, area);
+    let memory_info = Paragraph::new(memory_text).style(Style::default().fg(theme.text));
+    f.render_widget(memory_info, layout[2]);
 }
 
 // RAM kullanım grafiğini çizen fonksiyon
-fn draw_memory_chart(f: &mut Frame, area: Rect, app: &App) {
+fn draw_memory_chart(f: &mut Frame, area: Rect, app: &App, focused: bool) {
+    let theme = app.theme();
     if app.memory_history.is_empty() {
-        let block = Block::default()
-            .title("Memory Usage History")
-            .borders(Borders::ALL)
-            .style(Style::default().fg(Color::Blue));
+        let block = panel_block(app, focused, None).title("Memory Usage History");
         f.render_widget(block, area);
         return;
     }
@@ -330,123 +1106,2401 @@ fn draw_memory_chart(f: &mut Frame, area: Rect, app: &App) {
             (i as f64, percent)
         })
         .collect();
+    let sample_count = memory_data.len();
+    let memory_data = downsample_min_max(memory_data, area.width);
     
     let dataset = Dataset::default()
         .name("RAM")
-        .marker(symbols::Marker::Braille) // Güncellenmiş symbol kullanımı
+        .marker(glyphs(app).marker)
         .style(Style::default().fg(Color::Green))
         .data(&memory_data);
     
     let chart = Chart::new(vec![dataset])
         .block(
-            Block::default()
-                .title("Memory Usage History")
-                .borders(Borders::ALL)
-                .style(Style::default().fg(Color::Blue))
+            panel_block(app, focused, None).title("Memory Usage History").title(sample_age_title(app))
         )
         .x_axis(
             ratatui::widgets::Axis::default()
-                .title("Time")
-                .style(Style::default().fg(Color::Gray))
-                .bounds([0.0, app.cpu_history_len as f64])
+                .style(Style::default().fg(theme.muted))
+                .bounds([0.0, history_x_bound(sample_count)])
+                .labels(time_axis_labels(sample_count, app.refresh_ms, area.width))
         )
         .y_axis(
             ratatui::widgets::Axis::default()
                 .title("Usage %")
-                .style(Style::default().fg(Color::Gray))
+                .style(Style::default().fg(theme.muted))
                 .bounds([0.0, 100.0])
         );
     
     f.render_widget(chart, area);
 }
 
-// Process listesini çizen fonksiyon
-fn draw_process_section(f: &mut Frame, area: Rect, app: &App) {
-    let processes = app.top_processes();
+// Process tablosunun kolonları - enum sırası ekrandaki soldan sağa sıradır
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum ProcessColumn {
+    Pid,
+    Name,
+    Cpu,
+    Memory,
+    MemoryPercent,
+    DiskRead,
+    DiskWrite,
+    RunTime,
+    Path,
+}
+
+impl ProcessColumn {
+    // Önem sırası - dar terminallerde sondaki kolonlar önce düşürülür
+    // Disk hızları sadece geniş terminallerde görünür
+    const PRIORITY: [ProcessColumn; 9] = [
+        ProcessColumn::Name,
+        ProcessColumn::Cpu,
+        ProcessColumn::Memory,
+        ProcessColumn::Pid,
+        ProcessColumn::RunTime,
+        ProcessColumn::MemoryPercent,
+        ProcessColumn::Path,
+        ProcessColumn::DiskRead,
+        ProcessColumn::DiskWrite,
+    ];
     
-    // Tablo başlıkları
-    let header = Row::new(vec![
-        Cell::from("Process"),
-        Cell::from("CPU%"),
-        Cell::from("Memory"),
-    ])
-    .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+    // Ad, CPU ve bellek her zaman gösterilir - tablonun anlamlı kalması için gerekli
+    const REQUIRED: usize = 3;
     
-    // Process verilerini tablo satırlarına dönüştür
-    let rows: Vec<Row> = processes
-        .iter()
-        .map(|(name, cpu, memory)| {
-            Row::new(vec![
-                Cell::from(name.clone()),
-                Cell::from(format!("{:.1}", cpu)),
-                Cell::from(App::format_bytes(*memory)),
-            ])
-        })
-        .collect();
+    fn header(&self) -> &'static str {
+        match self {
+            ProcessColumn::Pid => "PID",
+            ProcessColumn::Name => "Process",
+            ProcessColumn::Cpu => "CPU%",
+            ProcessColumn::Memory => "Memory",
+            ProcessColumn::MemoryPercent => "Mem%",
+            ProcessColumn::DiskRead => "Read/s",
+            ProcessColumn::DiskWrite => "Write/s",
+            ProcessColumn::RunTime => "Time",
+            ProcessColumn::Path => "Path",
+        }
+    }
     
-    // Kolon genişliklerini belirle
-    let widths = [
-        Constraint::Percentage(50),
-        Constraint::Percentage(25),
-        Constraint::Percentage(25),
-    ];
+    // Kolonun okunabilir kalması için gereken en az genişlik
+    fn min_width(&self) -> u16 {
+        match self {
+            ProcessColumn::Pid => 7,
+            ProcessColumn::Name => 12,
+            ProcessColumn::Cpu => 6,
+            ProcessColumn::Memory => 10,
+            ProcessColumn::MemoryPercent => 6,
+            ProcessColumn::DiskRead | ProcessColumn::DiskWrite => 10,
+            ProcessColumn::RunTime => 12,
+            ProcessColumn::Path => 20,
+        }
+    }
     
-    // Modern ratatui API'sinde Table::new() artık widths parametresi de alır
-    let table = Table::new(rows, widths)
-        .header(header)
-        .block(
-            Block::default()
-                .title("Top Processes")
-                .borders(Borders::ALL)
-                .style(Style::default().fg(Color::Blue))
-        )
-        .style(Style::default().fg(Color::White));
+    // Ad ve yol kolonları kalan alanı paylaşır, diğerleri sabit genişliktedir
+    fn constraint(&self) -> Constraint {
+        match self {
+            ProcessColumn::Name => Constraint::Fill(1),
+            ProcessColumn::Path => Constraint::Fill(2),
+            other => Constraint::Length(other.min_width()),
+        }
+    }
     
-    f.render_widget(table, area);
+    // Bu kolona göre sıralama yapılıyorsa hangi sıralama kriterine karşılık geldiği
+    fn sort_key(&self) -> Option<ProcessSortKey> {
+        match self {
+            ProcessColumn::Name => Some(ProcessSortKey::Name),
+            ProcessColumn::Cpu => Some(ProcessSortKey::Cpu),
+            ProcessColumn::Memory | ProcessColumn::MemoryPercent => Some(ProcessSortKey::Memory),
+            ProcessColumn::RunTime => Some(ProcessSortKey::RunTime),
+            ProcessColumn::Pid => Some(ProcessSortKey::Pid),
+            ProcessColumn::Path => Some(ProcessSortKey::Path),
+            ProcessColumn::DiskRead => Some(ProcessSortKey::DiskRead),
+            ProcessColumn::DiskWrite => Some(ProcessSortKey::DiskWrite),
+        }
+    }
+    
+    fn cell(&self, process: &ProcessRow, width: u16, app: &App) -> Cell<'static> {
+        match self {
+            ProcessColumn::Pid => Cell::from(process.pid.to_string()),
+            ProcessColumn::Name => match process.tree {
+                // Ağaçta derinlik kadar girinti ve aç/kapa işareti - ad kalan genişliğe kısaltılır
+                Some(node) => {
+                    let marker = match (node.children, node.collapsed) {
+                        (0, _) => "  ",
+                        (_, true) => glyphs(app).tree_closed,
+                        (_, false) => glyphs(app).tree_open,
+                    };
+                    let prefix = format!("{}{}", "  ".repeat(node.depth), marker);
+                    let name = truncate_name(&process.name, (width as usize).saturating_sub(prefix.width()));
+                    Cell::from(format!("{prefix}{name}"))
+                }
+                None => Cell::from(truncate_name(&process.name, width as usize)),
+            },
+            ProcessColumn::Cpu => Cell::from(format!("{:.1}", process.cpu_usage)),
+            ProcessColumn::Memory => Cell::from(app.format_bytes(process.memory)),
+            ProcessColumn::MemoryPercent => Cell::from(format!("{:.1}", process.memory_percent)),
+            ProcessColumn::DiskRead => Cell::from(app.format_bytes(process.disk_read_rate)),
+            ProcessColumn::DiskWrite => Cell::from(app.format_bytes(process.disk_write_rate)),
+            ProcessColumn::RunTime => Cell::from(format_uptime(process.run_time)),
+            ProcessColumn::Path => Cell::from(truncate_path(&process.exe, width as usize)),
+        }
+    }
+}
+
+// Verilen genişliğe sığan kolonları seç
+// Kolonlar önem sırasına göre eklenir, sığmayan ilk opsiyonel kolonda durulur
+fn visible_process_columns(width: u16) -> Vec<ProcessColumn> {
+    let mut used = 0u16;
+    let mut columns = Vec::new();
+    
+    for (i, column) in ProcessColumn::PRIORITY.iter().enumerate() {
+        // Kolonlar arasında 1 karakter boşluk var
+        let needed = column.min_width() + u16::from(!columns.is_empty());
+        if i >= ProcessColumn::REQUIRED && used + needed > width {
+            break;
+        }
+        used += needed;
+        columns.push(*column);
+    }
+    
+    columns.sort();
+    columns
+}
+
+// Uzun adları sondan kısalt ve "…" ekle - ratatui hücreyi karakterin ortasından kesmesin
+// Genişlik terminal kolonudur: CJK ve emoji iki kolon kaplar, birleşik karakterler
+// (aksanlı harfler, bayraklar) tek parça olarak ya tamamen alınır ya hiç alınmaz
+fn truncate_name(name: &str, width: usize) -> String {
+    if name.width() <= width {
+        return name.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+    let mut used = 0;
+    let head: String = name
+        .graphemes(true)
+        .take_while(|grapheme| {
+            used += grapheme.width();
+            used < width
+        })
+        .collect();
+    format!("{}…", head)
+}
+
+// Uzun yolları baştan kısalt - dosya adı yolun sonunda olduğu için sonu daha değerli
+fn truncate_path(path: &str, width: usize) -> String {
+    if path.width() <= width {
+        return path.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+    let mut used = 0;
+    let mut tail: Vec<&str> = path
+        .graphemes(true)
+        .rev()
+        .take_while(|grapheme| {
+            used += grapheme.width();
+            used < width
+        })
+        .collect();
+    tail.reverse();
+    format!("…{}", tail.concat())
+}
+
+// Process listesini çizen fonksiyon
+fn draw_process_section(f: &mut Frame, area: Rect, app: &App, focused: bool) {
+    let processes = app.top_processes();
+    let block = panel_block(app, focused, None)
+        .title(if app.process_filter_enabled { "Top Processes" } else { "Top Processes (unfiltered)" })
+        .title(Title::from(process_states_line(app)).position(Position::Bottom));
+    let mut state = TableState::default().with_selected(Some(app.selected_process));
+    draw_process_table(f, area, app, block, &processes, &mut state);
+}
+
+// Alt çerçevede process sayıları: "312 total, 2 running, 305 sleeping, 1 zombie"
+// Sıfır olan durumlar yazılmaz, zombiler sarı; durum bilgisi olmayan platformlarda sadece toplam
+fn process_states_line(app: &App) -> Line<'static> {
+    let states = app.process_states();
+    let mut spans = vec![Span::raw(format!(" {} total", states.total))];
+    if states.detailed {
+        for (count, label) in [(states.running, "running"), (states.sleeping, "sleeping"), (states.stopped, "stopped")] {
+            if count > 0 {
+                spans.push(Span::raw(format!(", {count} {label}")));
+            }
+        }
+        if states.zombie > 0 {
+            spans.push(Span::raw(", "));
+            spans.push(Span::styled(
+                format!("{} zombie", states.zombie),
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            ));
+        }
+    }
+    spans.push(Span::raw(" "));
+    Line::from(spans)
+}
+
+// Processes sekmesi - tüm process'ler, kaydırma çubuğuyla
+fn draw_process_tab(f: &mut Frame, area: Rect, app: &App) {
+    let processes = app.visible_processes();
+    let selected = app.selected_full_process.min(processes.len().saturating_sub(1));
+    let position = if processes.is_empty() { 0 } else { selected + 1 };
+    let title = match (app.process_tree, app.tree_totals) {
+        (false, _) => "All Processes",
+        (true, false) => "Process Tree",
+        (true, true) => "Process Tree - subtree totals",
+    };
+    let block = Block::default()
+        .title(format!(
+            "{} ({}/{}){}",
+            title,
+            position,
+            processes.len(),
+            if app.process_filter_enabled { "" } else { " (unfiltered)" }
+        ))
+        .borders(Borders::ALL)
+        .style(Style::default().fg(app.theme().border));
+    
+    // Kaydırma bir önceki kareden devam eder - seçim yukarı çıkarken liste zıplamasın
+    let mut state = TableState::default()
+        .with_offset(app.full_process_offset.get())
+        .with_selected(Some(selected));
+    draw_process_table(f, area, app, block, &processes, &mut state);
+    app.full_process_offset.set(state.offset());
+    
+    // Sağ kenarda konum göstergesi - başlık satırının altından başlar
+    let track = Rect {
+        y: area.y + 2,
+        height: area.height.saturating_sub(3),
+        ..area
+    };
+    let mut scrollbar = ScrollbarState::new(processes.len()).position(selected);
+    f.render_stateful_widget(
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .style(Style::default().fg(app.theme().muted)),
+        track,
+        &mut scrollbar,
+    );
+}
+
+// İki process tablosunun ortak çizimi - kolon seçimi, sıralama başlığı ve tıklama alanları
+fn draw_process_table(f: &mut Frame, area: Rect, app: &App, block: Block, processes: &[ProcessRow], state: &mut TableState) {
+    let theme = app.theme();
+    let inner = block.inner(area);
+    
+    // Genişliğe göre hangi kolonların gösterileceğine karar ver
+    let columns = visible_process_columns(inner.width);
+    let widths: Vec<Constraint> = columns.iter().map(|c| c.constraint()).collect();
+    
+    // Kolonların gerçek genişliklerini Table ile aynı şekilde hesaplıyoruz
+    // Yol kolonunu bu genişliğe göre kısaltabilmek için gerekli
+    let column_rects = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(widths.clone())
+        .spacing(1)
+        .split(inner);
+    
+    // Tablo başlıkları
+    // Sıralama yapılan kolonun başlığına ▼ işareti ekliyoruz
+    let header = Row::new(columns.iter().map(|c| {
+        if c.sort_key() == Some(app.process_sort) {
+            Cell::from(format!("{}{}", c.header(), glyphs(app).sorted))
+        } else {
+            Cell::from(c.header())
+        }
+    }))
+        .style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+    
+    // Process verilerini tablo satırlarına dönüştür
+    // Sabitlenen process'ler vurgu renginde ve kalın - ilk N dışından gelse de fark edilsin
+    let pinned_style = Style::default().fg(theme.accent).add_modifier(Modifier::BOLD);
+    let rows: Vec<Row> = processes
+        .iter()
+        .map(|process| {
+            let row = Row::new(
+                columns
+                    .iter()
+                    .zip(column_rects.iter())
+                    .map(|(column, rect)| column.cell(process, rect.width, app)),
+            );
+            if app.pinned_pids.contains(&process.pid) {
+                row.style(pinned_style)
+            } else {
+                row
+            }
+        })
+        .collect();
+    
+    // Modern ratatui API'sinde Table::new() artık widths parametresi de alır
+    // Seçili satır ters renklerle vurgulanır - kill gibi işlemler bu satıra uygulanır
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .style(Style::default().fg(theme.text))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    
+    state.select(state.selected().map(|selected| selected.min(processes.len().saturating_sub(1))));
+    f.render_stateful_widget(table, area, state);
+    
+    // Tablo seçili satırı görünür tutmak için kaydırmış olabilir - tıklama eşlemesi bunu bilmeli
+    let mut hits = app.hit_areas.get();
+    hits.process_rows = Some(Rect {
+        y: inner.y + 1,
+        height: inner.height.saturating_sub(1),
+        ..inner
+    });
+    hits.process_offset = state.offset();
+    // Başlık hücreleri tıklanınca o kolona göre sıralanır
+    hits.process_headers = [None; 9];
+    for ((slot, column), rect) in hits.process_headers.iter_mut().zip(&columns).zip(column_rects.iter()) {
+        *slot = column.sort_key().map(|key| (Rect { height: 1, ..*rect }, key));
+    }
+    app.hit_areas.set(hits);
 }
 
 // Ağ trafiği bölümünü çizen fonksiyon
-fn draw_network_section(f: &mut Frame, area: Rect, app: &App) {
+fn draw_network_section(f: &mut Frame, area: Rect, app: &App, focused: bool) {
+    let theme = app.theme();
     // Son ağ verilerini al
     let (download_speed, upload_speed) = app.network_history
         .back()
         .copied()
         .unwrap_or((0, 0));
     
-    let network_text = format!(
-        "Network Traffic\n\
-         \n\
-         ⬇️ Download: {}/s\n\
-         ⬆️ Upload: {}/s\n\
-         \n\
-         Press 'q' or ESC to quit",
-        App::format_bytes(download_speed),
-        App::format_bytes(upload_speed)
+    // Anlık hızın altında geçmiş penceresinin ortalama/en düşük/en yüksek değerleri
+    let stats_line = |stats: Option<RateStats>| match stats {
+        Some(stats) => format!(
+            "   avg {}  min {}  max {}",
+            app.format_network_rate(stats.average),
+            app.format_network_rate(stats.min),
+            app.format_network_rate(stats.max)
+        ),
+        None => "   avg -  min -  max -".to_string(),
+    };
+    let stats = app.network_stats();
+    
+    let (down, up) = (glyphs(app).download, glyphs(app).upload);
+    let mut network_text = format!(
+        "{down} Download: {}\n\
+         {}\n\
+         {up} Upload: {}\n\
+         {}\n\
+         Since launch: {down} {}  {up} {}",
+        app.format_network_rate(download_speed),
+        stats_line(stats.map(|(download, _)| download)),
+        app.format_network_rate(upload_speed),
+        stats_line(stats.map(|(_, upload)| upload)),
+        app.format_bytes(app.network_received_total.total()),
+        app.format_bytes(app.network_transmitted_total.total())
+    );
+    
+    // Grafik tek bir arayüzü gösteriyorsa adı başlıkta - metin ve zirveler yine tüm arayüzlerin toplamı
+    let mut block = panel_block(app, focused, None)
+        .title(format!(
+            "Network (peak {down} {} {up} {})",
+            app.format_network_rate(app.peaks.download),
+            app.format_network_rate(app.peaks.upload)
+        ));
+    if let Some(name) = &app.selected_interface {
+        block = block.title(Title::from(format!(" chart: {name} ")).alignment(Alignment::Right));
+    }
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    
+    // 'n' ile hızlar yerine arayüzlerin ham sayaçları - `ip -s link` ile karşılaştırmak için
+    let mut text_height = 5;
+    if app.network_counters {
+        let lines: Vec<String> = app.sample.interfaces
+            .iter()
+            .map(|interface| format!(
+                "{}: {down} {}  {up} {}",
+                interface.name,
+                app.format_bytes(interface.received),
+                app.format_bytes(interface.transmitted)
+            ))
+            .collect();
+        network_text = if lines.is_empty() {
+            "No interface counters".to_string()
+        } else {
+            format!("Totals since interface up:\n{}", lines.join("\n"))
+        };
+        text_height = network_text.lines().count() as u16;
+    }
+    
+    // Metnin altında yer varsa (büyütülmüş panel, uzun terminal) hız geçmişi grafiği
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(text_height), Constraint::Min(0)])
+        .split(inner);
+    f.render_widget(Paragraph::new(network_text).style(Style::default().fg(theme.text)), layout[0]);
+    if layout[1].height >= NETWORK_CHART_MIN_HEIGHT {
+        draw_network_chart(f, layout[1], app);
+    }
+}
+
+// Ağ grafiğinin çizilmesi için gereken en az yükseklik - daha azında eksenler çizgiyi ezer
+const NETWORK_CHART_MIN_HEIGHT: u16 = 6;
+
+// Logaritmik eksende bu hızın (byte/s) altı bu değere yuvarlanır - log(0) tanımsız
+const NETWORK_LOG_FLOOR: f64 = 1.0;
+
+// İndirme ve yükleme hızı geçmişi - eksen doğrusal veya logaritmik (L tuşu)
+fn draw_network_chart(f: &mut Frame, area: Rect, app: &App) {
+    let theme = app.theme();
+    let (download, upload, max_y) = network_chart_points(app.network_chart_history(), app.network_log_scale);
+    let sample_count = download.len();
+    let max_x = history_x_bound(sample_count);
+    let (download, upload) = (downsample_min_max(download, area.width), downsample_min_max(upload, area.width));
+    
+    let datasets = vec![
+        Dataset::default()
+            .name(glyphs(app).download.trim_end_matches('\u{fe0f}'))
+            .marker(glyphs(app).marker)
+            .style(Style::default().fg(Color::Green))
+            .data(&download),
+        Dataset::default()
+            .name(glyphs(app).upload.trim_end_matches('\u{fe0f}'))
+            .marker(glyphs(app).marker)
+            .style(Style::default().fg(Color::Magenta))
+            .data(&upload),
+    ];
+    
+    let labels: Vec<Span> = network_axis_labels(max_y, app.network_log_scale)
+        .into_iter()
+        .map(|rate| Span::raw(app.format_network_rate(rate)))
+        .collect();
+    
+    let chart = Chart::new(datasets)
+        .x_axis(
+            ratatui::widgets::Axis::default()
+                .style(Style::default().fg(theme.muted))
+                .bounds([0.0, max_x])
+                .labels(time_axis_labels(sample_count, app.refresh_ms, area.width))
+        )
+        .y_axis(
+            ratatui::widgets::Axis::default()
+                .title(if app.network_log_scale { "log" } else { "" })
+                .style(Style::default().fg(theme.muted))
+                .bounds([0.0, max_y])
+                .labels(labels)
+        );
+    
+    f.render_widget(chart, area);
+}
+
+// Chart widget'ının beklediği (x, y) noktaları
+type ChartPoints = Vec<(f64, f64)>;
+
+// Grafik noktaları ve y ekseninin üst sınırı
+// Logaritmik modda her değer log10(max(hız, taban)) olur ve üst sınır bir sonraki on'un katına
+// yuvarlanır; böylece eksen etiketleri 1 B/s, 10 B/s ... gibi düzgün basamaklara denk gelir
+fn network_chart_points(history: &VecDeque<(u64, u64)>, log_scale: bool) -> (ChartPoints, ChartPoints, f64) {
+    let scale = |rate: u64| {
+        if log_scale {
+            (rate as f64).max(NETWORK_LOG_FLOOR).log10()
+        } else {
+            rate as f64
+        }
+    };
+    let download: ChartPoints = history.iter().enumerate().map(|(i, &(down, _))| (i as f64, scale(down))).collect();
+    let upload: ChartPoints = history.iter().enumerate().map(|(i, &(_, up))| (i as f64, scale(up))).collect();
+    
+    let peak = download.iter().chain(&upload).map(|&(_, y)| y).fold(0.0, f64::max);
+    let max_y = if log_scale {
+        // En az 1 KB/s'lik (10^3) bir eksen - boşta tek bir basamağa sıkışmasın
+        peak.ceil().max(3.0)
+    } else {
+        // Sıfır yükseklikli eksen çizilemez
+        (peak * 1.1).max(1.0)
+    };
+    (download, upload, max_y)
+}
+
+// Y ekseni etiketleri (byte/s) - alt, orta ve üst
+// Logaritmik eksende orta nokta da on'un bir kuvvetidir
+fn network_axis_labels(max_y: f64, log_scale: bool) -> Vec<u64> {
+    if log_scale {
+        let top = max_y.round() as i32;
+        [0, top / 2, top].iter().map(|&power| 10f64.powi(power) as u64).collect()
+    } else {
+        [0.0, max_y / 2.0, max_y].iter().map(|&value| value as u64).collect()
+    }
+}
+
+// Disk I/O bölümünü çizen fonksiyon - okuma ve yazma için iki sparkline
+fn draw_disk_io_section(f: &mut Frame, area: Rect, app: &App, focused: bool) {
+    let (read_speed, write_speed) = app.disk_io_history
+        .back()
+        .copied()
+        .unwrap_or((0, 0));
+    
+    let block = panel_block(app, focused, None)
+        .title(format!(
+            "Disk I/O | R: {}/s W: {}/s",
+            app.format_bytes(read_speed),
+            app.format_bytes(write_speed)
+        ));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    
+    let io_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(inner);
+    
+    // Sparkline sağa yaslı çizer, bu yüzden sadece sığan son örnekleri veriyoruz
+    let width = inner.width as usize;
+    let skip = app.disk_io_history.len().saturating_sub(width);
+    let read_data: Vec<u64> = app.disk_io_history.iter().skip(skip).map(|&(r, _)| r).collect();
+    let write_data: Vec<u64> = app.disk_io_history.iter().skip(skip).map(|&(_, w)| w).collect();
+    
+    let read_sparkline = Sparkline::default()
+        .data(&read_data)
+        .style(Style::default().fg(Color::Green));
+    let write_sparkline = Sparkline::default()
+        .data(&write_data)
+        .style(Style::default().fg(Color::Yellow));
+    
+    f.render_widget(read_sparkline, io_layout[0]);
+    f.render_widget(write_sparkline, io_layout[1]);
+}
+
+// Son uyarıları listeleyen fonksiyon - en yeni uyarı en üstte
+fn draw_alerts_section(f: &mut Frame, area: Rect, app: &App, focused: bool) {
+    let theme = app.theme();
+    let visible = area.height.saturating_sub(2) as usize;
+    
+    let items: Vec<ListItem> = if app.alerts.alerts.is_empty() {
+        vec![ListItem::new("No alerts").style(Style::default().fg(theme.muted))]
+    } else {
+        app.alerts.alerts
+            .iter()
+            .rev()
+            .take(visible)
+            .map(|alert| {
+                ListItem::new(format!(
+                    "{} [{}] {}",
+                    alert.timestamp.format("%H:%M:%S"),
+                    alert.kind.label(),
+                    alert.message
+                ))
+                .style(Style::default().fg(Color::Red))
+            })
+            .collect()
+    };
+    
+    let list = List::new(items).block(
+        panel_block(app, focused, None).title(format!("Alerts ({})", app.alerts.alerts.len()))
     );
     
-    let network_info = Paragraph::new(network_text)
+    f.render_widget(list, area);
+}
+
+// System sekmesi - host bilgileri, diskler ve sıcaklıklar
+fn draw_system_tab(f: &mut Frame, area: Rect, app: &App) {
+    // Solda host, GPU ve diskler; sağda batarya, bağlantılar ve sıcaklıklar
+    // GPU, batarya ve bağlantı panelleri sadece veri varsa, diskler ve sıcaklıklar
+    // gizlenmediyse yer kaplar. Her sütunun son paneli kalan yüksekliğin hepsini alır
+    let mut left = vec![(SystemBlock::Host, 10)];
+    if !app.sample.gpus.is_empty() {
+        // Her GPU için kullanım ve VRAM satırı
+        left.push((SystemBlock::Gpu, app.sample.gpus.len() as u16 * 2 + 2));
+    }
+    if app.panels.is_visible(Section::Disks) {
+        left.push((SystemBlock::Disks, 3));
+    }
+    
+    let mut right = Vec::new();
+    if !app.sample.batteries.is_empty() {
+        right.push((SystemBlock::Battery, app.sample.batteries.len() as u16 * 2 + 2));
+    }
+    if app.sample.connections.is_some() {
+        right.push((SystemBlock::Connections, 6));
+    }
+    if app.sample.process_connections.is_some() && app.network_talkers > 0 {
+        right.push((SystemBlock::Talkers, app.network_talkers as u16 + 2));
+    }
+    if app.panels.is_visible(Section::Temperatures) {
+        right.push((SystemBlock::Temperatures, 3));
+    }
+    
+    // Sağ sütun tamamen boşsa sol sütun tüm genişliği alır
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(if right.is_empty() {
+            vec![Constraint::Percentage(100)]
+        } else {
+            vec![Constraint::Percentage(60), Constraint::Percentage(40)]
+        })
+        .split(area);
+    
+    for (blocks, column) in [left, right].iter().zip(columns.iter()) {
+        let constraints = blocks.iter().enumerate().map(|(i, (_, height))| {
+            if i + 1 == blocks.len() {
+                Constraint::Min(*height)
+            } else {
+                Constraint::Length(*height)
+            }
+        });
+        let areas = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(constraints)
+            .split(*column);
+        for ((block, _), block_area) in blocks.iter().zip(areas.iter()) {
+            let block_area = *block_area;
+            match block {
+                SystemBlock::Host => draw_host_info(f, block_area, app),
+                SystemBlock::Gpu => draw_gpu_section(f, block_area, app),
+                SystemBlock::Disks => draw_disk_section(f, block_area, app),
+                SystemBlock::Battery => draw_battery_section(f, block_area, app),
+                SystemBlock::Connections => {
+                    if let Some(counts) = &app.sample.connections {
+                        draw_connections_section(f, block_area, app, counts);
+                    }
+                }
+                SystemBlock::Talkers => draw_talkers_section(f, block_area, app),
+                SystemBlock::Temperatures => draw_temperature_section(f, block_area, app),
+            }
+        }
+    }
+}
+
+// System sekmesindeki paneller - düzen görünenlerden kurulur
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SystemBlock {
+    Host,
+    Gpu,
+    Disks,
+    Battery,
+    Connections,
+    Talkers,
+    Temperatures,
+}
+
+// TCP bağlantıları - durumlarına göre sayılar, başlıkta toplam
+fn draw_connections_section(f: &mut Frame, area: Rect, app: &App, counts: &ConnectionCounts) {
+    let theme = app.theme();
+    let text = format!(
+        "ESTABLISHED: {}\n\
+         LISTEN: {}\n\
+         TIME_WAIT: {}\n\
+         CLOSE_WAIT: {}",
+        counts.established, counts.listen, counts.time_wait, counts.close_wait,
+    );
+    let paragraph = Paragraph::new(text)
         .block(
             Block::default()
-                .title("Network")
+                .title(format!("TCP Connections ({})", counts.total()))
                 .borders(Borders::ALL)
-                .style(Style::default().fg(Color::Blue))
+                .style(Style::default().fg(theme.border))
         )
-        .style(Style::default().fg(Color::White));
+        .style(Style::default().fg(theme.text));
     
-    f.render_widget(network_info, area);
+    f.render_widget(paragraph, area);
 }
 
-// Alt bilgi çubuğunu çizen fonksiyon
-fn draw_footer(f: &mut Frame, area: Rect) {
-    let footer_text = "🦀 Built with Rust | Press 'q' or ESC to quit | Refresh Rate: 4 FPS";
+// En çok TCP bağlantısı olan process'ler - byte hızı yok, sadece bağlantı sayısı
+fn draw_talkers_section(f: &mut Frame, area: Rect, app: &App) {
+    let theme = app.theme();
+    let talkers = app.top_talkers().unwrap_or_default();
+    let rows: Vec<Row> = if talkers.is_empty() {
+        vec![Row::new(vec!["No process connections visible"]).style(Style::default().fg(theme.muted))]
+    } else {
+        talkers
+            .iter()
+            .map(|(pid, name, connections)| {
+                Row::new(vec![
+                    Cell::from(pid.to_string()),
+                    Cell::from(name.clone()),
+                    Cell::from(connections.to_string()),
+                ])
+            })
+            .collect()
+    };
+    let widths = [Constraint::Length(7), Constraint::Fill(1), Constraint::Length(5)];
+    let table = Table::new(rows, widths)
+        .block(
+            Block::default()
+                .title(format!("Network by Process (top {})", app.network_talkers))
+                .borders(Borders::ALL)
+                .style(Style::default().fg(theme.border))
+        )
+        .style(Style::default().fg(theme.text));
+    
+    f.render_widget(table, area);
+}
+
+// GPU paneli - her GPU için kullanım ve VRAM gauge'ları
+fn draw_gpu_section(f: &mut Frame, area: Rect, app: &App) {
+    let theme = app.theme();
+    let block = Block::default()
+        .title("GPU")
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.border));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Length(1); app.sample.gpus.len() * 2])
+        .split(inner);
+    
+    for (i, gpu) in app.sample.gpus.iter().enumerate() {
+        let utilization = gpu.utilization as f32;
+        let mut label = format!("GPU{} {}: {:.0}%", i, gpu.name, utilization);
+        if let Some(temperature) = gpu.temperature {
+            label.push_str(&format!(" {}°C", temperature));
+        }
+        draw_gauge(f, rows[i * 2], app, utilization, usage_color(utilization, &app.color_thresholds), label);
+        
+        let memory_percent = gpu.memory_percent();
+        draw_gauge(
+            f,
+            rows[i * 2 + 1],
+            app,
+            memory_percent,
+            usage_color(memory_percent, &app.color_thresholds),
+            format!(
+                "VRAM: {} / {}",
+                app.format_bytes(gpu.memory_used),
+                app.format_bytes(gpu.memory_total)
+            ),
+        );
+    }
+}
+
+// Batarya paneli - yüzde, şarj durumu ve kalan süre
+fn draw_battery_section(f: &mut Frame, area: Rect, app: &App) {
+    let theme = app.theme();
+    
+    let items: Vec<ListItem> = app.sample.batteries
+        .iter()
+        .enumerate()
+        .map(|(i, battery)| {
+            let color = match categorize_battery(battery.percent) {
+                BatteryLevel::High => Color::Green,
+                BatteryLevel::Low => Color::Yellow,
+                BatteryLevel::Critical => Color::Red,
+            };
+            let remaining = match (battery.state, battery.time_to_empty, battery.time_to_full) {
+                (ChargeState::Discharging, Some(secs), _) => format!("{} left", format_uptime(secs)),
+                (ChargeState::Charging, _, Some(secs)) => format!("{} to full", format_uptime(secs)),
+                _ => String::new(),
+            };
+            ListItem::new(vec![
+                Line::styled(format!("BAT{}: {:.0}%", i, battery.percent), Style::default().fg(color)),
+                Line::styled(
+                    format!("  {} {}", battery.state.label(), remaining),
+                    Style::default().fg(theme.text),
+                ),
+            ])
+        })
+        .collect();
+    
+    let list = List::new(items).block(
+        Block::default()
+            .title("Battery")
+            .borders(Borders::ALL)
+            .style(Style::default().fg(theme.border))
+    );
+    
+    f.render_widget(list, area);
+}
+
+// Host bilgileri - isim, işletim sistemi, çekirdek, açılış zamanı
+fn draw_host_info(f: &mut Frame, area: Rect, app: &App) {
+    let theme = app.theme();
+    let unknown = || "unknown".to_string();
+    let boot_time = chrono::DateTime::from_timestamp(app.sample.host.boot_time as i64, 0)
+        .map(|time| time.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(unknown);
+    
+    // Model adı panel genişliğine sığmazsa sondan kırpılır (çerçeve + "CPU: " öneki)
+    let brand_width = (area.width as usize).saturating_sub(2 + 5);
+    let cpu_brand = if app.sample.host.cpu_brand.is_empty() {
+        unknown()
+    } else if app.sample.host.cpu_brand.chars().count() > brand_width {
+        let kept: String = app.sample.host.cpu_brand.chars().take(brand_width.saturating_sub(1)).collect();
+        format!("{}…", kept)
+    } else {
+        app.sample.host.cpu_brand.clone()
+    };
+    
+    let text = format!(
+        "Hostname: {}\n\
+         CPU: {}\n\
+         OS: {} {}\n\
+         Kernel: {}\n\
+         Boot time: {}\n\
+         Uptime: {}\n\
+         Processes: {}\n\
+         Physical cores: {}",
+        app.sample.host.host_name.clone().unwrap_or_else(unknown),
+        cpu_brand,
+        app.sample.host.os_name.clone().unwrap_or_else(unknown),
+        app.sample.host.os_version.clone().unwrap_or_default(),
+        app.sample.host.kernel_version.clone().unwrap_or_else(unknown),
+        boot_time,
+        format_uptime(app.sample.uptime),
+        app.sample.processes.len(),
+        app.sample.host.physical_core_count.map(|n| n.to_string()).unwrap_or_else(unknown),
+    );
     
-    let footer = Paragraph::new(footer_text)
-        .style(Style::default().fg(Color::Gray))
+    let info = Paragraph::new(text)
         .block(
             Block::default()
+                .title("Host")
                 .borders(Borders::ALL)
-                .style(Style::default().fg(Color::Blue))
+                .style(Style::default().fg(theme.border))
+        )
+        .style(Style::default().fg(theme.text));
+    
+    f.render_widget(info, area);
+}
+
+// Disk kullanım seviyesinin rengi
+fn disk_usage_color(category: DiskUsageCategory) -> Color {
+    match category {
+        DiskUsageCategory::Normal => Color::Green,
+        DiskUsageCategory::Warning => Color::Yellow,
+        DiskUsageCategory::Critical => Color::LightRed,
+        DiskUsageCategory::Full => Color::Red,
+    }
+}
+
+// Sıcaklık seviyesinin rengi
+fn temperature_color(category: TemperatureCategory) -> Color {
+    match category {
+        TemperatureCategory::Cool => Color::Green,
+        TemperatureCategory::Normal => Color::Cyan,
+        TemperatureCategory::Warm => Color::Yellow,
+        TemperatureCategory::Hot => Color::LightRed,
+        TemperatureCategory::Critical => Color::Red,
+    }
+}
+
+// sysinfo'nun boş döndürdüğü bir listenin açıklaması
+// Konteynerde liste genelde izin/bağlama eksikliğinden boştur - "yok" demek yanıltıcı olur
+fn empty_list_message(app: &App, missing: &str, subject: &str) -> String {
+    match &app.sample.host.container {
+        Some(container) => format!("{subject} not accessible inside {container} container"),
+        None => missing.to_string(),
+    }
+}
+
+// Disk tablosu - her mount noktası için kullanım bilgisi
+fn draw_disk_section(f: &mut Frame, area: Rect, app: &App) {
+    let theme = app.theme();
+    
+    let header = Row::new(vec!["Mount", "FS", "Used", "Total", "Use%"])
+        .style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+    
+    let disks = app.visible_disks();
+    let rows: Vec<Row> = disks
+        .iter()
+        .map(|disk| {
+            let color = disk_usage_color(categorize_disk_usage(disk.usage_percent, &app.color_thresholds));
+            Row::new(vec![
+                Cell::from(disk.mount_point.clone()),
+                Cell::from(disk.file_system.clone()),
+                Cell::from(format_bytes_detailed(disk.used_space, app.byte_units)),
+                Cell::from(format_bytes_detailed(disk.total_space, app.byte_units)),
+                Cell::from(format!("{:.1}%", disk.usage_percent)).style(Style::default().fg(color)),
+            ])
+        })
+        .collect();
+    
+    let widths = [
+        Constraint::Fill(1),
+        Constraint::Length(10),
+        Constraint::Length(10),
+        Constraint::Length(10),
+        Constraint::Length(6),
+    ];
+    
+    // Alt kısımda seçili diskin doluluk trendi - alan çok darsa sadece tablo
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(if area.height >= 12 {
+            vec![Constraint::Min(3), Constraint::Length(5)]
+        } else {
+            vec![Constraint::Min(3)]
+        })
+        .split(area);
+    
+    let block = Block::default()
+        .title(format!(
+            "Disks ({}, {})",
+            disks.len(),
+            if app.show_all_disks { "all" } else { "physical" }
+        ))
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.border));
+    
+    // Boş tablo yerine nedenini söyle - filtre mi, konteyner mi, gerçekten disk yok mu
+    if disks.is_empty() {
+        let message = if app.sample.disks.is_empty() {
+            empty_list_message(app, "No disks detected", "Disks")
+        } else {
+            "No physical disks (a: show all)".to_string()
+        };
+        f.render_widget(Paragraph::new(message).style(Style::default().fg(theme.muted)).block(block), area);
+        return;
+    }
+    
+    if app.disk_gauges {
+        draw_disk_gauges(f, layout[0], app, &disks, block);
+    } else {
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(block)
+            .style(Style::default().fg(theme.text))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        
+        let mut state = TableState::default()
+            .with_selected((!disks.is_empty()).then(|| app.selected_disk.min(disks.len() - 1)));
+        f.render_stateful_widget(table, layout[0], &mut state);
+    }
+    
+    if let (Some(trend_area), Some(disk)) = (layout.get(1), app.selected_disk()) {
+        draw_disk_trend(f, *trend_area, app, &disk);
+    }
+}
+
+// Her disk için bağlama noktası ve yüzde etiketli bir gauge - çok diskte tablodan hızlı taranır
+// Sütun sayısı genişliğe göre; sığmayan satırlar kaydırılır ki seçili disk hep görünsün
+fn draw_disk_gauges(f: &mut Frame, area: Rect, app: &App, disks: &[DiskInfo], block: Block) {
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    
+    let columns = gauge_grid_columns(disks.len(), inner.width);
+    let visible_rows = inner.height as usize;
+    if columns == 0 || visible_rows == 0 {
+        return;
+    }
+    let selected = app.selected_disk.min(disks.len() - 1);
+    let first_row = (selected / columns + 1).saturating_sub(visible_rows);
+    let cell_width = inner.width / columns as u16;
+    
+    for (index, disk) in disks.iter().enumerate().skip(first_row * columns).take(visible_rows * columns) {
+        let (row, column) = (index / columns - first_row, index % columns);
+        // Son sütun bölmeden kalan genişliği de alır
+        let width = if column + 1 == columns { inner.width - cell_width * column as u16 } else { cell_width };
+        let cell = Rect {
+            x: inner.x + cell_width * column as u16,
+            y: inner.y + row as u16,
+            width: width.saturating_sub(1).max(1),
+            height: 1,
+        };
+        let marker = if index == selected { "> " } else { "" };
+        draw_gauge(
+            f,
+            cell,
+            app,
+            disk.usage_percent,
+            disk_usage_color(categorize_disk_usage(disk.usage_percent, &app.color_thresholds)),
+            format!("{marker}{} {:.1}%", disk.mount_point, disk.usage_percent),
         );
+    }
+}
+
+// Izgaradaki sütun sayısı - her gauge en az DISK_GAUGE_WIDTH hücre, disk sayısından fazla sütun yok
+const DISK_GAUGE_WIDTH: u16 = 24;
+
+fn gauge_grid_columns(count: usize, width: u16) -> usize {
+    ((width / DISK_GAUGE_WIDTH).max(1) as usize).min(count)
+}
+
+// Seçili diskin doluluk geçmişi - yavaş yavaş dolan bir bölümü fark etmek için
+fn draw_disk_trend(f: &mut Frame, area: Rect, app: &App, disk: &DiskInfo) {
+    let theme = app.theme();
+    let history = app.disk_history.get(&disk.mount_point);
     
-    f.render_widget(footer, area);
+    // Penceredeki ilk ve son değer arasındaki fark - artıyorsa disk doluyor
+    let change = history
+        .and_then(|history| Some(history.back()? - history.front()?))
+        .unwrap_or(0.0);
+    let block = Block::default()
+        .title(format!("Trend: {} {:.1}% ({:+.1}%)", disk.mount_point, disk.usage_percent, change))
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.border));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    
+    // Sparkline sağa yaslı çizer, bu yüzden sadece sığan son örnekleri veriyoruz
+    let data: Vec<u64> = history
+        .map(|history| {
+            let skip = history.len().saturating_sub(inner.width as usize);
+            history.iter().skip(skip).map(|&percent| percent.round() as u64).collect()
+        })
+        .unwrap_or_default();
+    let sparkline = Sparkline::default()
+        .data(&data)
+        .max(100)
+        .style(Style::default().fg(disk_usage_color(categorize_disk_usage(disk.usage_percent, &app.color_thresholds))));
+    f.render_widget(sparkline, inner);
+}
+
+// Sıcaklık sensörleri listesi
+fn draw_temperature_section(f: &mut Frame, area: Rect, app: &App) {
+    let theme = app.theme();
+    
+    let mut items: Vec<ListItem> = if app.sample.temperatures.is_empty() && app.sample.fans.is_empty() {
+        let message = empty_list_message(app, "No temperature sensors on this system", "Sensors");
+        vec![ListItem::new(message).style(Style::default().fg(theme.muted))]
+    } else {
+        app.sample.temperatures
+            .iter()
+            .map(|temp| {
+                let line = temperature_label(temp);
+                let color = temperature_color(categorize_temperature(temp.current_temp, &app.color_thresholds));
+                ListItem::new(line).style(Style::default().fg(color))
+            })
+            .collect()
+    };
+    
+    // Fan sensörü olan sistemlerde RPM'ler sıcaklıkların altında - duran fan 0 RPM görünür
+    items.extend(app.sample.fans.iter().map(|fan| {
+        let color = if fan.rpm == 0 { theme.muted } else { theme.text };
+        ListItem::new(format!("{}: {} RPM", fan.label, fan.rpm)).style(Style::default().fg(color))
+    }));
+    
+    let block = Block::default()
+        .title(if app.sample.fans.is_empty() { "Temperatures" } else { "Temperatures & Fans" })
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.border));
+    let inner = block.inner(area);
+    f.render_widget(List::new(items).block(block), area);
+    
+    // Her sensör satırının sağında geçmişi - throttle/soğuma döngüsü testere dişi gibi görünür
+    // Etiketlerin bittiği yerden başlar; yer kalmazsa çizilmez
+    let label_width = app.sample.temperatures
+        .iter()
+        .map(|temp| temperature_label(temp).width() as u16)
+        .max()
+        .unwrap_or(0);
+    let x = inner.x + label_width + 2;
+    if x + TEMPERATURE_SPARKLINE_MIN_WIDTH > inner.right() {
+        return;
+    }
+    for (row, temp) in app.sample.temperatures.iter().enumerate().take(inner.height as usize) {
+        let Some(history) = app.temperature_history.get(&temp.component_name) else {
+            continue;
+        };
+        let rect = Rect { x, y: inner.y + row as u16, width: inner.right() - x, height: 1 };
+        let (data, max) = temperature_sparkline_data(history, rect.width as usize);
+        let color = temperature_color(categorize_temperature(temp.current_temp, &app.color_thresholds));
+        f.render_widget(Sparkline::default().data(&data).max(max).style(Style::default().fg(color)), rect);
+    }
+}
+
+// Sıcaklık sparkline'ı için gereken en az genişlik - daha dar bir çizgi bir şey anlatmaz
+const TEMPERATURE_SPARKLINE_MIN_WIDTH: u16 = 8;
+
+fn temperature_label(temp: &TemperatureInfo) -> String {
+    let mut line = format!("{}: {:.1}°C", temp.component_name, temp.current_temp);
+    if let Some(critical) = temp.critical_temp {
+        line.push_str(&format!(" (crit {:.0}°C)", critical));
+    }
+    line
+}
+
+// Sparkline'a sığan son değerler, pencerenin en düşüğünün bir derece altından itibaren
+// Sıfırdan ölçeklense 60-90°C arası salınım tek satırda neredeyse düz görünürdü
+fn temperature_sparkline_data(history: &VecDeque<f32>, width: usize) -> (Vec<u64>, u64) {
+    let skip = history.len().saturating_sub(width);
+    let recent: Vec<f32> = history.iter().skip(skip).copied().collect();
+    let floor = recent.iter().copied().fold(f32::INFINITY, f32::min) - 1.0;
+    let data: Vec<u64> = recent.iter().map(|&temp| ((temp - floor) * 10.0).round() as u64).collect();
+    let max = data.iter().copied().max().unwrap_or(1).max(1);
+    (data, max)
+}
+
+// Alt bilgi çubuğunu çizen fonksiyon
+// Yakın zamanda bir işlem sonucu varsa (örn. kill) kısa bir süre onu gösterir
+fn draw_footer(f: &mut Frame, area: Rect, app: &App) {
+    let theme = app.theme();
+    let footer_text = match app.active_status() {
+        Some(status) if status.is_error => Line::styled(status.text.clone(), Style::default().fg(Color::Red)),
+        Some(status) => Line::styled(status.text.clone(), Style::default().fg(Color::Green)),
+        None => hint_line(&footer_hints(app), app),
+    };
+    
+    // Sağ üst köşede monitörün kendi maliyeti - gözlemcinin yükü de görünsün
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.border));
+    if app.read_only {
+        block = block.title(Title::from(" read-only ").alignment(Alignment::Left));
+    }
+    if let Some((cpu, memory)) = app.self_usage() {
+        block = block.title(
+            Title::from(format!(" monitor: {:.1}% CPU, {} ", cpu, app.format_bytes(memory))).alignment(Alignment::Right),
+        );
+    }
+    let footer = Paragraph::new(footer_text).block(block);
+    
+    f.render_widget(footer, area);
+}
+
+// Tuş ipuçları satırı - salt okunur modda sinyal gönderen eylemlerin ipuçları soluk ve üstü çizili
+// input::apply ile aynı ölçüt: tuş ne olursa olsun eylemin kendisine bakılır
+fn hint_line(hints: &[Hint], app: &App) -> Line<'static> {
+    let style = Style::default().fg(app.theme().muted);
+    let disabled = style.add_modifier(Modifier::DIM | Modifier::CROSSED_OUT);
+    let mut spans = Vec::new();
+    for (i, hint) in hints.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled(" | ", style));
+        }
+        let text = if app.ascii_only { ascii_hints(hint.text.clone()) } else { hint.text.clone() };
+        let blocked = app.read_only && hint.actions.iter().any(Action::is_destructive);
+        spans.push(Span::styled(text, if blocked { disabled } else { style }));
+    }
+    Line::from(spans)
+}
+
+// Footer'daki tek bir ipucu ve tetiklediği eylemler - atamalardan bağımsız ipuçlarında boş
+#[derive(Debug, Clone, PartialEq)]
+struct Hint {
+    text: String,
+    actions: Vec<Action>,
+}
+
+// Footer ipuçlarını toplar - tuş etiketleri App'teki atamalardan gelir
+// Birlikte anılan eylemlerin tuşları sırayla eşleştirilir: Up [↑, k] ve Down [↓, j] → "↑/↓ k/j".
+// Hiç tuşu kalmamış eylemin ipucu atlanır
+struct Hints<'a> {
+    keys: &'a KeyLabels,
+    items: Vec<Hint>,
+}
+
+impl<'a> Hints<'a> {
+    fn new(keys: &'a KeyLabels) -> Self {
+        Self { keys, items: Vec::new() }
+    }
+
+    // Eylem gruplarının tuş etiketi - her grup kendi içinde eşleştirilir, gruplar boşlukla ayrılır
+    fn label(&self, groups: &[&[Action]]) -> String {
+        groups
+            .iter()
+            .flat_map(|actions| {
+                let count = actions.iter().map(|action| self.keys.get(*action).len()).max().unwrap_or(0);
+                (0..count).map(move |i| {
+                    actions
+                        .iter()
+                        .filter_map(|action| self.keys.get(*action).get(i).map(String::as_str))
+                        .collect::<Vec<_>>()
+                        .join("/")
+                })
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn keys(&mut self, groups: &[&[Action]], text: impl Display) -> &mut Self {
+        let label = self.label(groups);
+        if !label.is_empty() {
+            let actions = groups.iter().flat_map(|actions| actions.iter().copied()).collect();
+            self.items.push(Hint { text: format!("{label}: {text}"), actions });
+        }
+        self
+    }
+
+    fn action(&mut self, action: Action, text: impl Display) -> &mut Self {
+        self.keys(&[&[action]], text)
+    }
+
+    // Atamalardan bağımsız ipucu (fare, yazı alanı, sabit onay tuşları)
+    fn text(&mut self, text: impl Into<String>) -> &mut Self {
+        self.items.push(Hint { text: text.into(), actions: Vec::new() });
+        self
+    }
+
+    fn finish(&mut self) -> Vec<Hint> {
+        std::mem::take(&mut self.items)
+    }
+}
+
+// O anki moda ve odağa göre geçerli tuşları listele
+// Popup açıkken sadece popup'ın tuşları çalıştığı için sadece onlar gösterilir
+fn footer_hints(app: &App) -> Vec<Hint> {
+    use Action::*;
+    
+    let mut hints = Hints::new(&app.key_labels);
+    
+    // Çıkış onayı ve toplu kill tuşları atamalardan bağımsızdır
+    if app.quit_prompt {
+        return hints.text("y: quit").text("any other key: cancel").finish();
+    }
+    if let Some(popup) = &app.bulk_kill_popup {
+        if !popup.confirming {
+            hints.text("type a name or glob").text("Enter: review matches").text("ESC: cancel");
+        } else if app.bulk_kill_matches().iter().any(|process| app.is_critical_process(process)) {
+            hints.text("Y (shift): kill including system processes").text("any other key: back");
+        } else {
+            hints.text("y: kill all").text("any other key: back");
+        }
+        return hints.finish();
+    }
+    
+    let scroll: &[&[Action]] = &[&[Up, Down], &[PageUp, PageDown], &[Top, Bottom]];
+    if app.kill_popup.is_some() {
+        return hints.keys(&[&[Up, Down]], "choose signal").action(Select, "send").action(Back, "cancel").finish();
+    }
+    if app.detail_popup.is_some() {
+        return hints.keys(&[&[Select, Back]], "close details").finish();
+    }
+    if app.memory_popup {
+        return hints.keys(&[&[Select, Back, ShowTopMemory]], "close").finish();
+    }
+    if app.offenders_popup {
+        return hints.keys(&[&[Select, Back, ShowOffenders]], "close").finish();
+    }
+    if app.health_popup {
+        return hints.keys(&[&[Select, Back, ShowHealth]], "close").finish();
+    }
+    if app.diff_popup {
+        return hints.action(CaptureBaseline, "new baseline").keys(&[&[Select, Back, ShowDiff]], "close").finish();
+    }
+    if app.events_popup.is_some() {
+        return hints.keys(&[&[Up, Down], &[PageUp, PageDown]], "scroll").keys(&[&[Select, Back, ShowEvents]], "close").finish();
+    }
+    if app.panels_popup.is_some() {
+        return hints
+            .keys(&[&[Up, Down]], "choose panel")
+            .action(Select, "show/hide")
+            .keys(&[&[Back, ShowPanels]], "close")
+            .finish();
+    }
+    
+    // Sekmeye ve odaklanılan panele özgü tuşlar önce gelir
+    // System sekmesinde paneller arası odak yok, oklar disk tablosunu kaydırır
+    let filter = format!("filter ({})", if app.process_filter_enabled { "on" } else { "off" });
+    let sort = match hints.label(&[&[CycleSort]]) {
+        label if label.is_empty() => "click header: sort".to_string(),
+        label => format!("click header/{label}: sort"),
+    };
+    match (app.active_tab, app.focused_panel) {
+        (Tab::System, _) => {
+            hints
+                .keys(&[&[Up, Down]], "select disk")
+                .action(ToggleAllDisks, format!("show {} disks", if app.show_all_disks { "physical" } else { "all" }))
+                .action(ToggleDiskGauges, format!("disk {}", if app.disk_gauges { "table" } else { "gauges" }));
+        }
+        (Tab::Processes, _) if app.process_tree => {
+            hints
+                .keys(scroll, "scroll")
+                .action(ToggleCollapse, "expand/collapse")
+                .action(ToggleTreeTotals, if app.tree_totals { "own values" } else { "subtree totals" })
+                .action(ToggleProcessTree, "flat list")
+                .text(sort)
+                .action(Select, "details")
+                .action(KillProcess, "kill")
+                .action(TogglePin, "pin")
+                .action(ToggleProcessFilter, filter);
+        }
+        (Tab::Processes, _) => {
+            hints
+                .keys(scroll, "scroll")
+                .text(sort)
+                .action(ToggleProcessTree, "tree")
+                .action(Select, "details")
+                .action(KillProcess, "kill")
+                .action(TogglePin, "pin")
+                .action(ToggleProcessFilter, filter);
+        }
+        (Tab::Overview, Panel::Processes) => {
+            hints
+                .keys(&[&[Up, Down], &[Top, Bottom]], "select")
+                .action(Select, "details")
+                .action(KillProcess, "kill")
+                .action(KillByName, "kill by name")
+                .action(TogglePin, "pin")
+                .action(CycleSort, "sort")
+                .action(ToggleProcessFilter, filter)
+                .keys(&[&[FewerProcesses, MoreProcesses]], "rows")
+                .action(ShowTopMemory, "top memory")
+                .action(ShowOffenders, "offenders")
+                .action(ExportProcesses, "export");
+        }
+        (Tab::Overview, Panel::Cpu) if app.core_heatmap => {
+            hints.action(ToggleCoreHeatmap, "core gauges");
+        }
+        (Tab::Overview, Panel::Cpu) => {
+            hints
+                .keys(&[&[Up, Down], &[Top, Bottom]], "scroll cores")
+                .action(ToggleCoreHeatmap, "heatmap")
+                .action(CycleIdleCores, format!("idle cores ({})", app.idle_cores.next().label()));
+        }
+        (Tab::Overview, Panel::Network) => {
+            hints
+                .action(ToggleNetworkUnits, format!("show {}", if app.network_bits { "bytes" } else { "bits" }))
+                .action(ToggleNetworkScale, format!("{} scale", if app.network_log_scale { "linear" } else { "log" }))
+                .action(ToggleNetworkCounters, if app.network_counters { "rates" } else { "counters" })
+                .action(CycleInterface, format!("chart interface ({})", app.selected_interface.as_deref().unwrap_or("all")));
+        }
+        (Tab::Overview, _) => {}
+    }
+    
+    // Yenileme CPU'nun okunabildiğinden hızlıysa kullanıcı CPU'nun daha seyrek güncellendiğini görsün
+    let refresh = match app.cpu_sample_ms() {
+        cpu_ms if cpu_ms > app.refresh_ms => format!("{} ms, CPU {} ms", app.refresh_ms, cpu_ms),
+        _ => format!("{} ms", app.refresh_ms),
+    };
+    hints
+        .keys(&[&[PreviousTab, NextTab]], "tabs")
+        .action(FocusNext, "focus")
+        .action(ToggleZoom, "zoom")
+        .action(ShowPanels, "panels")
+        .action(ShowHealth, "health")
+        .action(ShowEvents, "events")
+        .keys(&[&[CaptureBaseline, ShowDiff]], "baseline/diff")
+        .action(ResetHistory, "reset")
+        .action(TogglePause, if app.paused { "resume" } else { "pause" })
+        .action(ExportCharts, "export charts")
+        .action(CopySnapshot, "copy snapshot")
+        .action(ToggleCompact, "compact")
+        .action(CycleTheme, format!("theme ({})", app.theme.label()))
+        .keys(&[&[FasterRefresh, SlowerRefresh]], format!("refresh ({refresh})"))
+        .keys(&[&[Quit, Back]], "quit")
+        .finish()
+}
+
+// Ekranın ortasında sabit boyutlu bir alan hesapla - popup'lar için
+// Terminal popup'tan küçükse alan terminal boyutuna kırpılır
+fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+// Sinyal seçim popup'ı
+fn draw_kill_popup(f: &mut Frame, area: Rect, popup: &KillPopup, theme: Theme) {
+    let popup_area = centered_rect(44, KILL_SIGNALS.len() as u16 + 4, area);
+    
+    let items: Vec<ListItem> = KILL_SIGNALS
+        .iter()
+        .map(|(_, label)| ListItem::new(*label))
+        .collect();
+    
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!("Kill {} ({})", popup.name, popup.pid))
+                .title_bottom("Enter: send | Esc: cancel")
+                .borders(Borders::ALL)
+                .style(Style::default().fg(theme.accent))
+        )
+        .style(Style::default().fg(theme.text))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol("> ");
+    
+    let mut state = ListState::default().with_selected(Some(popup.selected));
+    
+    // Clear widget'ı alttaki panellerin içeriğini siler - popup şeffaf görünmesin
+    f.render_widget(Clear, popup_area);
+    f.render_stateful_widget(list, popup_area, &mut state);
+}
+
+// Ada göre toplu kill - desen satırı, eşleşme sayısı ve listesi
+// Kritik process'ler kırmızı işaretlenir ve onay için büyük 'Y' istenir
+fn draw_bulk_kill_popup(f: &mut Frame, area: Rect, popup: &BulkKillPopup, app: &App) {
+    let theme = app.theme();
+    let popup_area = centered_rect(60, 18, area);
+    let matches = app.bulk_kill_matches();
+    let critical = matches.iter().filter(|process| app.is_critical_process(process)).count();
+    
+    let mut lines = vec![
+        Line::from(vec![
+            Span::raw("Name: "),
+            Span::styled(popup.pattern.clone(), Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(if popup.confirming { "" } else { glyphs(app).block }),
+        ]),
+        Line::from(format!("{} matching processes", matches.len())),
+    ];
+    if critical > 0 {
+        lines.push(Line::styled(
+            format!("{} includes {} system process(es) - press Y to confirm", glyphs(app).warning, critical),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        ));
+    }
+    lines.push(Line::from(""));
+    
+    let list_height = (popup_area.height as usize).saturating_sub(lines.len() + 2);
+    let hidden = matches.len().saturating_sub(list_height);
+    let shown = if hidden > 0 { list_height.saturating_sub(1) } else { list_height };
+    for process in matches.iter().take(shown) {
+        let style = if app.is_critical_process(process) {
+            Style::default().fg(Color::Red)
+        } else {
+            Style::default().fg(theme.text)
+        };
+        lines.push(Line::styled(format!("{:>7}  {}", process.pid, process.name), style));
+    }
+    if matches.len() > shown {
+        lines.push(Line::styled(format!("... and {} more", matches.len() - shown), Style::default().fg(theme.muted)));
+    }
+    
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title("Kill by name (TERM)")
+                .title_bottom(if popup.confirming { "y: kill all | other: back" } else { "Enter: review | Esc: cancel" })
+                .borders(Borders::ALL)
+                .style(Style::default().fg(theme.accent))
+        )
+        .style(Style::default().fg(theme.text));
+    f.render_widget(Clear, popup_area);
+    f.render_widget(paragraph, popup_area);
+}
+
+// Bölüm görünürlüğü listesi - işaretli olanlar ekranda
+fn draw_panels_popup(f: &mut Frame, area: Rect, app: &App, selected: usize) {
+    let theme = app.theme();
+    let popup_area = centered_rect(40, Section::ALL.len() as u16 + 2, area);
+    
+    let items: Vec<ListItem> = Section::ALL
+        .iter()
+        .map(|&section| {
+            let mark = if app.panels.is_visible(section) { "[x]" } else { "[ ]" };
+            ListItem::new(format!("{} {}", mark, section.label()))
+        })
+        .collect();
+    
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title("Panels")
+                .title_bottom("Enter: toggle | Esc: close")
+                .borders(Borders::ALL)
+                .style(Style::default().fg(theme.accent))
+        )
+        .style(Style::default().fg(theme.text))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol("> ");
+    
+    let mut state = ListState::default().with_selected(Some(selected));
+    f.render_widget(Clear, popup_area);
+    f.render_stateful_widget(list, popup_area, &mut state);
+}
+
+// Process detay popup'ı - sysinfo'nun process hakkında bildiği her şey
+fn draw_detail_popup(f: &mut Frame, area: Rect, details: &ProcessDetails, app: &App) {
+    let theme = app.theme();
+    let popup_area = centered_rect(area.width.saturating_mul(3) / 4, 16, area);
+    
+    let start_time = chrono::DateTime::from_timestamp(details.start_time as i64, 0)
+        .map(|time| time.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    let command = if details.cmd.is_empty() {
+        "-".to_string()
+    } else {
+        details.cmd.join(" ")
+    };
+    
+    let text = format!(
+        "PID: {}\n\
+         Parent PID: {}\n\
+         Status: {}{}\n\
+         CPU: {:.1}%\n\
+         Memory: {}\n\
+         Virtual memory: {}\n\
+         Started: {}\n\
+         Running for: {}\n\
+         Executable: {}\n\
+         Command: {}",
+        details.pid,
+        details.parent_pid.map(|pid| pid.to_string()).unwrap_or_else(|| "-".to_string()),
+        details.status,
+        if details.exited { " (exited)" } else { "" },
+        details.cpu_usage,
+        app.format_bytes(details.memory),
+        app.format_bytes(details.virtual_memory),
+        start_time,
+        format_uptime(details.run_time),
+        if details.exe.is_empty() { "-" } else { &details.exe },
+        command,
+    );
+    
+    let popup = Paragraph::new(text)
+        .block(
+            Block::default()
+                .title(format!("Process: {}", details.name))
+                .title_bottom("Esc: close")
+                .borders(Borders::ALL)
+                .style(Style::default().fg(theme.accent))
+        )
+        .style(Style::default().fg(theme.text))
+        .wrap(Wrap { trim: false });
+    
+    f.render_widget(Clear, popup_area);
+    f.render_widget(popup, popup_area);
+}
+
+// En çok bellek kullanan 10 process - bellek kullanımı ve toplam RAM'e oranı
+fn draw_memory_popup(f: &mut Frame, area: Rect, app: &App) {
+    let theme = app.theme();
+    let processes = app.top_memory_processes(10);
+    let popup_area = centered_rect(60, processes.len() as u16 + 4, area);
+    
+    let header = Row::new(vec!["PID", "Name", "Memory", "Mem%"])
+        .style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+    let rows: Vec<Row> = processes
+        .iter()
+        .map(|process| {
+            Row::new(vec![
+                Cell::from(process.pid.to_string()),
+                Cell::from(process.name.clone()),
+                Cell::from(app.format_bytes(process.memory)),
+                Cell::from(format!("{:.1}%", process.memory_percent)),
+            ])
+        })
+        .collect();
+    let widths = [
+        Constraint::Length(8),
+        Constraint::Fill(1),
+        Constraint::Length(12),
+        Constraint::Length(6),
+    ];
+    
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .title(format!(
+                    "Top Memory | {} / {}",
+                    app.format_bytes(app.sample.memory_used),
+                    app.format_bytes(app.sample.memory_total)
+                ))
+                .title_bottom("Esc: close")
+                .borders(Borders::ALL)
+                .style(Style::default().fg(theme.accent))
+        )
+        .style(Style::default().fg(theme.text));
+    
+    f.render_widget(Clear, popup_area);
+    f.render_widget(table, popup_area);
+}
+
+// Son dakikada CPU'da en sık ilk sıralara girenler - her tur sayılır, oran toplam tura göre
+fn draw_offenders_popup(f: &mut Frame, area: Rect, app: &App) {
+    let theme = app.theme();
+    let offenders = app.offenders.most_frequent(10);
+    let ticks = app.offenders.tick_count().max(1);
+    let popup_area = centered_rect(60, offenders.len().max(1) as u16 + 4, area);
+    
+    let header = Row::new(vec!["Name", "Ticks", "Share"])
+        .style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+    let rows: Vec<Row> = if offenders.is_empty() {
+        vec![Row::new(vec!["No CPU activity yet"]).style(Style::default().fg(theme.muted))]
+    } else {
+        offenders
+            .iter()
+            .map(|(name, count)| {
+                Row::new(vec![
+                    Cell::from(name.clone()),
+                    Cell::from(count.to_string()),
+                    Cell::from(format!("{:.0}%", *count as f64 / ticks as f64 * 100.0)),
+                ])
+            })
+            .collect()
+    };
+    let widths = [Constraint::Fill(1), Constraint::Length(6), Constraint::Length(6)];
+    
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .title(format!("CPU Offenders | top {} over last {} ticks", OFFENDER_TOP_N, app.offenders.tick_count()))
+                .title_bottom("Esc: close")
+                .borders(Borders::ALL)
+                .style(Style::default().fg(theme.accent))
+        )
+        .style(Style::default().fg(theme.text));
+    
+    f.render_widget(Clear, popup_area);
+    f.render_widget(table, popup_area);
+}
+
+// Referans snapshot'tan beri değişenler - yeni process'ler yeşil, kapananlar kırmızı,
+// değişen değerler ↑/↓ ile. Uzun listeler kısaltılır, kaç satırın gizlendiği yazılır
+const DIFF_LIST_LIMIT: usize = 6;
+
+fn draw_diff_popup(f: &mut Frame, area: Rect, app: &App) {
+    let Some(diff) = app.snapshot_diff() else {
+        return;
+    };
+    let theme = app.theme();
+    let glyphs = glyphs(app);
+    let change = |before: u64, after: u64| -> String {
+        match after.cmp(&before) {
+            std::cmp::Ordering::Greater => format!(" ({} {})", glyphs.increase, app.format_bytes(after - before)),
+            std::cmp::Ordering::Less => format!(" ({} {})", glyphs.decrease, app.format_bytes(before - after)),
+            std::cmp::Ordering::Equal => " (no change)".to_string(),
+        }
+    };
+    let heading = |text: String| Line::styled(text, Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+    let more = |count: usize| {
+        (count > DIFF_LIST_LIMIT)
+            .then(|| Line::styled(format!("  and {} more", count - DIFF_LIST_LIMIT), Style::default().fg(theme.muted)))
+    };
+    
+    let (cpu_before, cpu_after) = diff.cpu_average;
+    let cpu_delta = cpu_after - cpu_before;
+    let cpu_change = if cpu_delta.abs() < 0.05 {
+        " (no change)".to_string()
+    } else {
+        format!(" ({} {:.1}%)", if cpu_delta > 0.0 { glyphs.increase } else { glyphs.decrease }, cpu_delta.abs())
+    };
+    let mut lines = vec![
+        Line::from(format!("CPU avg   {:.1}%{}", cpu_after, cpu_change)),
+        Line::from(format!(
+            "Memory    {}{}",
+            app.format_bytes(diff.memory_used.1),
+            change(diff.memory_used.0, diff.memory_used.1)
+        )),
+        Line::from(format!("Swap      {}{}", app.format_bytes(diff.swap_used.1), change(diff.swap_used.0, diff.swap_used.1))),
+        Line::from(format!(
+            "Network   {} {}  {} {} transferred",
+            glyphs.download,
+            app.format_bytes(diff.network_received),
+            glyphs.upload,
+            app.format_bytes(diff.network_transmitted)
+        )),
+    ];
+    
+    let process_line = |sign: &str, pid: u32, name: &str, memory: u64, color: Color| {
+        Line::styled(
+            format!("  {sign} {:<8}{:<20} {}", pid, truncate_name(name, 20), app.format_bytes(memory)),
+            Style::default().fg(color),
+        )
+    };
+    lines.push(Line::default());
+    lines.push(heading(format!("New processes ({})", diff.appeared.len())));
+    lines.extend(
+        diff.appeared
+            .iter()
+            .take(DIFF_LIST_LIMIT)
+            .map(|process| process_line("+", process.pid, &process.name, process.memory, Color::Green)),
+    );
+    lines.extend(more(diff.appeared.len()));
+    lines.push(heading(format!("Gone processes ({})", diff.disappeared.len())));
+    lines.extend(
+        diff.disappeared
+            .iter()
+            .take(DIFF_LIST_LIMIT)
+            .map(|process| process_line("-", process.pid, &process.name, process.memory, Color::Red)),
+    );
+    lines.extend(more(diff.disappeared.len()));
+    lines.push(heading(format!("Memory changes ({})", diff.changed.len())));
+    lines.extend(diff.changed.iter().take(DIFF_LIST_LIMIT).map(|process| {
+        Line::from(format!(
+            "    {:<8}{:<20} {}{}",
+            process.pid,
+            truncate_name(&process.name, 20),
+            app.format_bytes(process.memory_after),
+            change(process.memory_before, process.memory_after)
+        ))
+    }));
+    lines.extend(more(diff.changed.len()));
+    
+    let popup_area = centered_rect(72, lines.len() as u16 + 2, area);
+    let popup = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(format!("Changes since baseline ({} ago)", format_uptime(diff.elapsed_secs.max(0) as u64)))
+                .title_bottom("B: new baseline | Esc: close")
+                .borders(Borders::ALL)
+                .style(Style::default().fg(theme.accent))
+        )
+        .style(Style::default().fg(theme.text));
+    
+    f.render_widget(Clear, popup_area);
+    f.render_widget(popup, popup_area);
+}
+
+// Sağlık durumunun nedenleri - en kötüden iyiye
+fn draw_health_popup(f: &mut Frame, area: Rect, app: &App) {
+    let theme = app.theme();
+    let health = app.health();
+    let popup_area = centered_rect(60, health.factors.len().max(1) as u16 + 2, area);
+    
+    let lines: Vec<Line> = if health.factors.is_empty() {
+        vec![Line::styled("All metrics within normal ranges", Style::default().fg(theme.muted))]
+    } else {
+        health
+            .factors
+            .iter()
+            .map(|factor| {
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:<9}", factor.level.label()),
+                        Style::default().fg(health_color(factor.level)).add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(factor.reason.clone()),
+                ])
+            })
+            .collect()
+    };
+    
+    let popup = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(format!("System Health: {}", health.level.label()))
+                .title_bottom("Esc: close")
+                .borders(Borders::ALL)
+                .style(Style::default().fg(theme.accent))
+        )
+        .style(Style::default().fg(theme.text));
+    
+    f.render_widget(Clear, popup_area);
+    f.render_widget(popup, popup_area);
+}
+
+// Olay günlüğü popup'ı - en yeni kayıt en üstte, önem derecesine göre renkli
+fn draw_events_popup(f: &mut Frame, area: Rect, app: &App, offset: usize) {
+    let theme = app.theme();
+    let height = (app.events.len().max(1) as u16 + 2).min(area.height.saturating_sub(4)).max(3);
+    let popup_area = centered_rect(area.width.saturating_mul(3) / 4, height, area);
+    
+    let items: Vec<ListItem> = if app.events.is_empty() {
+        vec![ListItem::new(Line::styled("No events yet", Style::default().fg(theme.muted)))]
+    } else {
+        app.events
+            .iter()
+            .rev()
+            .map(|entry| {
+                let color = match entry.severity {
+                    Severity::Info => theme.text,
+                    Severity::Warning => Color::Yellow,
+                    Severity::Error => Color::Red,
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(entry.timestamp.format("%H:%M:%S ").to_string(), Style::default().fg(theme.muted)),
+                    Span::styled(format!("{:<6}", entry.severity.label()), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+                    Span::styled(entry.text.clone(), Style::default().fg(color)),
+                ]))
+            })
+            .collect()
+    };
+    
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!("Event Log ({})", app.events.len()))
+                .title_bottom("Esc: close")
+                .borders(Borders::ALL)
+                .style(Style::default().fg(theme.accent))
+        )
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    
+    let mut state = ListState::default().with_selected((!app.events.is_empty()).then_some(offset));
+    f.render_widget(Clear, popup_area);
+    f.render_stateful_widget(list, popup_area, &mut state);
+}
+
+// Çıkış onayı popup'ı - confirm_quit açıkken 'q'/Esc ile gösterilir
+fn draw_quit_popup(f: &mut Frame, area: Rect, theme: Theme) {
+    let popup_area = centered_rect(24, 3, area);
+    let popup = Paragraph::new("Quit? (y/n)")
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(theme.accent))
+        )
+        .style(Style::default().fg(theme.text).add_modifier(Modifier::BOLD));
+    
+    f.render_widget(Clear, popup_area);
+    f.render_widget(popup, popup_area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::source::{InterfaceCounters, MockSource, ProcessSample, Sample};
+    use crate::state::ViewState;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    
+    // Verilen örneklerle çalışan App - ilk örnek App::new'de tüketilir, her update() sıradakini alır
+    async fn mock_app(samples: Vec<Sample>) -> App {
+        App::new(Config::default(), ViewState::default(), Box::new(MockSource::new(samples))).await.unwrap()
+    }
+    
+    // İki çekirdekli, geri kalanı boş örnek - testler sadece baktıkları alanları doldurur
+    fn mock_sample() -> Sample {
+        Sample { cpu_usage: vec![10.0; 2], ..Default::default() }
+    }
+    
+    // Footer ipuçlarının ekrandaki metni
+    fn hint_text(app: &App) -> String {
+        footer_hints(app).iter().map(|hint| hint.text.as_str()).collect::<Vec<_>>().join(" | ")
+    }
+    
+    // Çok küçük alanlarda hiçbir çizim fonksiyonu panik yapmamalı
+    #[tokio::test]
+    async fn test_tiny_terminal_does_not_panic() {
+        use ratatui::{backend::TestBackend, Terminal};
+        
+        // Her panelin dolu haliyle çizilmesi için tüm bölümlerde veri olan iki örnek
+        let process = |pid: u32, name: &str, cpu_usage: f32| ProcessSample {
+            pid,
+            name: name.to_string(),
+            status: "Runnable".to_string(),
+            cpu_usage,
+            memory: pid as u64 * 1024 * 1024,
+            ..Default::default()
+        };
+        let disk = |mount_point: &str, usage_percent: f32| DiskInfo {
+            name: "/dev/sda1".to_string(),
+            mount_point: mount_point.to_string(),
+            total_space: 100 * 1024 * 1024 * 1024,
+            available_space: 40 * 1024 * 1024 * 1024,
+            used_space: 60 * 1024 * 1024 * 1024,
+            usage_percent,
+            file_system: "ext4".to_string(),
+        };
+        let temperature = |component_name: &str, current_temp: f32| TemperatureInfo {
+            component_name: component_name.to_string(),
+            current_temp,
+            max_temp: Some(90.0),
+            critical_temp: Some(100.0),
+        };
+        let sample = |seconds: i64, received: u64| Sample {
+            timestamp: chrono::DateTime::from_timestamp(seconds, 0).unwrap().into(),
+            uptime: 3 * 86_400,
+            cpu_usage: vec![12.0, 97.0, 45.0, 3.0, 60.0, 88.0, 20.0, 1.0],
+            cpu_frequencies: vec![3_200; 8],
+            memory_used: 6 * 1024 * 1024 * 1024,
+            memory_total: 8 * 1024 * 1024 * 1024,
+            memory_available: 2 * 1024 * 1024 * 1024,
+            swap_used: 512 * 1024 * 1024,
+            swap_total: 2 * 1024 * 1024 * 1024,
+            network_received: received,
+            network_transmitted: received / 2,
+            interfaces: vec![InterfaceCounters { name: "eth0".to_string(), received, transmitted: received / 2 }],
+            processes: vec![process(1, "init", 0.1), process(42, "postgres", 85.0), process(77, "cargo", 40.0), process(90, "sshd", 0.0)],
+            disks: vec![disk("/", 60.0), disk("/home", 97.0)],
+            temperatures: vec![temperature("CPU Package", 72.0), temperature("nvme0", 45.0)],
+            fans: vec![crate::fans::FanInfo { label: "cpu_fan".to_string(), rpm: 1200 }],
+            connections: Some(crate::connections::ConnectionCounts { established: 12, listen: 4, ..Default::default() }),
+            ..Default::default()
+        };
+        let mut app = mock_app(vec![sample(0, 1_000_000), sample(1, 5_000_000)]).await;
+        app.update().await.unwrap();
+        
+        for (width, height) in [(0, 0), (1, 1), (10, 5), (MIN_WIDTH - 1, MIN_HEIGHT - 1), (MIN_WIDTH, MIN_HEIGHT)] {
+            let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+            terminal.draw(|f| ui(f, &app)).unwrap();
+            
+            // Paneller boyut kontrolü olmadan da (zoom, grid bölmeleri) küçük alanlara çizilebilir
+            terminal
+                .draw(|f| {
+                    let area = f.size();
+                    for panel in Panel::ALL {
+                        draw_panel(f, area, &app, panel);
+                    }
+                    draw_compact(f, area, &app);
+                    draw_system_tab(f, area, &app);
+                })
+                .unwrap();
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_render_to_string() {
+        let sample = Sample {
+            cpu_usage: vec![25.0, 75.0],
+            memory_used: 4 * 1024 * 1024 * 1024,
+            memory_total: 8 * 1024 * 1024 * 1024,
+            memory_available: 2 * 1024 * 1024 * 1024,
+            ..Default::default()
+        };
+        let mut app = mock_app(vec![sample.clone(), sample]).await;
+        app.update().await.unwrap();
+        
+        let screen = render_to_string(&app, 120, 40);
+        let lines: Vec<&str> = screen.lines().collect();
+        assert_eq!(lines.len(), 40);
+        assert!(lines.iter().all(|line| line.width() <= 120));
+        assert!(screen.contains("Rust System Monitor"));
+        assert!(screen.contains("CPU Cores: 2 | Avg Usage: 50.0%"));
+        // Kullanılan ve kullanılabilir bellek tek çubukta, açıklama satırında ayrı etiketlerle
+        assert!(screen.contains("Used 50.0%"));
+        assert!(screen.contains("Avail 2.0 GiB"));
+        
+        // Emoji iki hücre kaplar ama metinde bir kez görünmeli, çerçeve hizası bozulmamalı
+        assert_eq!(lines[1].width(), 120);
+    }
+    
+    #[tokio::test]
+    async fn test_clamp_to_view_after_resize() {
+        let sample = Sample { cpu_usage: vec![10.0; 8], ..Default::default() };
+        let mut app = mock_app(vec![sample]).await;
+        app.cpu_scroll = 7;
+        app.selected_process = 5;
+        
+        // Büyük pencerede bütün çekirdekler sığar - kaydırma sıfırlanır
+        render_to_string(&app, 140, 50);
+        app.clamp_to_view();
+        assert_eq!(app.cpu_scroll, 0);
+        assert_eq!(app.selected_process, 0);
+    }
+    
+    #[tokio::test]
+    async fn test_process_tab_lists_all_processes() {
+        let processes = (1..=60)
+            .map(|pid| ProcessSample { pid, name: format!("proc{pid}"), cpu_usage: 1.0, ..Default::default() })
+            .collect();
+        let sample = Sample { processes, ..mock_sample() };
+        let mut app = mock_app(vec![sample]).await;
+        app.active_tab = Tab::Processes;
+        app.set_process_sort(ProcessSortKey::Pid);
+        app.move_process_selection(45);
+
+        // Seçim görünür kalacak şekilde kaydırılır; başlık konumu gösterir
+        let screen = render_to_string(&app, 120, 30);
+        assert!(screen.contains("All Processes (46/60)"));
+        assert!(screen.contains("PID▼"));
+        assert!(screen.contains("proc46"));
+        assert!(!screen.contains("proc1 "));
+
+        // Başlık hücreleri tıklama için kaydedilir
+        let headers = app.hit_areas.get().process_headers;
+        assert!(headers.iter().flatten().any(|(_, key)| *key == ProcessSortKey::Name));
+    }
+
+    #[tokio::test]
+    async fn test_disk_gauge_grid() {
+        let disk = |mount: &str, usage_percent: f32| DiskInfo {
+            name: mount.to_string(),
+            mount_point: mount.to_string(),
+            total_space: 100,
+            available_space: 0,
+            used_space: 0,
+            usage_percent,
+            file_system: "ext4".to_string(),
+        };
+        let sample = Sample {
+            disks: vec![disk("/", 42.0), disk("/home", 97.5), disk("/data", 10.0)],
+            ..mock_sample()
+        };
+        let mut app = mock_app(vec![sample]).await;
+        app.active_tab = Tab::System;
+        app.disk_gauges = true;
+        app.selected_disk = 1;
+        
+        let screen = render_to_string(&app, 120, 40);
+        assert!(screen.contains("/ 42.0%"));
+        assert!(screen.contains("> /home 97.5%"));
+        assert!(screen.contains("/data 10.0%"));
+        assert!(!screen.contains("Mount"));
+        
+        // Sütun sayısı genişliğe ve disk sayısına göre
+        assert_eq!(gauge_grid_columns(3, 70), 2);
+        assert_eq!(gauge_grid_columns(3, 200), 3);
+        assert_eq!(gauge_grid_columns(3, 10), 1);
+        assert_eq!(gauge_grid_columns(0, 70), 0);
+    }
+    
+    #[tokio::test]
+    async fn test_diff_popup_since_baseline() {
+        let process = |pid: u32, name: &str, memory: u64| ProcessSample {
+            pid,
+            name: name.to_string(),
+            memory,
+            ..Default::default()
+        };
+        let before = Sample {
+            memory_used: 1024 * 1024 * 1024,
+            memory_total: 4 * 1024 * 1024 * 1024,
+            processes: vec![process(10, "db", 1024 * 1024), process(20, "job", 2048)],
+            ..mock_sample()
+        };
+        let after = Sample {
+            processes: vec![process(10, "db", 3 * 1024 * 1024), process(30, "build", 4096)],
+            network_received: 5 * 1024 * 1024,
+            ..before.clone()
+        };
+        let mut app = mock_app(vec![before.clone(), before, after]).await;
+        app.update().await.unwrap();
+        
+        // Referans yokken popup açılmaz
+        app.open_diff_popup();
+        assert!(!app.diff_popup);
+        app.capture_baseline();
+        app.update().await.unwrap();
+        app.open_diff_popup();
+        assert!(app.diff_popup);
+        
+        let screen = render_to_string(&app, 120, 40);
+        assert!(screen.contains("Changes since baseline"));
+        assert!(screen.contains("New processes (1)"));
+        assert!(screen.contains("+ 30      build"));
+        assert!(screen.contains("- 20      job"));
+        assert!(screen.contains("3.0 MiB (↑ 2.0 MiB)"));
+        assert!(screen.contains("Memory    1.0 GiB (no change)"));
+    }
+    
+    #[tokio::test]
+    async fn test_empty_lists_explain_container() {
+        let mut app = mock_app(vec![mock_sample()]).await;
+        app.active_tab = Tab::System;
+        
+        let screen = render_to_string(&app, 120, 40);
+        assert!(screen.contains("No disks detected"));
+        assert!(screen.contains("No temperature sensors on this system"));
+        
+        // Konteynerde boş liste kısıtlama anlamına gelir
+        app.sample.host.container = Some("Docker".to_string());
+        let screen = render_to_string(&app, 120, 40);
+        assert!(screen.contains("Disks not accessible inside Docker container"));
+        assert!(screen.contains("Sensors not accessible inside Docker container"));
+    }
+    
+    #[tokio::test]
+    async fn test_footer_shows_own_usage() {
+        let processes = vec![ProcessSample { pid: 4242, cpu_usage: 1.5, memory: 8 * 1024 * 1024, ..Default::default() }];
+        let sample = Sample { processes, ..mock_sample() };
+        let mut app = mock_app(vec![sample]).await;
+        
+        // Mock kaynak bu makineyi gözlemlemiyor - kendi PID'imiz bilinmez
+        assert_eq!(app.own_pid, None);
+        assert!(!render_to_string(&app, 120, 40).contains("monitor:"));
+        
+        app.own_pid = Some(4242);
+        assert!(render_to_string(&app, 120, 40).contains("monitor: 1.5% CPU, 8.0 MiB"));
+    }
+    
+    #[test]
+    fn test_time_axis_labels() {
+        let text = |labels: Vec<Span>| labels.iter().map(|label| label.content.to_string()).collect::<Vec<_>>();
+        // 60 saniye, 250 ms aralık: 241 örnek
+        assert_eq!(text(time_axis_labels(241, 250, 80)), ["-60s", "-40s", "-20s", "now"]);
+        assert_eq!(text(time_axis_labels(241, 250, 30)), ["-60s", "-30s", "now"]);
+        assert_eq!(text(time_axis_labels(241, 250, 10)), ["-60s", "now"]);
+        assert_eq!(text(time_axis_labels(181, 1000, 10)), ["-3m", "now"]);
+        assert_eq!(text(time_axis_labels(151, 1000, 10)), ["-2m30s", "now"]);
+        assert_eq!(text(time_axis_labels(3601, 1000, 10)), ["-1h", "now"]);
+        // Tek örnek - geçmiş yok
+        assert!(time_axis_labels(1, 250, 80).is_empty());
+    }
+    
+    #[tokio::test]
+    async fn test_network_counters_view() {
+        let interfaces = vec![
+            InterfaceCounters { name: "eth0".to_string(), received: 3 * 1024 * 1024 * 1024, transmitted: 512 * 1024 },
+            InterfaceCounters { name: "lo".to_string(), received: 2048, transmitted: 2048 },
+        ];
+        let sample = Sample { interfaces, ..mock_sample() };
+        let mut app = mock_app(vec![sample]).await;
+        
+        // Varsayılan görünüm hızlar
+        assert!(render_to_string(&app, 120, 40).contains("Download:"));
+        
+        app.toggle_network_counters();
+        let screen = render_to_string(&app, 120, 40);
+        assert!(!screen.contains("Download:"));
+        assert!(screen.contains("eth0: ⬇️ 3.0 GiB  ⬆️ 512.0 KiB"));
+        assert!(screen.contains("lo: ⬇️ 2.0 KiB  ⬆️ 2.0 KiB"));
+    }
+    
+    #[tokio::test]
+    async fn test_ascii_only_rendering() {
+        let mut app = mock_app(vec![mock_sample(), mock_sample()]).await;
+        app.update().await.unwrap();
+        app.ascii_only = true;
+        
+        let screen = render_to_string(&app, 120, 40);
+        assert!(screen.contains("Rust System Monitor"));
+        assert!(screen.contains("RX Download:"));
+        // Kenarlıklar ve grafik noktaları dışında ASCII olmayan karakter kalmamalı
+        // (ratatui'de ASCII grafik işaretçisi yok, Dot en yaygın desteklenen)
+        let stray: Vec<char> = screen
+            .chars()
+            .filter(|c| !c.is_ascii() && *c != '•' && !('\u{2500}'..='\u{257f}').contains(c))
+            .collect();
+        assert!(stray.is_empty(), "ASCII dışı karakterler: {:?}\n{}", stray, screen);
+    }
+    
+    #[test]
+    fn test_health_banner_lists_factors_that_fit() {
+        let factor = |level, reason: &str| crate::health::HealthFactor { level, reason: reason.to_string() };
+        let health = Health {
+            level: HealthLevel::Critical,
+            factors: vec![
+                factor(HealthLevel::Critical, "/ 97% full"),
+                factor(HealthLevel::Warning, "memory 85%"),
+                factor(HealthLevel::Warning, "CPU Package 88°C"),
+            ],
+        };
+        assert_eq!(
+            health_banner_text(&health, 80),
+            " Health: Critical - / 97% full, memory 85%, CPU Package 88°C "
+        );
+        // Sığmayanlar sayı olarak özetlenir
+        assert_eq!(health_banner_text(&health, 40), " Health: Critical - / 97% full +2 ");
+        assert_eq!(
+            health_banner_text(&Health { level: HealthLevel::Good, factors: Vec::new() }, 80),
+            " Health: Good "
+        );
+    }
+    
+    #[test]
+    fn test_pause_banner_keeps_resume_hint() {
+        assert_eq!(pause_banner_text(None, 80), " PAUSED (p: resume) ");
+        assert_eq!(
+            pause_banner_text(Some("Disk / is 97% full"), 80),
+            " PAUSED: Disk / is 97% full (p: resume) "
+        );
+        assert_eq!(pause_banner_text(Some("Disk / is 97% full"), 30), " PAUSED: Disk / … (p: resume) ");
+    }
+    
+    #[test]
+    fn test_core_count_text() {
+        assert_eq!(core_count_text(16, Some(8)), "CPU: 16 threads / 8 cores");
+        assert_eq!(core_count_text(4, None), "CPU Cores: 4");
+    }
+    
+    #[test]
+    fn test_format_sample_age() {
+        assert_eq!(format_age(Duration::from_millis(900)), "0s");
+        assert_eq!(format_age(Duration::from_secs(42)), "42s");
+        assert_eq!(format_age(Duration::from_secs(125)), "2m 05s");
+        assert_eq!(format_age(Duration::from_secs(3_780)), "1h 03m");
+    }
+    
+    #[tokio::test]
+    async fn test_heartbeat_advances_per_refresh() {
+        let mut app = mock_app(vec![mock_sample(), mock_sample(), mock_sample()]).await;
+        app.ascii_only = true;
+        assert_eq!(heartbeat_frame(&app), "| ");
+        app.update().await.unwrap();
+        assert_eq!(heartbeat_frame(&app), "/ ");
+        // Kaynakta yeni örnek yoksa nabız da ilerlemez
+        app.update().await.unwrap();
+        app.update().await.unwrap();
+        assert_eq!(heartbeat_frame(&app), "- ");
+        
+        app.heartbeat = false;
+        assert_eq!(heartbeat_frame(&app), "");
+    }
+    
+    #[test]
+    fn test_ascii_gauge() {
+        assert_eq!(ascii_gauge(50.0, 10, "ab", "#"), "####ab    ");
+        assert_eq!(ascii_gauge(100.0, 6, "", "#"), "######");
+        // Sığmayan etiket kesilir
+        assert_eq!(ascii_gauge(0.0, 3, "long", "#"), "lon");
+    }
+    
+    #[test]
+    fn test_stacked_bar_cells() {
+        assert_eq!(stacked_bar_cells(25, 75, 100, 20), (5, 15));
+        // Yuvarlamalar toplam genişliği aşmaz
+        assert_eq!(stacked_bar_cells(55, 45, 100, 3), (2, 1));
+        // Tanımları farklı platformlarda boş kalan kısım çizilmez
+        assert_eq!(stacked_bar_cells(50, 20, 100, 10), (5, 2));
+        assert_eq!(stacked_bar_cells(1, 1, 0, 10), (0, 0));
+    }
+    
+    #[test]
+    fn test_temperature_sparkline_scales_to_window() {
+        let history: VecDeque<f32> = [50.0, 60.0, 90.0, 61.0, 89.0].into_iter().collect();
+        // En düşük değer bir birim yükseklikte, en yüksek tam yükseklikte
+        assert_eq!(temperature_sparkline_data(&history, 10), (vec![10, 110, 410, 120, 400], 410));
+        // Genişlik kadar son değer
+        assert_eq!(temperature_sparkline_data(&history, 2).0, vec![10, 290]);
+    }
+    
+    #[tokio::test]
+    async fn test_focused_panel_has_thick_border() {
+        let mut app = mock_app(vec![mock_sample()]).await;
+        
+        // Başlık çerçevenin sol üst köşesinden hemen sonra başlar
+        let screen = render_to_string(&app, 120, 40);
+        assert!(screen.contains("┏CPU Cores"));
+        assert!(screen.contains("┌Top Processes"));
+        
+        app.focus_next();
+        app.focus_next();
+        let screen = render_to_string(&app, 120, 40);
+        assert!(screen.contains("┌CPU Cores"));
+        assert!(screen.contains("┏Top Processes"));
+    }
+    
+    #[tokio::test]
+    async fn test_hidden_panels_reflow() {
+        let mut app = mock_app(vec![mock_sample()]).await;
+        
+        // Sağ sütun tamamen gizli - sol sütun tüm genişliği alır
+        for section in [Section::Processes, Section::Network, Section::DiskIo, Section::Alerts, Section::CpuChart] {
+            app.panels.toggle(section);
+        }
+        let screen = render_to_string(&app, 120, 40);
+        assert!(!screen.contains("Top Processes"));
+        assert!(!screen.contains("CPU Usage History"));
+        assert!(screen.contains("Memory Usage History"));
+        let cpu_area = app.hit_areas.get().cpu_gauges.unwrap();
+        assert_eq!(cpu_area.width, 120);
+        
+        // Hiçbir şey kalmadıysa boş ekran yerine ipucu
+        for section in [Section::CpuGauges, Section::MemoryInfo, Section::MemoryChart] {
+            app.panels.toggle(section);
+        }
+        assert!(render_to_string(&app, 120, 40).contains("press V"));
+    }
+    
+    #[test]
+    fn test_network_chart_log_scale() {
+        let history: VecDeque<(u64, u64)> = [(0, 10), (1_000, 100), (250_000_000, 0)].into_iter().collect();
+        
+        // Doğrusal: eksen en büyük değerin biraz üstü
+        let (download, _, max_y) = network_chart_points(&history, false);
+        assert_eq!(download[2], (2.0, 250_000_000.0));
+        assert_eq!(max_y, 275_000_000.0);
+        
+        // Logaritmik: sıfır tabana yuvarlanır, eksen bir sonraki on'un kuvvetinde biter
+        let (download, upload, max_y) = network_chart_points(&history, true);
+        assert_eq!(download[0].1, 0.0);
+        assert_eq!(download[1].1, 3.0);
+        assert_eq!(upload[0].1, 1.0);
+        assert_eq!(max_y, 9.0);
+        assert_eq!(network_axis_labels(max_y, true), vec![1, 10_000, 1_000_000_000]);
+        
+        // Boş geçmişte bile geçerli bir eksen
+        let (_, _, max_y) = network_chart_points(&VecDeque::new(), true);
+        assert_eq!(max_y, 3.0);
+    }
+    
+    #[test]
+    fn test_heatmap_wraps_cores() {
+        let usage = [5.0, 50.0, 95.0, 10.0, 20.0];
+        let thresholds = ColorThresholds::default();
+        let lines = heatmap_lines(&usage, 2, &thresholds, "█");
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[2].spans.len(), 1);
+        assert_eq!(lines[1].spans[0].style.fg, Some(usage_color(95.0, &thresholds)));
+        assert!(heatmap_lines(&usage, 0, &thresholds, "█").is_empty());
+    }
+    
+    #[tokio::test]
+    async fn test_footer_hints_follow_focus_and_popups() {
+        let mut app = mock_app(Vec::new()).await;
+        
+        app.focused_panel = Panel::Processes;
+        assert!(hint_text(&app).starts_with("↑/↓ k/j gg/G Home/End: select | Enter: details | x: kill"));
+        
+        app.focused_panel = Panel::Memory;
+        assert!(!hint_text(&app).contains("x: kill"));
+        
+        // Yeniden atanan tuşlar footer'da görünür; başka eyleme verilen varsayılan tuş eski eylemde gösterilmez
+        let bindings = [
+            (KeyEvent::new(KeyCode::Char('K'), KeyModifiers::NONE), Action::KillProcess),
+            (KeyEvent::new(KeyCode::Char('z'), KeyModifiers::CONTROL), Action::ToggleZoom),
+            (KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE), Action::ShowHealth),
+        ];
+        app.key_labels = crate::input::InputHandler::new(bindings.into_iter().collect()).key_labels();
+        app.focused_panel = Panel::Processes;
+        let hints = hint_text(&app);
+        assert!(hints.contains("K: kill") && !hints.contains("x: kill"), "{hints}");
+        assert!(hints.contains("ctrl+z: zoom") && hints.contains("x: health"), "{hints}");
+        
+        // Salt okunurda soluk çizilen, sinyal gönderen eylemin ipucudur - tuşu değil
+        app.read_only = true;
+        let line = hint_line(&footer_hints(&app), &app);
+        let crossed = |text: &str| {
+            line.spans
+                .iter()
+                .find(|span| span.content == text)
+                .map(|span| span.style.add_modifier.contains(Modifier::CROSSED_OUT))
+        };
+        assert_eq!(crossed("K: kill"), Some(true));
+        assert_eq!(crossed("X: kill by name"), Some(true));
+        assert_eq!(crossed("x: health"), Some(false));
+        
+        // Popup açıkken sadece popup'ın tuşları
+        app.quit_prompt = true;
+        assert_eq!(hint_text(&app), "y: quit | any other key: cancel");
+    }
+    
+    #[test]
+    fn test_moving_average() {
+        let values = [10.0, 20.0, 30.0, 40.0];
+        assert_eq!(moving_average(&values, 1), values.to_vec());
+        assert_eq!(moving_average(&values, 0), values.to_vec());
+        assert_eq!(moving_average(&values, 2), vec![10.0, 15.0, 25.0, 35.0]);
+        assert_eq!(moving_average(&values, 10), vec![10.0, 15.0, 20.0, 25.0]);
+    }
+    
+    #[test]
+    fn test_compact_layout_threshold() {
+        let size = |width, height| Rect { x: 0, y: 0, width, height };
+        assert!(use_compact_layout(size(80, 24), false));
+        assert!(!use_compact_layout(size(160, 48), false));
+        assert!(use_compact_layout(size(160, 48), true));
+    }
+    
+    #[test]
+    fn test_process_columns_drop_on_narrow_width() {
+        assert_eq!(
+            visible_process_columns(20),
+            vec![ProcessColumn::Name, ProcessColumn::Cpu, ProcessColumn::Memory]
+        );
+        assert_eq!(
+            visible_process_columns(40),
+            vec![ProcessColumn::Pid, ProcessColumn::Name, ProcessColumn::Cpu, ProcessColumn::Memory]
+        );
+        assert_eq!(visible_process_columns(120).len(), ProcessColumn::PRIORITY.len());
+    }
+    
+    #[test]
+    fn test_downsample_keeps_spikes() {
+        let points: Vec<(f64, f64)> = (0..100).map(|i| (i as f64, if i == 37 { 95.0 } else { 10.0 + (i % 3) as f64 })).collect();
+        // Sığıyorsa dokunulmaz
+        assert_eq!(downsample_min_max(points.clone(), 50), points);
+        
+        let reduced = downsample_min_max(points, 10);
+        assert!(reduced.len() <= 20);
+        assert!(reduced.contains(&(37.0, 95.0)));
+        // Noktalar zaman sırasında kalır
+        assert!(reduced.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(reduced.iter().map(|point| point.1).fold(f64::MAX, f64::min), 10.0);
+    }
+    
+    #[test]
+    fn test_history_x_bound_follows_sample_count() {
+        assert_eq!(history_x_bound(0), 1.0);
+        assert_eq!(history_x_bound(1), 1.0);
+        assert_eq!(history_x_bound(30), 29.0);
+    }
+    
+    #[test]
+    fn test_truncate_path_keeps_tail() {
+        assert_eq!(truncate_path("/usr/bin/bash", 20), "/usr/bin/bash");
+        assert_eq!(truncate_path("/usr/bin/bash", 6), "…/bash");
+        assert_eq!(truncate_path("/usr/bin/bash", 0), "");
+        // Çift genişlikli karakterler kolona göre sayılır
+        assert_eq!(truncate_path("/opt/アプリ", 6), "…プリ");
+    }
+    
+    #[test]
+    fn test_truncate_long_unicode_name() {
+        assert_eq!(truncate_name("bash", 12), "bash");
+        assert_eq!(truncate_name("데이터베이스-서버", 8), "데이터…");
+        assert_eq!(truncate_name("데이터베이스-서버", 8).width(), 7);
+        // Aksan işareti harfinden ayrılmaz
+        assert_eq!(truncate_name("e\u{301}e\u{301}e\u{301}", 2), "e\u{301}…");
+        assert_eq!(truncate_name("firefox", 0), "");
+    }
 }
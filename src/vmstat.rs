@@ -0,0 +1,65 @@
+// vmstat.rs - Swap giriş/çıkış sayaçları (/proc/vmstat)
+// Swap'in ne kadar dolu olduğu tek başına bir şey söylemez: %90 dolu bir swap sessizce
+// duruyor olabilir. Asıl sıkışma işareti sayfaların sürekli diske yazılıp geri okunmasıdır.
+// Çekirdek bunları açılıştan beri sayan pswpin/pswpout sayaçlarında tutar; hız, ağ
+// hızlarında olduğu gibi iki okuma arasındaki farktan hesaplanır
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+// Sayaçlar sayfa cinsindendir - byte'a çevirmek için yaygın sayfa boyutu kullanılır
+pub const PAGE_SIZE: u64 = 4096;
+
+// Açılıştan beri swap'ten okunan ve swap'e yazılan sayfa sayıları
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct SwapCounters {
+    pub pages_in: u64,
+    pub pages_out: u64,
+}
+
+// Dosya okunamazsa veya sayaçlardan biri eksikse None
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub fn read_swap_counters_from(path: &Path) -> Option<SwapCounters> {
+    let contents = fs::read_to_string(path).ok()?;
+    let counter = |name: &str| {
+        contents.lines().find_map(|line| {
+            let (key, value) = line.split_once(' ')?;
+            (key == name).then(|| value.trim().parse().ok()).flatten()
+        })
+    };
+    Some(SwapCounters {
+        pages_in: counter("pswpin")?,
+        pages_out: counter("pswpout")?,
+    })
+}
+
+#[cfg(target_os = "linux")]
+pub fn read_swap_counters() -> Option<SwapCounters> {
+    read_swap_counters_from(Path::new("/proc/vmstat"))
+}
+
+// Diğer platformlarda swap hızı gösterilmez
+#[cfg(not(target_os = "linux"))]
+pub fn read_swap_counters() -> Option<SwapCounters> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_swap_counters_from_vmstat() {
+        let path = std::env::temp_dir().join(format!("sysmon-vmstat-{}", std::process::id()));
+        fs::write(&path, "nr_free_pages 1024\npswpin 120\npswpout 4500\npgfault 9\n").unwrap();
+        let counters = read_swap_counters_from(&path);
+        fs::write(&path, "nr_free_pages 1024\npswpin 120\n").unwrap();
+        let partial = read_swap_counters_from(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(counters, Some(SwapCounters { pages_in: 120, pages_out: 4500 }));
+        assert_eq!(partial, None);
+        assert_eq!(read_swap_counters_from(Path::new("/nonexistent/vmstat")), None);
+    }
+}
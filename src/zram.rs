@@ -0,0 +1,93 @@
+// zram.rs - Sıkıştırılmış bellek (zram) istatistikleri
+// zram swap'i RAM'de sıkıştırarak tutar; swap satırları sadece sıkıştırılmamış boyutu gösterir,
+// gerçekte ne kadar bellek harcandığını söylemez. Her cihaz /sys/block/zramN/mm_stat dosyasında
+// boşlukla ayrılmış sayaçlar sunar: ilk üçü orijinal veri, sıkıştırılmış veri ve cihazın
+// kullandığı toplam bellek (byte). zswap'in sayaçları debugfs'te ve root yetkisi gerektirdiği
+// için okunmaz. zram yoksa (veya Linux değilse) bellek panelinde zram satırı gösterilmez
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+// Tüm zram cihazlarının toplamı
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ZramStats {
+    pub original: u64,    // Sıkıştırılmadan önceki veri (byte)
+    pub compressed: u64,  // Sıkıştırılmış veri (byte)
+    pub memory_used: u64, // Cihazın harcadığı bellek, ayırıcı kaybı dahil (byte)
+}
+
+impl ZramStats {
+    // Sıkıştırma oranı - cihaz boşsa None
+    pub fn ratio(&self) -> Option<f64> {
+        (self.compressed > 0).then(|| self.original as f64 / self.compressed as f64)
+    }
+}
+
+// /sys/block altındaki zram cihazlarını topla - hiç okunabilir mm_stat yoksa None
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub fn read_zram_stats_from(root: &Path) -> Option<ZramStats> {
+    let entries = fs::read_dir(root).ok()?;
+    let mut total: Option<ZramStats> = None;
+    for entry in entries.flatten() {
+        if !entry.file_name().to_str().is_some_and(|name| name.starts_with("zram")) {
+            continue;
+        }
+        let Ok(contents) = fs::read_to_string(entry.path().join("mm_stat")) else {
+            continue;
+        };
+        let fields: Vec<u64> = contents.split_whitespace().filter_map(|field| field.parse().ok()).collect();
+        let [original, compressed, memory_used, ..] = fields[..] else {
+            continue;
+        };
+        let stats = total.get_or_insert_with(ZramStats::default);
+        stats.original += original;
+        stats.compressed += compressed;
+        stats.memory_used += memory_used;
+    }
+    total
+}
+
+#[cfg(target_os = "linux")]
+pub fn read_zram_stats() -> Option<ZramStats> {
+    read_zram_stats_from(Path::new("/sys/block"))
+}
+
+// zram sadece Linux'ta var
+#[cfg(not(target_os = "linux"))]
+pub fn read_zram_stats() -> Option<ZramStats> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_zram_stats_from_sysfs() {
+        let root = std::env::temp_dir().join(format!("sysmon-zram-{}", std::process::id()));
+        for (device, mm_stat) in [
+            ("zram0", "4096000 1024000 1200000 0 1200000 10 0 2\n"),
+            ("zram1", "1000 500 800 0 800 0 0 0\n"),
+            ("zram2", "garbage\n"),
+        ] {
+            fs::create_dir_all(root.join(device)).unwrap();
+            fs::write(root.join(device).join("mm_stat"), mm_stat).unwrap();
+        }
+        fs::create_dir_all(root.join("sda")).unwrap();
+        let stats = read_zram_stats_from(&root);
+
+        // zram olmayan bir sistem
+        fs::remove_dir_all(root.join("zram0")).unwrap();
+        fs::remove_dir_all(root.join("zram1")).unwrap();
+        fs::remove_dir_all(root.join("zram2")).unwrap();
+        let none = read_zram_stats_from(&root);
+        fs::remove_dir_all(&root).unwrap();
+
+        let stats = stats.unwrap();
+        assert_eq!(stats, ZramStats { original: 4097000, compressed: 1024500, memory_used: 1200800 });
+        assert!((stats.ratio().unwrap() - 3.999).abs() < 0.001);
+        assert_eq!(none, None);
+        assert_eq!(ZramStats::default().ratio(), None);
+    }
+}